@@ -1,4 +1,5 @@
 #![feature(test)]
+#![allow(clippy::needless_borrow)]
 
 extern crate test;
 
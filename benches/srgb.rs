@@ -0,0 +1,59 @@
+#![feature(test)]
+
+extern crate test;
+
+use cvr::convert::transfer::Srgb;
+
+const WIDTH: usize = 3840;
+const HEIGHT: usize = 2160;
+
+fn make_srgb() -> cvr::rgb::Image<u8> {
+  let n = WIDTH * HEIGHT;
+
+  cvr::rgb::Image::from_vec(
+    (0..n).map(|i| (i % 256) as u8).collect(),
+    (0..n).map(|i| (i * 7 % 256) as u8).collect(),
+    (0..n).map(|i| (i * 13 % 256) as u8).collect(),
+    WIDTH,
+    HEIGHT,
+  )
+  .unwrap()
+}
+
+fn make_linear() -> cvr::rgb::Image<f32> {
+  let mut img = cvr::rgb::Image::default();
+  make_srgb().to_linear(&mut img);
+  img
+}
+
+#[bench]
+fn srgb_to_linear_lut(bencher: &mut test::bench::Bencher) {
+  let img = make_srgb();
+  let mut out = cvr::rgb::Image::default();
+
+  bencher.iter(|| img.to_linear(&mut out));
+}
+
+#[bench]
+fn srgb_to_linear_curve(bencher: &mut test::bench::Bencher) {
+  let img = make_srgb();
+  let mut out = cvr::rgb::Image::default();
+
+  bencher.iter(|| img.decode(&Srgb, &mut out));
+}
+
+#[bench]
+fn linear_to_srgb_lut(bencher: &mut test::bench::Bencher) {
+  let img = make_linear();
+  let mut out = cvr::rgb::Image::default();
+
+  bencher.iter(|| img.to_srgb(&mut out));
+}
+
+#[bench]
+fn linear_to_srgb_curve(bencher: &mut test::bench::Bencher) {
+  let img = make_linear();
+  let mut out = cvr::rgb::Image::<u8>::default();
+
+  bencher.iter(|| img.encode(&Srgb, &mut out));
+}
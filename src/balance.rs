@@ -0,0 +1,304 @@
+//! `balance` contains [white balancing](https://en.wikipedia.org/wiki/Color_balance) routines which remove the color
+//! cast an illuminant leaves on an image, such as the green tint of raw sensor data or the orange glow of tungsten
+//! lighting.
+//!
+//! Every routine operates on linear `RGB` images. The correction is a set of per-channel [`Gains`], which are either
+//! supplied directly, estimated from the image with [`gray_world`], [`white_patch`] or [`reference_patch`], or derived
+//! from the color temperature of the illuminant with [`Gains::from_temperature`]. The estimated gains are normalized
+//! so that the green gain is `1.0`, which leaves the overall brightness of the image largely untouched.
+//!
+//! # Example
+//! ```
+//! let img = cvr::rgb::Image::from_vec(
+//!   vec![0.2_f32, 0.4, 0.3, 0.1],
+//!   vec![0.4_f32, 0.8, 0.6, 0.2],
+//!   vec![0.1_f32, 0.2, 0.15, 0.05],
+//!   2,
+//!   2,
+//! )
+//! .unwrap();
+//!
+//! let gains = cvr::balance::gray_world(&img);
+//! assert_eq!(gains, cvr::balance::Gains::new(2.0, 1.0, 4.0));
+//!
+//! let mut balanced = cvr::rgb::Image::new();
+//! cvr::balance::apply(&img, &mut balanced, gains);
+//!
+//! assert!(balanced.rgb_iter().all(|[r, g, b]| r == g && g == b));
+//! ```
+//!
+
+use crate::rgb;
+
+/// `Gains` are the factors that each of the red, green and blue channels of a linear pixel are multiplied by.
+///
+/// The [`Default`] is [`Gains::IDENTITY`], which leaves pixels unchanged.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gains {
+  /// The factor applied to the red channel.
+  pub r: f32,
+  /// The factor applied to the green channel.
+  pub g: f32,
+  /// The factor applied to the blue channel.
+  pub b: f32,
+}
+
+impl Gains {
+  /// `IDENTITY` leaves every channel unchanged.
+  ///
+  pub const IDENTITY: Gains = Gains {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+  };
+
+  /// `new` constructs manual gains for the red, green and blue channels.
+  ///
+  #[must_use]
+  pub fn new(r: f32, g: f32, b: f32) -> Self {
+    Gains { r, g, b }
+  }
+
+  /// `from_neutral` returns the gains which turn the supplied linear color, known to be a shade of gray in the scene,
+  /// into a neutral one with the same green value.
+  ///
+  /// A channel that is zero or not finite is left with a gain of `1.0`, as is every channel if the green value is.
+  ///
+  #[must_use]
+  pub fn from_neutral([r, g, b]: [f32; 3]) -> Self {
+    let gain = |x: f32| {
+      let gain = g / x;
+      if gain.is_finite() && gain > 0.0 {
+        gain
+      } else {
+        1.0
+      }
+    };
+
+    Gains {
+      r: gain(r),
+      g: 1.0,
+      b: gain(b),
+    }
+  }
+
+  /// `from_temperature` returns the gains which neutralize the light of an illuminant with the supplied
+  /// [color temperature](https://en.wikipedia.org/wiki/Color_temperature) in Kelvin, i.e. which make a white object
+  /// lit by it appear white under the `D65` white point of linear `sRGB`.
+  ///
+  /// Warm temperatures follow the Planckian locus of incandescent light while those from `5000 K` follow the CIE
+  /// daylight locus, so that `6504 K` yields the identity. The temperature is clamped to the range `[2000.0, 25000.0]`,
+  /// below which the illuminant falls outside of the `sRGB` gamut.
+  ///
+  /// # Example
+  /// ```
+  /// // tungsten light is orange so its red is attenuated and its blue amplified
+  /// //
+  /// let gains = cvr::balance::Gains::from_temperature(2856.0);
+  /// assert!(gains.r < 1.0 && gains.b > 1.0);
+  ///
+  /// let daylight = cvr::balance::Gains::from_temperature(6504.0);
+  /// assert!((daylight.r - 1.0).abs() < 1e-2 && (daylight.b - 1.0).abs() < 1e-2);
+  /// ```
+  ///
+  #[must_use]
+  pub fn from_temperature(kelvin: f32) -> Self {
+    let [x, y] = chromaticity(kelvin);
+    let white = crate::convert::WhitePoint::from_chromaticity(x, y);
+
+    Gains::from_neutral(crate::convert::xyz_to_linear(white.xyz()))
+  }
+
+  /// `apply` multiplies each channel of the linear pixel by its gain.
+  ///
+  #[must_use]
+  pub fn apply(self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+    [r * self.r, g * self.g, b * self.b]
+  }
+}
+
+impl Default for Gains {
+  fn default() -> Self {
+    Gains::IDENTITY
+  }
+}
+
+/// `apply` multiplies every pixel of the linear image `x` by the supplied gains, storing the result in `y`.
+///
+/// Channels are not clamped, so gains above `1.0` can push highlights beyond the range `[0.0, 1.0]`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn apply(x: &rgb::Image<f32>, y: &mut rgb::Image<f32>, gains: Gains) {
+  y.resize(x.width(), x.height());
+
+  let (red, green, blue) = y.rgb_mut();
+  for (src, dst, gain) in [
+    (x.r(), red, gains.r),
+    (x.g(), green, gains.g),
+    (x.b(), blue, gains.b),
+  ] {
+    src
+      .iter()
+      .zip(dst.iter_mut())
+      .for_each(|(src, dst)| *dst = src * gain);
+  }
+}
+
+/// `gray_world` estimates the gains of the linear image under the
+/// [gray world assumption](https://en.wikipedia.org/wiki/Color_normalization#Grey_world), that the average color of a
+/// scene is a neutral gray. `NaN` values are ignored.
+///
+#[must_use]
+pub fn gray_world(img: &rgb::Image<f32>) -> Gains {
+  Gains::from_neutral(mean(img.view()))
+}
+
+/// `white_patch` estimates the gains of the linear image under the white patch, or max-`RGB`, assumption, that the
+/// brightest value of each channel belongs to a white surface. `NaN` values are ignored.
+///
+/// Rather than the maximum, the `p`-th percentile of each channel is used, which prevents a handful of clipped or
+/// noisy pixels from dominating the estimate. A `p` of `100.0` selects the maximum.
+///
+/// # Panics
+///
+/// Panics if `p` is not within the range `[0.0, 100.0]`.
+///
+#[must_use]
+pub fn white_patch(img: &rgb::Image<f32>, p: f32) -> Gains {
+  assert!(
+    (0.0..=100.0).contains(&p),
+    "percentile must be within [0, 100]"
+  );
+
+  Gains::from_neutral([img.r(), img.g(), img.b()].map(|plane| percentile(plane, p)))
+}
+
+/// `reference_patch` computes the gains from the average color of the `width * height` rectangle whose top-left corner
+/// is at column `x` and row `y` of the linear image, which is known to contain a neutral surface such as a gray card.
+/// `NaN` values are ignored.
+///
+/// # Errors
+///
+/// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+///
+pub fn reference_patch(
+  img: &rgb::Image<f32>,
+  x: usize,
+  y: usize,
+  width: usize,
+  height: usize,
+) -> Result<Gains, crate::Error> {
+  let patch = img.view().roi(x, y, width, height)?;
+  Ok(Gains::from_neutral(mean(patch)))
+}
+
+/// `mean` averages each channel of the view, ignoring `NaN` values. A channel without any values averages to `0.0`.
+///
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn mean(img: rgb::ImageRef<'_, f32>) -> [f32; 3] {
+  let mut sums = [0.0_f64; 3];
+  let mut counts = [0_usize; 3];
+
+  img.rgb_iter().for_each(|pixel| {
+    for ((x, sum), count) in pixel.iter().zip(sums.iter_mut()).zip(counts.iter_mut()) {
+      if !x.is_nan() {
+        *sum += f64::from(*x);
+        *count += 1;
+      }
+    }
+  });
+
+  [0, 1, 2].map(|c| {
+    if counts[c] == 0 {
+      0.0
+    } else {
+      (sums[c] / counts[c] as f64) as f32
+    }
+  })
+}
+
+/// `percentile` returns the `p`-th percentile of the plane using the nearest-rank method, ignoring `NaN` values. A
+/// plane without any values returns `0.0`.
+///
+#[allow(
+  clippy::cast_precision_loss,
+  clippy::cast_possible_truncation,
+  clippy::cast_sign_loss
+)]
+fn percentile(plane: &[f32], p: f32) -> f32 {
+  let mut values: Vec<f32> = plane.iter().copied().filter(|x| !x.is_nan()).collect();
+  if values.is_empty() {
+    return 0.0;
+  }
+
+  let n = values.len();
+  let rank = (f64::from(p) / 100.0 * n as f64).ceil() as usize;
+  *values
+    .select_nth_unstable_by(rank.clamp(1, n) - 1, f32::total_cmp)
+    .1
+}
+
+/// `chromaticity` returns the `(x, y)` chromaticity coordinates of an illuminant with the supplied color temperature.
+///
+/// Below `4000 K` the Planckian locus is followed and above `5000 K` the CIE daylight locus, which passes through
+/// `D65`. In between, the two are blended linearly in reciprocal temperature so that the coordinates change smoothly.
+///
+fn chromaticity(kelvin: f32) -> [f32; 2] {
+  let t = f64::from(kelvin.clamp(2000.0, 25000.0));
+
+  let [x, y] = if t <= 4000.0 {
+    planckian(t)
+  } else if t >= 5000.0 {
+    daylight(t)
+  } else {
+    let w = (1.0 / 4000.0 - 1.0 / t) / (1.0 / 4000.0 - 1.0 / 5000.0);
+    let ([x1, y1], [x2, y2]) = (planckian(t), daylight(t));
+
+    [x1 + w * (x2 - x1), y1 + w * (y2 - y1)]
+  };
+
+  #[allow(clippy::cast_possible_truncation)]
+  [x as f32, y as f32]
+}
+
+/// `planckian` approximates the chromaticity of a black body radiator with the cubic splines of Kim et al., valid for
+/// temperatures within `[1667.0, 25000.0]`.
+///
+fn planckian(t: f64) -> [f64; 2] {
+  let (t2, t3) = (t * t, t * t * t);
+
+  let x = if t <= 4000.0 {
+    -0.266_123_9e9 / t3 - 0.234_358_9e6 / t2 + 0.877_695_6e3 / t + 0.179_91
+  } else {
+    -3.025_846_9e9 / t3 + 2.107_037_9e6 / t2 + 0.222_634_7e3 / t + 0.240_39
+  };
+
+  let (x2, x3) = (x * x, x * x * x);
+
+  let y = if t <= 2222.0 {
+    -1.106_381_4 * x3 - 1.348_110_2 * x2 + 2.185_558_32 * x - 0.202_196_83
+  } else if t <= 4000.0 {
+    -0.954_947_6 * x3 - 1.374_185_93 * x2 + 2.091_370_15 * x - 0.167_488_67
+  } else {
+    3.081_758 * x3 - 5.873_386_7 * x2 + 3.751_129_97 * x - 0.370_014_83
+  };
+
+  [x, y]
+}
+
+/// `daylight` computes the chromaticity of the CIE daylight illuminant, valid for temperatures within
+/// `[4000.0, 25000.0]`.
+///
+fn daylight(t: f64) -> [f64; 2] {
+  let (t2, t3) = (t * t, t * t * t);
+
+  let x = if t <= 7000.0 {
+    -4.607e9 / t3 + 2.9678e6 / t2 + 0.099_11e3 / t + 0.244_063
+  } else {
+    -2.0064e9 / t3 + 1.9018e6 / t2 + 0.247_48e3 / t + 0.237_04
+  };
+
+  [x, -3.0 * x * x + 2.87 * x - 0.275]
+}
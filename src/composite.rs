@@ -0,0 +1,253 @@
+//! `composite` implements [Porter-Duff](https://en.wikipedia.org/wiki/Alpha_compositing) compositing of one `RGBA`
+//! image onto another.
+//!
+//! Compositing blends light, so it's only physically correct on linear data. The floating point routines expect
+//! linear images, such as those produced by [`rgba::Image::to_linear`], while [`composite_srgb`] decodes and
+//! re-encodes 8-bit `sRGB` images itself. Internally every operator works on premultiplied alpha, see
+//! [`rgba::cvt_premultiply`] for converting whole images.
+//!
+//! The source image is placed with its top-left corner at an offset within the destination, which may be negative or
+//! extend past the destination's edges. Only the destination is written to and the source is treated as fully
+//! transparent wherever it doesn't cover the destination, which matters for operators like [`Operator::In`] that
+//! clear the destination outside of the source.
+//!
+//! # Example
+//! ```
+//! use cvr::composite::Operator;
+//!
+//! // a half transparent red square placed over the bottom-right pixel of an opaque blue canvas
+//! //
+//! let src = cvr::rgba::Image::from_vec(vec![1.0_f32], vec![0.0], vec![0.0], vec![0.5], 1, 1).unwrap();
+//! let mut dst = cvr::rgba::Image::from_vec(vec![0.0_f32; 4], vec![0.0; 4], vec![1.0; 4], vec![1.0; 4], 2, 2).unwrap();
+//!
+//! cvr::composite::composite(&src, &mut dst, 1, 1, Operator::Over);
+//!
+//! assert_eq!(dst.get(0, 0), Some([0.0, 0.0, 1.0, 1.0]));
+//! assert_eq!(dst.get(1, 1), Some([0.5, 0.0, 0.5, 1.0]));
+//! ```
+//!
+
+use crate::rgba;
+use std::convert::TryFrom;
+
+/// `Operator` selects the Porter-Duff operator used to combine the source with the destination.
+///
+/// In the descriptions below, "inside" and "outside" refer to the coverage given by the alpha of the other image.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Operator {
+  /// `Over` places the source on top of the destination.
+  ///
+  #[default]
+  Over,
+
+  /// `In` keeps only the parts of the source inside of the destination, discarding the destination itself.
+  ///
+  In,
+
+  /// `Out` keeps only the parts of the source outside of the destination, discarding the destination itself.
+  ///
+  Out,
+
+  /// `Atop` places the parts of the source inside of the destination on top of it, keeping the destination's coverage.
+  ///
+  Atop,
+
+  /// `Xor` keeps the parts of the source outside of the destination and the parts of the destination outside of the
+  /// source.
+  ///
+  Xor,
+}
+
+impl Operator {
+  /// `apply` combines a single premultiplied `src` pixel with a premultiplied `dst` pixel, returning the premultiplied
+  /// result in `RGBA` ordering.
+  ///
+  #[must_use]
+  pub fn apply(self, src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    let (src_alpha, dst_alpha) = (src[3], dst[3]);
+
+    let (f_src, f_dst) = match self {
+      Operator::Over => (1.0, 1.0 - src_alpha),
+      Operator::In => (dst_alpha, 0.0),
+      Operator::Out => (1.0 - dst_alpha, 0.0),
+      Operator::Atop => (dst_alpha, 1.0 - src_alpha),
+      Operator::Xor => (1.0 - dst_alpha, 1.0 - src_alpha),
+    };
+
+    [0, 1, 2, 3].map(|c| src[c] * f_src + dst[c] * f_dst)
+  }
+
+  /// `clears_uncovered` reports whether the operator changes the destination where the source is fully transparent,
+  /// in which case the result there is always fully transparent.
+  ///
+  fn clears_uncovered(self) -> bool {
+    matches!(self, Operator::In | Operator::Out)
+  }
+}
+
+/// `premultiply` converts a linear straight alpha pixel to premultiplied alpha. Alpha is clamped to `[0.0, 1.0]`.
+///
+#[must_use]
+pub fn premultiply([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+  let a = a.clamp(0.0, 1.0);
+  [r * a, g * a, b * a, a]
+}
+
+/// `unpremultiply` converts a premultiplied alpha pixel back to straight alpha. Fully transparent pixels carry no color
+/// and become zero.
+///
+#[must_use]
+pub fn unpremultiply([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+  if a > 0.0 {
+    [r / a, g / a, b / a, a]
+  } else {
+    [0.0; 4]
+  }
+}
+
+/// `composite` combines the linear straight alpha image `src`, placed with its top-left corner at column `x` and row
+/// `y` of `dst`, with the linear straight alpha image `dst` using the supplied operator. The result is stored in `dst`
+/// with straight alpha.
+///
+pub fn composite(
+  src: &rgba::Image<f32>,
+  dst: &mut rgba::Image<f32>,
+  x: isize,
+  y: isize,
+  op: Operator,
+) {
+  blend(src, dst, (x, y), op, |s, d| {
+    unpremultiply(op.apply(premultiply(s), premultiply(d)))
+  });
+}
+
+/// `composite_premultiplied` behaves like [`composite`] except that both images, as well as the result, use
+/// premultiplied alpha, which saves converting every pixel.
+///
+pub fn composite_premultiplied(
+  src: &rgba::Image<f32>,
+  dst: &mut rgba::Image<f32>,
+  x: isize,
+  y: isize,
+  op: Operator,
+) {
+  blend(src, dst, (x, y), op, |s, d| op.apply(s, d));
+}
+
+/// `composite_srgb` behaves like [`composite`] for 8-bit `sRGBA` images with straight alpha. Both images are decoded
+/// to linear light before compositing and the result is encoded back to `sRGB`.
+///
+/// # Example
+/// ```
+/// use cvr::composite::Operator;
+///
+/// let src = cvr::rgba::Image::from_vec(vec![255_u8], vec![255], vec![255], vec![128], 1, 1).unwrap();
+/// let mut dst = cvr::rgba::Image::from_vec(vec![0_u8], vec![0], vec![0], vec![255], 1, 1).unwrap();
+///
+/// cvr::composite::composite_srgb(&src, &mut dst, 0, 0, Operator::Over);
+///
+/// // half of the light of white is far brighter than the `sRGB` midpoint
+/// //
+/// assert_eq!(dst.get(0, 0), Some([188, 188, 188, 255]));
+/// ```
+///
+pub fn composite_srgb(
+  src: &rgba::Image<u8>,
+  dst: &mut rgba::Image<u8>,
+  x: isize,
+  y: isize,
+  op: Operator,
+) {
+  let mut linear_src = rgba::Image::new();
+  let mut linear_dst = rgba::Image::new();
+
+  src.to_linear(&mut linear_src);
+  dst.to_linear(&mut linear_dst);
+
+  composite(&linear_src, &mut linear_dst, x, y, op);
+
+  linear_dst.to_srgb(dst);
+}
+
+/// `blend` applies `f` to every pixel of `dst` covered by `src` when placed at `offset`. Uncovered pixels are cleared
+/// if the operator requires it and are left untouched otherwise.
+///
+fn blend<F>(
+  src: &rgba::Image<f32>,
+  dst: &mut rgba::Image<f32>,
+  (x, y): (isize, isize),
+  op: Operator,
+  f: F,
+) where
+  F: Fn([f32; 4], [f32; 4]) -> [f32; 4],
+{
+  let height = dst.height();
+  let (cols, rows, (sx, sy)) =
+    match span(x, src.width(), dst.width()).zip(span(y, src.height(), dst.height())) {
+      Some(((cols, sx), (rows, sy))) => (cols, rows, (sx, sy)),
+      None => (0..0, 0..0, (0, 0)),
+    };
+
+  let clear = op.clears_uncovered();
+  let visited = if clear { 0..height } else { rows.clone() };
+
+  let mut src_rows = src.rows().skip(sy);
+  for (row, [r, g, b, a]) in dst
+    .rows_mut()
+    .enumerate()
+    .skip(visited.start)
+    .take(visited.len())
+  {
+    let covered = if rows.contains(&row) {
+      src_rows.next()
+    } else {
+      None
+    };
+
+    let Some([sr, sg, sb, sa]) = covered else {
+      for plane in [r, g, b, a] {
+        plane.fill(0.0);
+      }
+      continue;
+    };
+
+    for (i, col) in cols.clone().enumerate() {
+      let s = [sr[sx + i], sg[sx + i], sb[sx + i], sa[sx + i]];
+      let pixel = f(s, [r[col], g[col], b[col], a[col]]);
+
+      r[col] = pixel[0];
+      g[col] = pixel[1];
+      b[col] = pixel[2];
+      a[col] = pixel[3];
+    }
+
+    if clear {
+      for plane in [r, g, b, a] {
+        plane[..cols.start].fill(0.0);
+        plane[cols.end..].fill(0.0);
+      }
+    }
+  }
+}
+
+/// `span` returns the range of indices along a destination axis of length `len` that a source of length `src_len`
+/// placed at `offset` covers, along with the source index that lines up with the start of the range. It returns
+/// `None` if the source doesn't cover the axis at all.
+///
+fn span(offset: isize, src_len: usize, len: usize) -> Option<(std::ops::Range<usize>, usize)> {
+  let start = offset.max(0);
+  let end = offset
+    .saturating_add(isize::try_from(src_len).unwrap_or(isize::MAX))
+    .min(isize::try_from(len).unwrap_or(isize::MAX));
+
+  if start >= end {
+    return None;
+  }
+
+  let skip = usize::try_from(start.checked_sub(offset)?).ok()?;
+  Some((
+    usize::try_from(start).ok()?..usize::try_from(end).ok()?,
+    skip,
+  ))
+}
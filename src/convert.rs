@@ -19,6 +19,21 @@
 //!
 //! Read more on `sRGB` and its usages [here](https://en.wikipedia.org/wiki/SRGB#Usage).
 //!
+//! For perceptual work such as gradients or hue shifts, the [Oklab](https://bottosson.github.io/posts/oklab/) and
+//! `OkLCh` spaces give far more uniform results than `HSV`, and [`gamut_clip_oklch`] maps colors that fall outside of
+//! the `sRGB` gamut back into it without shifting their hue.
+//!
+//! Data encoded with a curve other than `sRGB`, such as `BT.709` video or `PQ` and `HLG` HDR footage, can be
+//! linearized with the curves in the [`transfer`] module.
+//!
+//! Colors can be moved between `RGB` spaces with different primaries and white points, such as `Display P3` or
+//! `Rec.2020`, using the [`gamut`] module.
+//!
+//! Whole planes can be converted with the vectorized routines of the [`kernels`] module.
+//!
+//! The [`space`](crate::space) module can be used to tag images and iterators with the color space their data is
+//! encoded in so that these mistakes are caught at compile time.
+//!
 //! # How to Convert `sRGB` to Linear
 //!
 //! ```
@@ -66,10 +81,18 @@
 //! in some cases, it is the default setting to have `sRGB` encoding enabled.
 //!
 
+pub mod gamut;
+pub mod kernels;
+pub mod transfer;
+
+mod lut;
+
 /// `srgb_to_linear` converts an `sRGB` gamma-corrected 8-bit pixel value into its corresponding
 /// value in the linear `sRGB` color space as a `f32` mapped to the range `[0, 1]`.
 ///
-/// This function is the inverse of `linear_to_srgb`.
+/// This function is the inverse of `linear_to_srgb`. It is equivalent to decoding the normalized value with
+/// [`transfer::Srgb`], see the [`transfer`] module for other curves, but reads the result from a precomputed
+/// 256-entry table instead of evaluating the curve.
 ///
 /// Notes on the algorithm and the constants used can be found [here](https://en.wikipedia.org/wiki/SRGB).
 ///
@@ -96,19 +119,7 @@
 ///
 #[must_use]
 pub fn srgb_to_linear(u: u8) -> f32 {
-  // 1/ 255.0 => 0.00392156863
-  //
-  let u = f32::from(u) * 0.003_921_569;
-
-  if u <= 0.04045 {
-    // 1 / 12.92 => 0.0773993808
-    //
-    u * 0.077_399_38
-  } else {
-    // 1/ 1.055 => 0.947867299
-    //
-    ((u + 0.055) * 0.947_867_3).powf(2.4)
-  }
+  lut::SRGB_TO_LINEAR[usize::from(u)]
 }
 
 /// `linear_to_srgb` takes a `f32` linear `sRGB` pixel value in the range `[0, 1]` and encodes it as
@@ -117,7 +128,9 @@ pub fn srgb_to_linear(u: u8) -> f32 {
 /// Note: if the gamma-corrected value exceeds `1.0` then it is automatically clipped and `255` is
 /// returned.
 ///
-/// This function is the inverse of `srgb_to_linear`.
+/// This function is the inverse of `srgb_to_linear`. Rather than evaluating the curve, it looks up the range of
+/// outputs the value's exponent and leading mantissa bits fall in and compares it against the linear value at which
+/// the next 8-bit output begins. The result is identical to rounding the value encoded by [`transfer::Srgb`].
 ///
 /// Notes on the algorithm and the constants used can be found [here](https://en.wikipedia.org/wiki/SRGB#Specification_of_the_transformation).
 ///
@@ -145,23 +158,13 @@ pub fn srgb_to_linear(u: u8) -> f32 {
 #[must_use]
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 pub fn linear_to_srgb(u: f32) -> u8 {
-  let u = if u <= 0.003_130_8 {
-    12.92 * u
-  } else {
-    // 1 / 2.4 => 0.416666667
-    //
-    1.055 * u.powf(0.416_666_66) - 0.055
-  };
-
-  if u >= 1.0 {
-    return 255;
-  }
-
-  if u < 0.0 {
-    return 0;
-  }
+  // negative values and `NaN` map to `0`
+  //
+  let x = if u >= 0.0 { u.min(1.0) } else { 0.0 };
+  let x = x.max(f32::from_bits(lut::BUCKET_MIN_BITS));
 
-  (255.0 * u).round() as u8
+  let k = lut::SRGB_BUCKETS[lut::bucket(x)] as usize;
+  (k + usize::from(lut::SRGB_THRESHOLDS[k + 1] <= x)) as u8
 }
 
 /// `linear_to_gray` takes the provided linearized `RGB` pixel value and converts it to its
@@ -173,26 +176,107 @@ pub fn linear_to_gray([r, g, b]: [f32; 3]) -> f32 {
   0.212_639 * r + 0.715_168_7 * g + 0.072_192_32 * b
 }
 
+/// `GrayWeights` selects the coefficients used to reduce the three channels of an `RGB` pixel to a single gray value.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GrayWeights {
+  /// `Luminance` uses the coefficients of [`linear_to_gray`], yielding the `Y` component of the CIE XYZ color space.
+  /// These weights are only physically meaningful when applied to linear data.
+  ///
+  #[default]
+  Luminance,
+
+  /// `Bt601` uses the ITU-R BT.601 luma coefficients `(0.299, 0.587, 0.114)`, which are conventionally applied
+  /// directly to gamma-encoded data. This matches the behavior of `OpenCV`'s `cvtColor`.
+  ///
+  Bt601,
+}
+
+impl GrayWeights {
+  /// `coefficients` returns the weights applied to the red, green and blue channels respectively.
+  ///
+  #[must_use]
+  #[allow(clippy::mistyped_literal_suffixes)]
+  pub fn coefficients(self) -> [f32; 3] {
+    match self {
+      GrayWeights::Luminance => [0.212_639, 0.715_168_7, 0.072_192_32],
+      GrayWeights::Bt601 => [0.299, 0.587, 0.114],
+    }
+  }
+}
+
+/// `RangeError` identifies the channel of a pixel whose value lies outside of the range a conversion accepts. `NaN`
+/// values are always out of range.
+///
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq)]
+pub struct RangeError {
+  /// The index of the offending channel within the pixel, i.e. `0` for `R` or `H`.
+  pub channel: usize,
+  /// The value that was supplied for the channel.
+  pub value: f32,
+}
+
+impl std::fmt::Display for RangeError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "channel {} is out of range: {}",
+      self.channel, self.value
+    )
+  }
+}
+
+impl std::error::Error for RangeError {}
+
+/// `check_range` verifies that every channel of `pixel` lies within the matching inclusive bound of `bounds`.
+///
+fn check_range(pixel: [f32; 3], bounds: [(f32, f32); 3]) -> Result<[f32; 3], RangeError> {
+  for (channel, (value, (min, max))) in pixel.iter().copied().zip(bounds).enumerate() {
+    if !(min..=max).contains(&value) {
+      return Err(RangeError { channel, value });
+    }
+  }
+
+  Ok(pixel)
+}
+
+/// `nan_to_zero` replaces `NaN` with `0.0`, leaving every other value untouched.
+///
+fn nan_to_zero(x: f32) -> f32 {
+  if x.is_nan() {
+    0.0
+  } else {
+    x
+  }
+}
+
+/// `wrap_hue` wraps the supplied hue in degrees around the color wheel into the range `[0.0, 360.0]`, mapping
+/// non-finite hues to `0.0`. Hues already within the range are returned unchanged.
+///
+fn wrap_hue(h: f32) -> f32 {
+  if (0.0..=360.0).contains(&h) {
+    h
+  } else if h.is_finite() {
+    h.rem_euclid(360.0)
+  } else {
+    0.0
+  }
+}
+
 /// `linear_to_hsv` takes the provided linearized `RGB` pixel values and converts them to their
 /// representation in the `HSV` color space [using the equation provided here](https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB).
 ///
 /// The returned array is in `(H, S, V)` ordering with `H` in the range `[0.0, 360.0]` and `S`, `V`
 /// both within the range `[0.0, 1.0]`.
 ///
-/// # Panics
-///
-/// Panics in debug builds if the supplied `[r, g, b]` values are not within the range `[0.0, 1.0]`.
-///
-/// # Safety
-///
-/// While not technically unsafe, `(R, G, B)` values are assumed to be in the range `[0.0, 1.0]`.
+/// Inputs outside of the range `[0.0, 1.0]` are well-defined: `NaN` channels are treated as `0.0` and all other values
+/// are run through the same equation, which keeps `H` within its range for any finite input but lets `S` and `V`
+/// leave theirs. Use [`linear_to_hsv_clamped`] to clamp the input first or [`try_linear_to_hsv`] to reject it.
 ///
 #[must_use]
 #[allow(clippy::float_cmp, clippy::many_single_char_names)]
-pub fn linear_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
-  debug_assert!((0.0..=1.0).contains(&r));
-  debug_assert!((0.0..=1.0).contains(&g));
-  debug_assert!((0.0..=1.0).contains(&b));
+pub fn linear_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+  let [r, g, b] = rgb.map(nan_to_zero);
 
   let x_max = r.max(g).max(b);
   let x_min = r.min(g).min(b);
@@ -201,16 +285,20 @@ pub fn linear_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
 
   let v = x_max;
 
+  // the chroma overflows for channels of opposite signs near `f32::MAX`, in which case the hue is computed from the
+  // halved channels instead, as that keeps their differences finite without changing their ratios
+  //
+  let k = if c == f32::INFINITY { 0.5 } else { 1.0 };
+  let d = k * x_max - k * x_min;
+
   let h = if c == 0.0 {
     0.0
   } else if v == r {
-    60.0 * (0.0 + (g - b) / c)
+    60.0 * (0.0 + (k * g - k * b) / d)
   } else if v == g {
-    60.0 * (2.0 + (b - r) / c)
-  } else if v == b {
-    60.0 * (4.0 + (r - g) / c)
+    60.0 * (2.0 + (k * b - k * r) / d)
   } else {
-    unsafe { std::hint::unreachable_unchecked() };
+    60.0 * (4.0 + (k * r - k * g) / d)
   };
 
   let s = if v == 0.0 { 0.0 } else { c / v };
@@ -219,164 +307,1426 @@ pub fn linear_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
   [h, s, v]
 }
 
-/// `hsv_to_linear` takes an `HSV` triple and converts it to its corresponding values in the linear
-/// `RGB` color space.
+/// `linear_to_hsv_clamped` clamps the supplied linear `RGB` values to the range `[0.0, 1.0]`, mapping `NaN` to `0.0`,
+/// before converting them to `HSV` with [`linear_to_hsv`]. The outputs are always within their documented ranges.
 ///
-/// The input hue must be in the range `[0.0, 360.0]` and the `S` and `V` values must be in the
-/// range `[0.0, 1.0]`.
+#[must_use]
+pub fn linear_to_hsv_clamped(rgb: [f32; 3]) -> [f32; 3] {
+  linear_to_hsv(rgb.map(|x| nan_to_zero(x).clamp(0.0, 1.0)))
+}
+
+/// `try_linear_to_hsv` converts the supplied linear `RGB` values to `HSV` with [`linear_to_hsv`] after checking
+/// that every channel is within the range `[0.0, 1.0]`.
 ///
-/// # Panics
+/// # Errors
+///
+/// Returns a [`RangeError`] naming the first channel that is outside of the range `[0.0, 1.0]` or `NaN`.
 ///
-/// Panics in debug builds if the supplied `[h, s, v]` values exceed their bounds, i.e. if `h` is
-/// not within the range `[0.0, 360.0]` and `s` or `v` are outside the range `[0.0, 1.0]`.
+pub fn try_linear_to_hsv(rgb: [f32; 3]) -> Result<[f32; 3], RangeError> {
+  check_range(rgb, [(0.0, 1.0); 3]).map(linear_to_hsv)
+}
+
+/// `hsv_to_linear` takes an `HSV` triple and converts it to its corresponding values in the linear
+/// `RGB` color space.
 ///
-/// # Safety
+/// The input hue is expected to be in the range `[0.0, 360.0]` and the `S` and `V` values in the
+/// range `[0.0, 1.0]`.
 ///
-/// While not explicitly `unsafe`, this function has implicit contracts on the ranges of its inputs
-/// and isn't guaranteed to be correct or `panic!` for values outside those ranges.
+/// Inputs outside of these ranges are well-defined: `NaN` channels are treated as `0.0`, hues are wrapped around the
+/// color wheel, with infinite hues becoming `0.0`, and out of range `S` and `V` values are run through the same
+/// equation. Use [`hsv_to_linear_clamped`] to clamp the input first or [`try_hsv_to_linear`] to reject it.
 ///
 #[must_use]
-#[allow(clippy::many_single_char_names, clippy::manual_range_contains)]
-pub fn hsv_to_linear([h, s, v]: [f32; 3]) -> [f32; 3] {
-  debug_assert!((0.0..=360.0).contains(&h));
-  debug_assert!((0.0..=1.0).contains(&s));
-  debug_assert!((0.0..=1.0).contains(&v));
+#[allow(clippy::many_single_char_names)]
+pub fn hsv_to_linear(hsv: [f32; 3]) -> [f32; 3] {
+  let [h, s, v] = hsv.map(nan_to_zero);
+  let h = wrap_hue(h);
 
   let c = s * v;
 
   let h = h / 60.0;
   let x = c * (1.0 - (h % 2.0 - 1.0).abs());
 
+  // the wrapped hue lies within `[0.0, 6.0]` so the final branch covers the last sextant
+  //
   let (r, g, b) = if c == 0.0 {
     (0.0, 0.0, 0.0)
-  } else if h >= 0.0 && h <= 1.0 {
+  } else if h <= 1.0 {
     (c, x, 0.0)
-  } else if h > 1.0 && h <= 2.0 {
+  } else if h <= 2.0 {
     (x, c, 0.0)
-  } else if h > 2.0 && h <= 3.0 {
+  } else if h <= 3.0 {
     (0.0, c, x)
-  } else if h > 3.0 && h <= 4.0 {
+  } else if h <= 4.0 {
     (0.0, x, c)
-  } else if h > 4.0 && h <= 5.0 {
+  } else if h <= 5.0 {
     (x, 0.0, c)
-  } else if h > 5.0 && h <= 6.0 {
-    (c, 0.0, x)
   } else {
-    std::unreachable!();
+    (c, 0.0, x)
   };
 
   let m = v - c;
   [r + m, g + m, b + m]
 }
 
-/// `iter` contains the set of conversion iterators that enable lazy color space conversions.
+/// `hsv_to_linear_clamped` wraps the supplied hue around the color wheel and clamps `S` and `V` to the range
+/// `[0.0, 1.0]`, mapping `NaN` to `0.0`, before converting the pixel with [`hsv_to_linear`]. The outputs are always
+/// within the range `[0.0, 1.0]`.
 ///
-pub mod iter {
-  use super::{hsv_to_linear, linear_to_gray, linear_to_hsv, linear_to_srgb, srgb_to_linear};
+#[must_use]
+pub fn hsv_to_linear_clamped([h, s, v]: [f32; 3]) -> [f32; 3] {
+  hsv_to_linear([
+    h,
+    nan_to_zero(s).clamp(0.0, 1.0),
+    nan_to_zero(v).clamp(0.0, 1.0),
+  ])
+}
 
-  /// `SRGBToLinear` lazily converts 8-bit `sRGB` pixels to their linear floating point
-  /// counterparts.
-  ///
-  pub type SRGBToLinear<I> = std::iter::Map<I, fn([u8; 3]) -> [f32; 3]>;
+/// `try_hsv_to_linear` converts the supplied `HSV` values to linear `RGB` with [`hsv_to_linear`] after checking that
+/// the hue is within the range `[0.0, 360.0]` and `S` and `V` are within the range `[0.0, 1.0]`.
+///
+/// # Errors
+///
+/// Returns a [`RangeError`] naming the first channel that is outside of its range or `NaN`.
+///
+pub fn try_hsv_to_linear(hsv: [f32; 3]) -> Result<[f32; 3], RangeError> {
+  check_range(hsv, [(0.0, 360.0), (0.0, 1.0), (0.0, 1.0)]).map(hsv_to_linear)
+}
 
-  /// `SRGBLinear` is the public trait `std::iter::Iterator` types implement to enable
-  /// `.srgb_to_linear()` as an iterator adapter.
-  ///
-  pub trait SRGBLinearIterator: std::iter::Iterator<Item = [u8; 3]>
-  where
-    Self: Sized,
-  {
-    /// `srgb_to_linear` converts the current `Iterator` to a [`iter::SRGBToLinear`](crate::convert::iter::SRGBToLinear).
-    ///
-    fn srgb_to_linear(self) -> SRGBToLinear<Self> {
-      self.map(|[r, g, b]| [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)])
-    }
-  }
+/// `linear_to_hsl` takes the provided linearized `RGB` pixel values and converts them to their
+/// representation in the `HSL` color space [using the equation provided here](https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB).
+///
+/// The returned array is in `(H, S, L)` ordering with `H` in the range `[0.0, 360.0)` and `S`, `L`
+/// both within the range `[0.0, 1.0]`.
+///
+/// # Panics
+///
+/// Panics in debug builds if the supplied `[r, g, b]` values are not within the range `[0.0, 1.0]`.
+///
+#[must_use]
+#[allow(clippy::float_cmp, clippy::many_single_char_names)]
+pub fn linear_to_hsl([r, g, b]: [f32; 3]) -> [f32; 3] {
+  debug_assert!((0.0..=1.0).contains(&r));
+  debug_assert!((0.0..=1.0).contains(&g));
+  debug_assert!((0.0..=1.0).contains(&b));
 
-  impl<Iter> SRGBLinearIterator for Iter where Iter: std::iter::Iterator<Item = [u8; 3]> {}
+  let x_max = r.max(g).max(b);
+  let x_min = r.min(g).min(b);
 
-  /// `LinearToSRGBIter` lazily converts linear floating point `(R, G, B)` data into its
-  /// 8-bit `sRGB` representation.
-  ///
-  pub type LinearToSRGB<I> = std::iter::Map<I, fn([f32; 3]) -> [u8; 3]>;
+  let c = x_max - x_min;
+  let l = 0.5 * (x_max + x_min);
 
-  /// `LinearToSRGB` is the public trait `std::iter::Iterator` types implement to enable
-  /// `.linear_to_srgb()` as an iterator adapter.
-  ///
-  #[allow(clippy::type_complexity)]
-  pub trait LinearSRGBIterator: std::iter::Iterator<Item = [f32; 3]>
-  where
-    Self: Sized,
-  {
-    /// `linear_to_srgb` converts the current `Iterator` to a [`iter::LinearToSRGB`](crate::convert::iter::LinearToSRGB).
-    ///
-    fn linear_to_srgb(self) -> LinearToSRGB<Self> {
-      self.map(|[r, g, b]| [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)])
-    }
-  }
+  let s = if l == 0.0 || l == 1.0 {
+    0.0
+  } else {
+    c / (1.0 - (2.0 * l - 1.0).abs())
+  };
 
-  impl<Iter> LinearSRGBIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+  [hexagonal_hue([r, g, b], x_max, c), s, l]
+}
 
-  /// `LinearToGray` lazily converts linearized `f32` pixel values to their corresponding
-  /// [luminance in the CIE XYZ color space](https://en.wikipedia.org/wiki/CIE_1931_color_space#Meaning_of_X,_Y_and_Z).
-  ///
-  pub type LinearToGray<I> = std::iter::Map<I, fn([f32; 3]) -> f32>;
+/// `hsl_to_linear` takes an `HSL` triple and converts it to its corresponding values in the linear
+/// `RGB` color space.
+///
+/// The input hue must be in the range `[0.0, 360.0]` and the `S` and `L` values must be in the
+/// range `[0.0, 1.0]`.
+///
+/// # Panics
+///
+/// Panics in debug builds if the supplied `[h, s, l]` values exceed their bounds.
+///
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn hsl_to_linear([h, s, l]: [f32; 3]) -> [f32; 3] {
+  debug_assert!((0.0..=360.0).contains(&h));
+  debug_assert!((0.0..=1.0).contains(&s));
+  debug_assert!((0.0..=1.0).contains(&l));
 
-  /// `LinearGrayIterator` is the public trait implemented for all `Iterator` types that enables
-  /// the adapter `linear_to_gray()` to be invoked.
-  ///
-  pub trait LinearGrayIterator: std::iter::Iterator<Item = [f32; 3]>
-  where
-    Self: Sized,
-  {
-    /// `linear_to_gray` converts the current `Iterator` into a [`iter::LinearToGray`](crate::convert::iter::LinearToGray).
-    ///
-    fn linear_to_gray(self) -> LinearToGray<Self> {
-      self.map(linear_to_gray)
-    }
-  }
+  let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+  let [r, g, b] = hexagonal_chroma(h, c);
 
-  impl<Iter> LinearGrayIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+  let m = l - c / 2.0;
+  [r + m, g + m, b + m]
+}
 
-  /// `LinearToHSV` lazily converts linearized `f32` pixel values to their corresponding
-  /// [HSV values](https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB).
-  ///
-  pub type LinearToHSV<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+/// `linear_to_hsi` takes the provided linearized `RGB` pixel values and converts them to their
+/// representation in the `HSI` color space, using the geometric hue definition found in
+/// Gonzalez & Woods' _Digital Image Processing_.
+///
+/// The returned array is in `(H, S, I)` ordering with `H` in the range `[0.0, 360.0)` and `S`, `I`
+/// both within the range `[0.0, 1.0]`. Achromatic pixels have a hue of `0.0`.
+///
+/// # Panics
+///
+/// Panics in debug builds if the supplied `[r, g, b]` values are not within the range `[0.0, 1.0]`.
+///
+#[must_use]
+#[allow(clippy::float_cmp, clippy::many_single_char_names)]
+pub fn linear_to_hsi([r, g, b]: [f32; 3]) -> [f32; 3] {
+  debug_assert!((0.0..=1.0).contains(&r));
+  debug_assert!((0.0..=1.0).contains(&g));
+  debug_assert!((0.0..=1.0).contains(&b));
 
-  /// `LinearHSVIterator` is the public trait implemented for all `Iterator` types that enables
-  /// the adapter `linear_to_hsv()` to be invoked.
-  ///
-  pub trait LinearHSVIterator: std::iter::Iterator<Item = [f32; 3]>
-  where
-    Self: Sized,
-  {
-    /// `linear_to_hsv` transforms the current `Iterator` into a [`iter::LinearToHSV`](crate::convert::iter::LinearToHSV).
-    ///
-    fn linear_to_hsv(self) -> LinearToHSV<Self> {
-      self.map(linear_to_hsv)
+  let i = (r + g + b) / 3.0;
+  let x_min = r.min(g).min(b);
+
+  let s = if i == 0.0 { 0.0 } else { 1.0 - x_min / i };
+
+  let numerator = 0.5 * ((r - g) + (r - b));
+  let denominator = ((r - g) * (r - g) + (r - b) * (g - b)).sqrt();
+
+  let h = if denominator == 0.0 {
+    0.0
+  } else {
+    let theta = (numerator / denominator)
+      .clamp(-1.0, 1.0)
+      .acos()
+      .to_degrees();
+    if b > g {
+      360.0 - theta
+    } else {
+      theta
     }
+  };
+
+  [h % 360.0, s, i]
+}
+
+/// `hsi_to_linear` takes an `HSI` triple and converts it to its corresponding values in the linear
+/// `RGB` color space.
+///
+/// The input hue must be in the range `[0.0, 360.0]` and the `S` and `I` values must be in the
+/// range `[0.0, 1.0]`. Not every `HSI` triple maps to an `RGB` value within `[0.0, 1.0]`, the
+/// result is not clamped.
+///
+/// # Panics
+///
+/// Panics in debug builds if the supplied `[h, s, i]` values exceed their bounds.
+///
+#[must_use]
+#[allow(clippy::float_cmp, clippy::many_single_char_names)]
+pub fn hsi_to_linear([h, s, i]: [f32; 3]) -> [f32; 3] {
+  debug_assert!((0.0..=360.0).contains(&h));
+  debug_assert!((0.0..=1.0).contains(&s));
+  debug_assert!((0.0..=1.0).contains(&i));
+
+  let h = h % 360.0;
+
+  // each 120 degree sector is handled identically, with the roles of the channels rotated
+  //
+  let sector_start = if h < 120.0 {
+    0.0
+  } else if h < 240.0 {
+    120.0
+  } else {
+    240.0
+  };
+
+  let h = (h - sector_start).to_radians();
+
+  let low = i * (1.0 - s);
+  let high = i * (1.0 + s * h.cos() / (std::f32::consts::FRAC_PI_3 - h).cos());
+  let mid = 3.0 * i - (low + high);
+
+  if sector_start == 0.0 {
+    [high, mid, low]
+  } else if sector_start == 120.0 {
+    [low, high, mid]
+  } else {
+    [mid, low, high]
   }
+}
 
-  impl<Iter> LinearHSVIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+/// `WhitePoint` is the `XYZ` tristimulus value of a reference white, normalized so that `Y = 1.0`.
+///
+/// The perceptual `L*a*b*` and `L*u*v*` spaces are defined relative to a reference white. The [`Default`] is
+/// [`WhitePoint::D65`], the white point of the `sRGB` color space. Passing a different white point to [`xyz_to_lab`]
+/// and friends only changes the normalization, no chromatic adaptation is performed. Use a [`CieReference`] to adapt
+/// linear `sRGB` values to the white point first.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WhitePoint {
+  /// The `X` component of the reference white.
+  pub x: f32,
+  /// The `Y` component of the reference white.
+  pub y: f32,
+  /// The `Z` component of the reference white.
+  pub z: f32,
+}
 
-  /// `HSVToLinear` lazily converts linearized `f32` pixel values to their corresponding
-  /// [RGB values](https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB).
+impl WhitePoint {
+  /// `D65` is CIE standard illuminant D65, approximating average daylight, as used by `sRGB`.
   ///
-  pub type HSVToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+  pub const D65: WhitePoint = WhitePoint {
+    x: 0.950_455_9,
+    y: 1.0,
+    z: 1.089_057_8,
+  };
 
-  /// `HSVLinearIterator` is the public trait implemented for all `Iterator` types that enables
-  /// the adapter `hsv_to_linear()` to be invoked.
+  /// `D50` is CIE standard illuminant D50, approximating horizon light, as used by ICC profiles and printing.
   ///
-  pub trait HSVLinearIterator: std::iter::Iterator<Item = [f32; 3]>
-  where
-    Self: Sized,
-  {
-    /// `hsv_to_linear` converts the current `Iterator` to a [`iter::HSVToLinear`](crate::convert::iter::HSVToLinear).
-    ///
-    fn hsv_to_linear(self) -> HSVToLinear<Self> {
-      self.map(hsv_to_linear)
+  pub const D50: WhitePoint = WhitePoint {
+    x: 0.964_295_7,
+    y: 1.0,
+    z: 0.825_104_6,
+  };
+
+  /// `from_chromaticity` constructs a `WhitePoint` from its `(x, y)` chromaticity coordinates.
+  ///
+  #[must_use]
+  pub fn from_chromaticity(x: f32, y: f32) -> Self {
+    WhitePoint {
+      x: x / y,
+      y: 1.0,
+      z: (1.0 - x - y) / y,
     }
   }
 
-  impl<Iter> HSVLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+  /// `xyz` returns the reference white as an `[X, Y, Z]` triple.
+  ///
+  #[must_use]
+  pub fn xyz(self) -> [f32; 3] {
+    [self.x, self.y, self.z]
+  }
+}
+
+impl Default for WhitePoint {
+  fn default() -> Self {
+    WhitePoint::D65
+  }
+}
+
+/// `CIE_EPSILON` is the threshold below which the `L*` lightness curve becomes linear.
+///
+const CIE_EPSILON: f32 = 216.0 / 24389.0;
+
+/// `CIE_KAPPA` is the slope of the linear segment of the `L*` lightness curve.
+///
+const CIE_KAPPA: f32 = 24389.0 / 27.0;
+
+/// `linear_to_xyz` converts the provided linearized `RGB` pixel values to the
+/// [CIE 1931 XYZ color space](https://en.wikipedia.org/wiki/CIE_1931_color_space), relative to the `D65` white point.
+///
+/// The `Y` component is identical to the luminance computed by [`linear_to_gray`].
+///
+#[must_use]
+#[allow(clippy::mistyped_literal_suffixes)]
+pub fn linear_to_xyz([r, g, b]: [f32; 3]) -> [f32; 3] {
+  [
+    0.412_390_8 * r + 0.357_584_3 * g + 0.180_480_8 * b,
+    0.212_639 * r + 0.715_168_7 * g + 0.072_192_32 * b,
+    0.019_330_8 * r + 0.119_194_8 * g + 0.950_532_2 * b,
+  ]
+}
+
+/// `xyz_to_linear` converts the provided `XYZ` values, relative to the `D65` white point, to linear `RGB`.
+///
+/// This function is the inverse of `linear_to_xyz`. Colors outside of the `sRGB` gamut produce channel values outside
+/// of the range `[0.0, 1.0]`, which are not clamped.
+///
+#[must_use]
+pub fn xyz_to_linear([x, y, z]: [f32; 3]) -> [f32; 3] {
+  [
+    3.240_97 * x - 1.537_383_2 * y - 0.498_610_76 * z,
+    -0.969_243_6 * x + 1.875_967_5 * y + 0.041_555_06 * z,
+    0.055_630_08 * x - 0.203_976_96 * y + 1.056_971_5 * z,
+  ]
+}
+
+/// `xyz_to_lab` converts the provided `XYZ` values to the [CIELAB](https://en.wikipedia.org/wiki/CIELAB_color_space)
+/// color space relative to the reference `white`.
+///
+/// The returned array is in `(L*, a*, b*)` ordering with `L*` in the range `[0.0, 100.0]` for colors no brighter
+/// than the reference white.
+///
+#[must_use]
+pub fn xyz_to_lab([x, y, z]: [f32; 3], white: WhitePoint) -> [f32; 3] {
+  let f = |t: f32| {
+    if t > CIE_EPSILON {
+      t.cbrt()
+    } else {
+      (CIE_KAPPA * t + 16.0) / 116.0
+    }
+  };
+
+  let (fx, fy, fz) = (f(x / white.x), f(y / white.y), f(z / white.z));
+
+  [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// `lab_to_xyz` converts the provided `L*a*b*` values, relative to the reference `white`, back to `XYZ`.
+///
+/// This function is the inverse of `xyz_to_lab`.
+///
+#[must_use]
+pub fn lab_to_xyz([l, a, b]: [f32; 3], white: WhitePoint) -> [f32; 3] {
+  let fy = (l + 16.0) / 116.0;
+  let fx = fy + a / 500.0;
+  let fz = fy - b / 200.0;
+
+  let f_inv = |t: f32| {
+    let t3 = t * t * t;
+    if t3 > CIE_EPSILON {
+      t3
+    } else {
+      (116.0 * t - 16.0) / CIE_KAPPA
+    }
+  };
+
+  let yr = if l > CIE_KAPPA * CIE_EPSILON {
+    fy * fy * fy
+  } else {
+    l / CIE_KAPPA
+  };
+
+  [white.x * f_inv(fx), white.y * yr, white.z * f_inv(fz)]
+}
+
+/// `xyz_to_luv` converts the provided `XYZ` values to the [CIELUV](https://en.wikipedia.org/wiki/CIELUV) color space
+/// relative to the reference `white`.
+///
+/// The returned array is in `(L*, u*, v*)` ordering. Black maps to `[0.0, 0.0, 0.0]`.
+///
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn xyz_to_luv([x, y, z]: [f32; 3], white: WhitePoint) -> [f32; 3] {
+  let yr = y / white.y;
+  let l = if yr > CIE_EPSILON {
+    116.0 * yr.cbrt() - 16.0
+  } else {
+    CIE_KAPPA * yr
+  };
+
+  let denominator = x + 15.0 * y + 3.0 * z;
+  if denominator <= 0.0 {
+    return [l, 0.0, 0.0];
+  }
+
+  let [un, vn] = uv_chromaticity(white.xyz());
+  let (u, v) = (4.0 * x / denominator, 9.0 * y / denominator);
+
+  [l, 13.0 * l * (u - un), 13.0 * l * (v - vn)]
+}
+
+/// `luv_to_xyz` converts the provided `L*u*v*` values, relative to the reference `white`, back to `XYZ`.
+///
+/// This function is the inverse of `xyz_to_luv`.
+///
+#[must_use]
+pub fn luv_to_xyz([l, u, v]: [f32; 3], white: WhitePoint) -> [f32; 3] {
+  if l <= 0.0 {
+    return [0.0, 0.0, 0.0];
+  }
+
+  let [un, vn] = uv_chromaticity(white.xyz());
+  let u = u / (13.0 * l) + un;
+  let v = v / (13.0 * l) + vn;
+
+  let y = white.y
+    * if l > CIE_KAPPA * CIE_EPSILON {
+      let fy = (l + 16.0) / 116.0;
+      fy * fy * fy
+    } else {
+      l / CIE_KAPPA
+    };
+
+  [
+    y * 9.0 * u / (4.0 * v),
+    y,
+    y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v),
+  ]
+}
+
+/// `linear_to_lab` converts the provided linearized `RGB` pixel values to `L*a*b*` relative to the `D65` white point.
+///
+#[must_use]
+pub fn linear_to_lab(rgb: [f32; 3]) -> [f32; 3] {
+  xyz_to_lab(linear_to_xyz(rgb), WhitePoint::D65)
+}
+
+/// `lab_to_linear` converts the provided `L*a*b*` values, relative to the `D65` white point, to linear `RGB`.
+///
+#[must_use]
+pub fn lab_to_linear(lab: [f32; 3]) -> [f32; 3] {
+  xyz_to_linear(lab_to_xyz(lab, WhitePoint::D65))
+}
+
+/// `linear_to_luv` converts the provided linearized `RGB` pixel values to `L*u*v*` relative to the `D65` white point.
+///
+#[must_use]
+pub fn linear_to_luv(rgb: [f32; 3]) -> [f32; 3] {
+  xyz_to_luv(linear_to_xyz(rgb), WhitePoint::D65)
+}
+
+/// `luv_to_linear` converts the provided `L*u*v*` values, relative to the `D65` white point, to linear `RGB`.
+///
+#[must_use]
+pub fn luv_to_linear(luv: [f32; 3]) -> [f32; 3] {
+  xyz_to_linear(luv_to_xyz(luv, WhitePoint::D65))
+}
+
+/// `CieReference` converts between linear `sRGB` and the `L*a*b*` and `L*u*v*` color spaces relative to an arbitrary
+/// reference white.
+///
+/// Linear `sRGB` values are relative to `D65`, so their `XYZ` values are chromatically adapted to the reference white
+/// with the selected [`Adaptation`](gamut::Adaptation) before being normalized, and adapted back on the way out. The
+/// [`Default`] uses [`WhitePoint::D65`], for which no adaptation is needed and every conversion matches its
+/// `D65`-relative counterpart, such as [`linear_to_lab`].
+///
+/// # Example
+/// ```
+/// use cvr::convert::gamut::Adaptation;
+/// use cvr::convert::{CieReference, WhitePoint};
+///
+/// let d50 = CieReference::new(WhitePoint::D50, Adaptation::Bradford);
+///
+/// // the `sRGB` white is adapted to `D50` and remains neutral
+/// //
+/// let [l, a, b] = d50.linear_to_lab([1.0, 1.0, 1.0]);
+/// assert!((l - 100.0).abs() < 1e-3);
+/// assert!(a.abs() < 1e-2 && b.abs() < 1e-2);
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CieReference {
+  white: WhitePoint,
+  adaptation: Option<(gamut::Matrix, gamut::Matrix)>,
+}
+
+impl CieReference {
+  /// `new` constructs the reference for the supplied `white` point, adapting to it with the `method` transform.
+  ///
+  #[must_use]
+  pub fn new(white: WhitePoint, method: gamut::Adaptation) -> Self {
+    let adaptation = if white == WhitePoint::D65 {
+      None
+    } else {
+      Some((
+        gamut::adaptation_matrix(WhitePoint::D65, white, method),
+        gamut::adaptation_matrix(white, WhitePoint::D65, method),
+      ))
+    };
+
+    CieReference { white, adaptation }
+  }
+
+  /// `white` returns the reference white point.
+  ///
+  #[must_use]
+  pub fn white(&self) -> WhitePoint {
+    self.white
+  }
+
+  /// `linear_to_xyz` converts the provided linearized `RGB` pixel values to `XYZ`, adapted to the reference white.
+  ///
+  #[must_use]
+  pub fn linear_to_xyz(&self, rgb: [f32; 3]) -> [f32; 3] {
+    let xyz = linear_to_xyz(rgb);
+    match self.adaptation {
+      Some((to_white, _)) => transform(&to_white, xyz),
+      None => xyz,
+    }
+  }
+
+  /// `xyz_to_linear` converts the provided `XYZ` values, relative to the reference white, to linear `RGB`.
+  ///
+  /// This function is the inverse of `linear_to_xyz`.
+  ///
+  #[must_use]
+  pub fn xyz_to_linear(&self, xyz: [f32; 3]) -> [f32; 3] {
+    xyz_to_linear(match self.adaptation {
+      Some((_, from_white)) => transform(&from_white, xyz),
+      None => xyz,
+    })
+  }
+
+  /// `linear_to_lab` converts the provided linearized `RGB` pixel values to `L*a*b*` relative to the reference white.
+  ///
+  #[must_use]
+  pub fn linear_to_lab(&self, rgb: [f32; 3]) -> [f32; 3] {
+    xyz_to_lab(self.linear_to_xyz(rgb), self.white)
+  }
+
+  /// `lab_to_linear` converts the provided `L*a*b*` values, relative to the reference white, to linear `RGB`.
+  ///
+  #[must_use]
+  pub fn lab_to_linear(&self, lab: [f32; 3]) -> [f32; 3] {
+    self.xyz_to_linear(lab_to_xyz(lab, self.white))
+  }
+
+  /// `linear_to_luv` converts the provided linearized `RGB` pixel values to `L*u*v*` relative to the reference white.
+  ///
+  #[must_use]
+  pub fn linear_to_luv(&self, rgb: [f32; 3]) -> [f32; 3] {
+    xyz_to_luv(self.linear_to_xyz(rgb), self.white)
+  }
+
+  /// `luv_to_linear` converts the provided `L*u*v*` values, relative to the reference white, to linear `RGB`.
+  ///
+  #[must_use]
+  pub fn luv_to_linear(&self, luv: [f32; 3]) -> [f32; 3] {
+    self.xyz_to_linear(luv_to_xyz(luv, self.white))
+  }
+}
+
+impl Default for CieReference {
+  fn default() -> Self {
+    CieReference::from(WhitePoint::D65)
+  }
+}
+
+impl From<WhitePoint> for CieReference {
+  /// `from` constructs the reference for the supplied white point using the default
+  /// [`Adaptation`](gamut::Adaptation).
+  ///
+  fn from(white: WhitePoint) -> Self {
+    CieReference::new(white, gamut::Adaptation::default())
+  }
+}
+
+/// `linear_to_oklab` converts the provided linearized `RGB` pixel values to the perceptual
+/// [Oklab](https://bottosson.github.io/posts/oklab/) color space.
+///
+/// The returned array is in `(L, a, b)` ordering with `L` in the range `[0.0, 1.0]` for colors within the `sRGB`
+/// gamut and `a`, `b` roughly within the range `[-0.4, 0.4]`. Unlike `HSV`, equal distances in Oklab correspond to
+/// roughly equal perceived differences, which makes it well suited to gradients and palette generation.
+///
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn linear_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+  let l = (0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+  let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+  let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+  [
+    0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+    1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+    0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+  ]
+}
+
+/// `oklab_to_linear` converts the provided Oklab values back to linear `RGB`.
+///
+/// This function is the inverse of `linear_to_oklab`. Colors outside of the `sRGB` gamut produce channel values
+/// outside of the range `[0.0, 1.0]`, which are not clamped; see [`gamut_clip_oklch`] for mapping them back into it.
+///
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+  let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+  let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+  let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+  let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+  [
+    4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+    -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+    -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+  ]
+}
+
+/// `oklab_to_oklch` converts the provided Oklab values to their cylindrical `OkLCh` representation.
+///
+/// The returned array is in `(L, C, h)` ordering with the hue `h` in degrees within the range `[0.0, 360.0)`.
+/// Achromatic colors have a hue of `0.0`.
+///
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn oklab_to_oklch([l, a, b]: [f32; 3]) -> [f32; 3] {
+  let c = a.hypot(b);
+  let h = if c == 0.0 {
+    0.0
+  } else {
+    b.atan2(a).to_degrees().rem_euclid(360.0)
+  };
+
+  [l, c, h]
+}
+
+/// `oklch_to_oklab` converts the provided `OkLCh` values, with the hue in degrees, back to Oklab.
+///
+#[must_use]
+pub fn oklch_to_oklab([l, c, h]: [f32; 3]) -> [f32; 3] {
+  let (sin, cos) = h.to_radians().sin_cos();
+  [l, c * cos, c * sin]
+}
+
+/// `linear_to_oklch` converts the provided linearized `RGB` pixel values to `OkLCh`.
+///
+#[must_use]
+pub fn linear_to_oklch(rgb: [f32; 3]) -> [f32; 3] {
+  oklab_to_oklch(linear_to_oklab(rgb))
+}
+
+/// `oklch_to_linear` converts the provided `OkLCh` values to linear `RGB`.
+///
+/// Colors outside of the `sRGB` gamut are not clamped; use [`oklch_to_linear_clipped`] when the result must be
+/// displayable.
+///
+#[must_use]
+pub fn oklch_to_linear(lch: [f32; 3]) -> [f32; 3] {
+  oklab_to_linear(oklch_to_oklab(lch))
+}
+
+/// `OKLCH_GAMUT_EPSILON` is how far a channel may stray outside of `[0.0, 1.0]` while still being considered in gamut,
+/// absorbing the rounding error of the Oklab round trip.
+///
+const OKLCH_GAMUT_EPSILON: f32 = 1e-5;
+
+/// `oklch_in_gamut` returns whether the provided `OkLCh` color can be represented in linear `RGB` without any of its
+/// channels falling outside of the range `[0.0, 1.0]`.
+///
+#[must_use]
+pub fn oklch_in_gamut(lch: [f32; 3]) -> bool {
+  oklch_to_linear(lch)
+    .iter()
+    .all(|&x| (-OKLCH_GAMUT_EPSILON..=1.0 + OKLCH_GAMUT_EPSILON).contains(&x))
+}
+
+/// `gamut_clip_oklch` maps the provided `OkLCh` color into the `sRGB` gamut while preserving its hue.
+///
+/// Lightness is clamped to `[0.0, 1.0]` and chroma is then reduced until the color fits, leaving in-gamut colors
+/// untouched. This keeps the perceived hue of saturated colors stable, unlike clamping each `RGB` channel
+/// independently. `NaN` components are treated as `0.0`.
+///
+/// # Example
+/// ```
+/// let [l, c, h] = cvr::convert::gamut_clip_oklch([0.7, 0.4, 150.0]);
+///
+/// assert_eq!((l, h), (0.7, 150.0));
+/// assert!(c < 0.4);
+/// assert!(cvr::convert::oklch_in_gamut([l, c, h]));
+/// ```
+///
+#[must_use]
+pub fn gamut_clip_oklch(lch: [f32; 3]) -> [f32; 3] {
+  let [l, c, h] = lch.map(nan_to_zero);
+  let l = l.clamp(0.0, 1.0);
+  let c = c.max(0.0);
+
+  if oklch_in_gamut([l, c, h]) {
+    return [l, c, h];
+  }
+
+  let (mut low, mut high) = (0.0_f32, c);
+  for _ in 0..24 {
+    let mid = 0.5 * (low + high);
+    if oklch_in_gamut([l, mid, h]) {
+      low = mid;
+    } else {
+      high = mid;
+    }
+  }
+
+  [l, low, h]
+}
+
+/// `oklch_to_linear_clipped` converts the provided `OkLCh` color to linear `RGB`, first mapping it into the `sRGB`
+/// gamut with [`gamut_clip_oklch`]. Every returned channel is within the range `[0.0, 1.0]`.
+///
+#[must_use]
+pub fn oklch_to_linear_clipped(lch: [f32; 3]) -> [f32; 3] {
+  oklch_to_linear(gamut_clip_oklch(lch)).map(|x| x.clamp(0.0, 1.0))
+}
+
+/// `uv_chromaticity` returns the CIE 1976 `(u', v')` chromaticity coordinates of the supplied `XYZ` value.
+///
+fn uv_chromaticity([x, y, z]: [f32; 3]) -> [f32; 2] {
+  let denominator = x + 15.0 * y + 3.0 * z;
+  [4.0 * x / denominator, 9.0 * y / denominator]
+}
+
+/// `transform` multiplies the matrix `m` with the column vector `v`.
+///
+fn transform(m: &gamut::Matrix, v: [f32; 3]) -> [f32; 3] {
+  m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// `hexagonal_hue` computes the `HSL` hue, in degrees, from a pixel along with its largest channel value and
+/// chroma.
+///
+#[allow(clippy::float_cmp, clippy::many_single_char_names)]
+fn hexagonal_hue([r, g, b]: [f32; 3], x_max: f32, c: f32) -> f32 {
+  let h = if c == 0.0 {
+    0.0
+  } else if x_max == r {
+    60.0 * ((g - b) / c)
+  } else if x_max == g {
+    60.0 * (2.0 + (b - r) / c)
+  } else {
+    60.0 * (4.0 + (r - g) / c)
+  };
+
+  if h < 0.0 {
+    360.0 + h
+  } else {
+    h
+  }
+}
+
+/// `hexagonal_chroma` returns the `RGB` value with the supplied hue and chroma whose smallest channel is `0.0`,
+/// which the inverse `HSL` transform offsets by its lightness.
+///
+fn hexagonal_chroma(h: f32, c: f32) -> [f32; 3] {
+  let h = (h % 360.0) / 60.0;
+  let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+
+  if h < 1.0 {
+    [c, x, 0.0]
+  } else if h < 2.0 {
+    [x, c, 0.0]
+  } else if h < 3.0 {
+    [0.0, c, x]
+  } else if h < 4.0 {
+    [0.0, x, c]
+  } else if h < 5.0 {
+    [x, 0.0, c]
+  } else {
+    [c, 0.0, x]
+  }
+}
+
+/// `iter` contains the set of conversion iterators that enable lazy color space conversions.
+///
+pub mod iter {
+  use super::transfer::{decode_sample, encode_sample, TransferFunction};
+  use super::{
+    hsi_to_linear, hsl_to_linear, hsv_to_linear, hsv_to_linear_clamped, lab_to_linear,
+    linear_to_gray, linear_to_hsi, linear_to_hsl, linear_to_hsv, linear_to_hsv_clamped,
+    linear_to_lab, linear_to_luv, linear_to_oklab, linear_to_oklch, linear_to_srgb, linear_to_xyz,
+    luv_to_linear, oklab_to_linear, oklch_to_linear, srgb_to_linear, try_hsv_to_linear,
+    try_linear_to_hsv, xyz_to_linear, CieReference, RangeError,
+  };
+  use crate::Numeric;
+
+  /// `SRGBToLinear` lazily converts 8-bit `sRGB` pixels to their linear floating point
+  /// counterparts.
+  ///
+  pub type SRGBToLinear<I> = std::iter::Map<I, fn([u8; 3]) -> [f32; 3]>;
+
+  /// `SRGBLinear` is the public trait `std::iter::Iterator` types implement to enable
+  /// `.srgb_to_linear()` as an iterator adapter.
+  ///
+  pub trait SRGBLinearIterator: std::iter::Iterator<Item = [u8; 3]>
+  where
+    Self: Sized,
+  {
+    /// `srgb_to_linear` converts the current `Iterator` to a [`iter::SRGBToLinear`](crate::convert::iter::SRGBToLinear).
+    ///
+    fn srgb_to_linear(self) -> SRGBToLinear<Self> {
+      self.map(|[r, g, b]| [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)])
+    }
+  }
+
+  impl<Iter> SRGBLinearIterator for Iter where Iter: std::iter::Iterator<Item = [u8; 3]> {}
+
+  /// `LinearToSRGBIter` lazily converts linear floating point `(R, G, B)` data into its
+  /// 8-bit `sRGB` representation.
+  ///
+  pub type LinearToSRGB<I> = std::iter::Map<I, fn([f32; 3]) -> [u8; 3]>;
+
+  /// `LinearToSRGB` is the public trait `std::iter::Iterator` types implement to enable
+  /// `.linear_to_srgb()` as an iterator adapter.
+  ///
+  #[allow(clippy::type_complexity)]
+  pub trait LinearSRGBIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_srgb` converts the current `Iterator` to a [`iter::LinearToSRGB`](crate::convert::iter::LinearToSRGB).
+    ///
+    fn linear_to_srgb(self) -> LinearToSRGB<Self> {
+      self.map(|[r, g, b]| [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)])
+    }
+  }
+
+  impl<Iter> LinearSRGBIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToGray` lazily converts linearized `f32` pixel values to their corresponding
+  /// [luminance in the CIE XYZ color space](https://en.wikipedia.org/wiki/CIE_1931_color_space#Meaning_of_X,_Y_and_Z).
+  ///
+  pub type LinearToGray<I> = std::iter::Map<I, fn([f32; 3]) -> f32>;
+
+  /// `LinearGrayIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_gray()` to be invoked.
+  ///
+  pub trait LinearGrayIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_gray` converts the current `Iterator` into a [`iter::LinearToGray`](crate::convert::iter::LinearToGray).
+    ///
+    fn linear_to_gray(self) -> LinearToGray<Self> {
+      self.map(linear_to_gray)
+    }
+  }
+
+  impl<Iter> LinearGrayIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToHSV` lazily converts linearized `f32` pixel values to their corresponding
+  /// [HSV values](https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB).
+  ///
+  pub type LinearToHSV<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearToHSVClamped` lazily clamps linearized `f32` pixel values to the range `[0.0, 1.0]` before converting them
+  /// to `HSV`.
+  ///
+  pub type LinearToHSVClamped<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `TryLinearToHSV` lazily converts linearized `f32` pixel values to `HSV`, yielding an error for every pixel with
+  /// a channel outside of the range `[0.0, 1.0]`.
+  ///
+  pub type TryLinearToHSV<I> = std::iter::Map<I, fn([f32; 3]) -> Result<[f32; 3], RangeError>>;
+
+  /// `LinearHSVIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapters `linear_to_hsv()`, `linear_to_hsv_clamped()` and `try_linear_to_hsv()` to be invoked.
+  ///
+  pub trait LinearHSVIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_hsv` transforms the current `Iterator` into a [`iter::LinearToHSV`](crate::convert::iter::LinearToHSV).
+    ///
+    fn linear_to_hsv(self) -> LinearToHSV<Self> {
+      self.map(linear_to_hsv)
+    }
+
+    /// `linear_to_hsv_clamped` transforms the current `Iterator` into a
+    /// [`iter::LinearToHSVClamped`](crate::convert::iter::LinearToHSVClamped).
+    ///
+    fn linear_to_hsv_clamped(self) -> LinearToHSVClamped<Self> {
+      self.map(linear_to_hsv_clamped)
+    }
+
+    /// `try_linear_to_hsv` transforms the current `Iterator` into a
+    /// [`iter::TryLinearToHSV`](crate::convert::iter::TryLinearToHSV).
+    ///
+    fn try_linear_to_hsv(self) -> TryLinearToHSV<Self> {
+      self.map(try_linear_to_hsv)
+    }
+  }
+
+  impl<Iter> LinearHSVIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `HSVToLinear` lazily converts linearized `f32` pixel values to their corresponding
+  /// [RGB values](https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB).
+  ///
+  pub type HSVToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `HSVToLinearClamped` lazily wraps the hue and clamps the saturation and value of `HSV` pixels before converting
+  /// them to linear `RGB`.
+  ///
+  pub type HSVToLinearClamped<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `TryHSVToLinear` lazily converts `HSV` pixels to linear `RGB`, yielding an error for every pixel with a channel
+  /// outside of its range.
+  ///
+  pub type TryHSVToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> Result<[f32; 3], RangeError>>;
+
+  /// `HSVLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapters `hsv_to_linear()`, `hsv_to_linear_clamped()` and `try_hsv_to_linear()` to be invoked.
+  ///
+  pub trait HSVLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `hsv_to_linear` converts the current `Iterator` to a [`iter::HSVToLinear`](crate::convert::iter::HSVToLinear).
+    ///
+    fn hsv_to_linear(self) -> HSVToLinear<Self> {
+      self.map(hsv_to_linear)
+    }
+
+    /// `hsv_to_linear_clamped` converts the current `Iterator` to a
+    /// [`iter::HSVToLinearClamped`](crate::convert::iter::HSVToLinearClamped).
+    ///
+    fn hsv_to_linear_clamped(self) -> HSVToLinearClamped<Self> {
+      self.map(hsv_to_linear_clamped)
+    }
+
+    /// `try_hsv_to_linear` converts the current `Iterator` to a
+    /// [`iter::TryHSVToLinear`](crate::convert::iter::TryHSVToLinear).
+    ///
+    fn try_hsv_to_linear(self) -> TryHSVToLinear<Self> {
+      self.map(try_hsv_to_linear)
+    }
+  }
+
+  impl<Iter> HSVLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToHSL` lazily converts linearized `f32` pixel values to their corresponding
+  /// [HSL values](https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB).
+  ///
+  pub type LinearToHSL<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearHSLIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_hsl()` to be invoked.
+  ///
+  pub trait LinearHSLIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_hsl` transforms the current `Iterator` into a [`iter::LinearToHSL`](crate::convert::iter::LinearToHSL).
+    ///
+    fn linear_to_hsl(self) -> LinearToHSL<Self> {
+      self.map(linear_to_hsl)
+    }
+  }
+
+  impl<Iter> LinearHSLIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `HSLToLinear` lazily converts `HSL` pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type HSLToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `HSLLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `hsl_to_linear()` to be invoked.
+  ///
+  pub trait HSLLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `hsl_to_linear` converts the current `Iterator` to a [`iter::HSLToLinear`](crate::convert::iter::HSLToLinear).
+    ///
+    fn hsl_to_linear(self) -> HSLToLinear<Self> {
+      self.map(hsl_to_linear)
+    }
+  }
+
+  impl<Iter> HSLLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToHSI` lazily converts linearized `f32` pixel values to their corresponding
+  /// [HSI values](https://en.wikipedia.org/wiki/HSL_and_HSV#Lightness).
+  ///
+  pub type LinearToHSI<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearHSIIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_hsi()` to be invoked.
+  ///
+  pub trait LinearHSIIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_hsi` transforms the current `Iterator` into a [`iter::LinearToHSI`](crate::convert::iter::LinearToHSI).
+    ///
+    fn linear_to_hsi(self) -> LinearToHSI<Self> {
+      self.map(linear_to_hsi)
+    }
+  }
+
+  impl<Iter> LinearHSIIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `HSIToLinear` lazily converts `HSI` pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type HSIToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `HSILinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `hsi_to_linear()` to be invoked.
+  ///
+  pub trait HSILinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `hsi_to_linear` converts the current `Iterator` to a [`iter::HSIToLinear`](crate::convert::iter::HSIToLinear).
+    ///
+    fn hsi_to_linear(self) -> HSIToLinear<Self> {
+      self.map(hsi_to_linear)
+    }
+  }
+
+  impl<Iter> HSILinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToXYZ` lazily converts linearized `f32` pixel values to their corresponding CIE XYZ values.
+  ///
+  pub type LinearToXYZ<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearXYZIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_xyz()` to be invoked.
+  ///
+  pub trait LinearXYZIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_xyz` transforms the current `Iterator` into a [`iter::LinearToXYZ`](crate::convert::iter::LinearToXYZ).
+    ///
+    fn linear_to_xyz(self) -> LinearToXYZ<Self> {
+      self.map(linear_to_xyz)
+    }
+  }
+
+  impl<Iter> LinearXYZIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `XYZToLinear` lazily converts CIE XYZ pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type XYZToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `XYZLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `xyz_to_linear()` to be invoked.
+  ///
+  pub trait XYZLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `xyz_to_linear` converts the current `Iterator` to a [`iter::XYZToLinear`](crate::convert::iter::XYZToLinear).
+    ///
+    fn xyz_to_linear(self) -> XYZToLinear<Self> {
+      self.map(xyz_to_linear)
+    }
+  }
+
+  impl<Iter> XYZLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToLab` lazily converts linearized `f32` pixel values to their corresponding `D65`-relative CIELAB values.
+  ///
+  pub type LinearToLab<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearLabIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_lab()` to be invoked.
+  ///
+  pub trait LinearLabIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_lab` transforms the current `Iterator` into a [`iter::LinearToLab`](crate::convert::iter::LinearToLab).
+    ///
+    fn linear_to_lab(self) -> LinearToLab<Self> {
+      self.map(linear_to_lab)
+    }
+  }
+
+  impl<Iter> LinearLabIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LabToLinear` lazily converts `D65`-relative CIELAB pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type LabToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LabLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `lab_to_linear()` to be invoked.
+  ///
+  pub trait LabLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `lab_to_linear` converts the current `Iterator` to a [`iter::LabToLinear`](crate::convert::iter::LabToLinear).
+    ///
+    fn lab_to_linear(self) -> LabToLinear<Self> {
+      self.map(lab_to_linear)
+    }
+  }
+
+  impl<Iter> LabLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToLuv` lazily converts linearized `f32` pixel values to their corresponding `D65`-relative CIELUV values.
+  ///
+  pub type LinearToLuv<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearLuvIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_luv()` to be invoked.
+  ///
+  pub trait LinearLuvIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_luv` transforms the current `Iterator` into a [`iter::LinearToLuv`](crate::convert::iter::LinearToLuv).
+    ///
+    fn linear_to_luv(self) -> LinearToLuv<Self> {
+      self.map(linear_to_luv)
+    }
+  }
+
+  impl<Iter> LinearLuvIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LuvToLinear` lazily converts `D65`-relative CIELUV pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type LuvToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LuvLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `luv_to_linear()` to be invoked.
+  ///
+  pub trait LuvLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `luv_to_linear` converts the current `Iterator` to a [`iter::LuvToLinear`](crate::convert::iter::LuvToLinear).
+    ///
+    fn luv_to_linear(self) -> LuvToLinear<Self> {
+      self.map(luv_to_linear)
+    }
+  }
+
+  impl<Iter> LuvLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `CieRelative` lazily converts pixel values with one of the conversions of a [`CieReference`], such as
+  /// [`CieReference::linear_to_lab`].
+  ///
+  pub struct CieRelative<I> {
+    iter: I,
+    reference: CieReference,
+    convert: fn(&CieReference, [f32; 3]) -> [f32; 3],
+  }
+
+  impl<I> std::iter::Iterator for CieRelative<I>
+  where
+    I: std::iter::Iterator<Item = [f32; 3]>,
+  {
+    type Item = [f32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+      let (reference, convert) = (&self.reference, self.convert);
+      self.iter.next().map(|px| convert(reference, px))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+      self.iter.size_hint()
+    }
+  }
+
+  /// `CieRelativeIterator` is the public trait implemented for all `Iterator` types that enables the adapters
+  /// `linear_to_lab_with()`, `lab_to_linear_with()`, `linear_to_luv_with()` and `luv_to_linear_with()` to be invoked.
+  ///
+  pub trait CieRelativeIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_lab_with` transforms the current `Iterator` into a
+    /// [`iter::CieRelative`](crate::convert::iter::CieRelative) converting linear pixels to `L*a*b*` relative to the
+    /// `reference` white.
+    ///
+    fn linear_to_lab_with(self, reference: CieReference) -> CieRelative<Self> {
+      CieRelative {
+        iter: self,
+        reference,
+        convert: CieReference::linear_to_lab,
+      }
+    }
+
+    /// `lab_to_linear_with` transforms the current `Iterator` into a
+    /// [`iter::CieRelative`](crate::convert::iter::CieRelative) converting `L*a*b*` pixels relative to the
+    /// `reference` white to linear `RGB`.
+    ///
+    fn lab_to_linear_with(self, reference: CieReference) -> CieRelative<Self> {
+      CieRelative {
+        iter: self,
+        reference,
+        convert: CieReference::lab_to_linear,
+      }
+    }
+
+    /// `linear_to_luv_with` transforms the current `Iterator` into a
+    /// [`iter::CieRelative`](crate::convert::iter::CieRelative) converting linear pixels to `L*u*v*` relative to the
+    /// `reference` white.
+    ///
+    fn linear_to_luv_with(self, reference: CieReference) -> CieRelative<Self> {
+      CieRelative {
+        iter: self,
+        reference,
+        convert: CieReference::linear_to_luv,
+      }
+    }
+
+    /// `luv_to_linear_with` transforms the current `Iterator` into a
+    /// [`iter::CieRelative`](crate::convert::iter::CieRelative) converting `L*u*v*` pixels relative to the
+    /// `reference` white to linear `RGB`.
+    ///
+    fn luv_to_linear_with(self, reference: CieReference) -> CieRelative<Self> {
+      CieRelative {
+        iter: self,
+        reference,
+        convert: CieReference::luv_to_linear,
+      }
+    }
+  }
+
+  impl<Iter> CieRelativeIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToOklab` lazily converts linearized `f32` pixel values to their corresponding Oklab values.
+  ///
+  pub type LinearToOklab<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearOklabIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_oklab()` to be invoked.
+  ///
+  pub trait LinearOklabIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_oklab` transforms the current `Iterator` into a [`iter::LinearToOklab`](crate::convert::iter::LinearToOklab).
+    ///
+    fn linear_to_oklab(self) -> LinearToOklab<Self> {
+      self.map(linear_to_oklab)
+    }
+  }
+
+  impl<Iter> LinearOklabIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `OklabToLinear` lazily converts Oklab pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type OklabToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `OklabLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `oklab_to_linear()` to be invoked.
+  ///
+  pub trait OklabLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `oklab_to_linear` converts the current `Iterator` to a [`iter::OklabToLinear`](crate::convert::iter::OklabToLinear).
+    ///
+    fn oklab_to_linear(self) -> OklabToLinear<Self> {
+      self.map(oklab_to_linear)
+    }
+  }
+
+  impl<Iter> OklabLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `LinearToOklch` lazily converts linearized `f32` pixel values to their corresponding `OkLCh` values.
+  ///
+  pub type LinearToOklch<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `LinearOklchIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `linear_to_oklch()` to be invoked.
+  ///
+  pub trait LinearOklchIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `linear_to_oklch` transforms the current `Iterator` into a [`iter::LinearToOklch`](crate::convert::iter::LinearToOklch).
+    ///
+    fn linear_to_oklch(self) -> LinearToOklch<Self> {
+      self.map(linear_to_oklch)
+    }
+  }
+
+  impl<Iter> LinearOklchIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `OklchToLinear` lazily converts `OkLCh` pixel values to their corresponding linear `RGB` values.
+  ///
+  pub type OklchToLinear<I> = std::iter::Map<I, fn([f32; 3]) -> [f32; 3]>;
+
+  /// `OklchLinearIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `oklch_to_linear()` to be invoked.
+  ///
+  pub trait OklchLinearIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `oklch_to_linear` converts the current `Iterator` to a [`iter::OklchToLinear`](crate::convert::iter::OklchToLinear).
+    ///
+    fn oklch_to_linear(self) -> OklchToLinear<Self> {
+      self.map(oklch_to_linear)
+    }
+  }
+
+  impl<Iter> OklchLinearIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
+
+  /// `Decode` lazily converts pixels encoded with a [`TransferFunction`] to linear floating point, normalizing them by
+  /// the sample type's [`Numeric::SCALE`] first.
+  ///
+  pub struct Decode<I, F> {
+    iter: I,
+    curve: F,
+  }
+
+  impl<I, T, F> std::iter::Iterator for Decode<I, F>
+  where
+    I: std::iter::Iterator<Item = [T; 3]>,
+    T: Numeric,
+    F: TransferFunction,
+  {
+    type Item = [f32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+      let curve = &self.curve;
+      self
+        .iter
+        .next()
+        .map(|px| px.map(|x| decode_sample(curve, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+      self.iter.size_hint()
+    }
+  }
+
+  /// `DecodeIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `decode()` to be invoked.
+  ///
+  pub trait DecodeIterator<T>: std::iter::Iterator<Item = [T; 3]>
+  where
+    Self: Sized,
+    T: Numeric,
+  {
+    /// `decode` transforms the current `Iterator` into a [`iter::Decode`](crate::convert::iter::Decode) that
+    /// linearizes each pixel with the supplied `curve`.
+    ///
+    fn decode<F>(self, curve: F) -> Decode<Self, F>
+    where
+      F: TransferFunction,
+    {
+      Decode { iter: self, curve }
+    }
+  }
+
+  impl<Iter, T> DecodeIterator<T> for Iter
+  where
+    Iter: std::iter::Iterator<Item = [T; 3]>,
+    T: Numeric,
+  {
+  }
+
+  /// `Encode` lazily converts linear floating point pixels to the sample type `T` using a [`TransferFunction`],
+  /// scaling the encoded values by [`Numeric::SCALE`] and saturating them.
+  ///
+  pub struct Encode<I, F, T> {
+    iter: I,
+    curve: F,
+    sample: std::marker::PhantomData<T>,
+  }
+
+  impl<I, F, T> std::iter::Iterator for Encode<I, F, T>
+  where
+    I: std::iter::Iterator<Item = [f32; 3]>,
+    F: TransferFunction,
+    T: Numeric,
+  {
+    type Item = [T; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+      let curve = &self.curve;
+      self
+        .iter
+        .next()
+        .map(|px| px.map(|x| encode_sample(curve, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+      self.iter.size_hint()
+    }
+  }
+
+  /// `EncodeIterator` is the public trait implemented for all `Iterator` types that enables
+  /// the adapter `encode()` to be invoked.
+  ///
+  pub trait EncodeIterator: std::iter::Iterator<Item = [f32; 3]>
+  where
+    Self: Sized,
+  {
+    /// `encode` transforms the current `Iterator` into a [`iter::Encode`](crate::convert::iter::Encode) that applies
+    /// the supplied `curve` to each linear pixel. The output sample type is usually inferred but can be chosen
+    /// explicitly, as in `encode::<_, u16>(curve)`.
+    ///
+    fn encode<F, T>(self, curve: F) -> Encode<Self, F, T>
+    where
+      F: TransferFunction,
+      T: Numeric,
+    {
+      Encode {
+        iter: self,
+        curve,
+        sample: std::marker::PhantomData,
+      }
+    }
+  }
+
+  impl<Iter> EncodeIterator for Iter where Iter: std::iter::Iterator<Item = [f32; 3]> {}
 } // iter
@@ -0,0 +1,406 @@
+//! `gamut` contains the descriptors needed to move colors between different `RGB` color spaces, such as `sRGB`,
+//! `Display P3`, `Adobe RGB`, `Rec.2020` and `ProPhoto RGB`.
+//!
+//! An [`RgbSpace`] is described by the chromaticities of its primaries, its reference white and the transfer function
+//! its values are encoded with. A [`Conversion`] between two spaces is computed once, passing through `XYZ` and
+//! adapting between white points with the selected [`Adaptation`], and can then be applied to individual pixels or to
+//! whole images with [`cvt_rgb_space`](crate::rgb::cvt_rgb_space).
+//!
+//! Colors that fall outside of the destination gamut are handled according to the selected [`GamutMapping`].
+//!
+//! # Example
+//! ```
+//! use cvr::convert::gamut::{Adaptation, Conversion, GamutMapping, RgbSpace};
+//!
+//! let conversion = Conversion::new(
+//!     RgbSpace::SRGB,
+//!     RgbSpace::DISPLAY_P3,
+//!     Adaptation::Bradford,
+//!     GamutMapping::Clip,
+//! );
+//!
+//! // `sRGB` red is well within `Display P3` but is no longer its most saturated red
+//! //
+//! let [r, g, b] = conversion.convert([1.0, 0.0, 0.0]);
+//! assert!((r - 0.9175).abs() < 1e-3);
+//! assert!((g - 0.2003).abs() < 1e-3);
+//! assert!((b - 0.1387).abs() < 1e-3);
+//! ```
+//!
+
+use super::transfer::{AdobeRgb, Gamma, Rec709, Srgb, TransferFunction};
+use super::WhitePoint;
+
+/// `Matrix` is a row-major `3x3` matrix, applied to column vectors.
+///
+pub type Matrix = [[f32; 3]; 3];
+
+/// `Primaries` holds the CIE 1931 `(x, y)` chromaticity coordinates of the red, green and blue primaries of an `RGB`
+/// color space.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Primaries {
+  /// The chromaticity of the red primary.
+  pub red: [f32; 2],
+  /// The chromaticity of the green primary.
+  pub green: [f32; 2],
+  /// The chromaticity of the blue primary.
+  pub blue: [f32; 2],
+}
+
+/// `RgbSpace` describes an `RGB` color space by its primaries, its reference white and the transfer function its
+/// values are encoded with.
+///
+/// Descriptors for linear data can be created from the predefined spaces by replacing their `transfer` with
+/// [`Linear`](super::transfer::Linear).
+///
+/// # Example
+/// ```
+/// use cvr::convert::gamut::RgbSpace;
+/// use cvr::convert::transfer::Linear;
+///
+/// let linear_p3 = RgbSpace {
+///     transfer: &Linear,
+///     ..RgbSpace::DISPLAY_P3
+/// };
+///
+/// // the `Y` row of the matrix holds the luminance of each primary
+/// //
+/// let [_, y, _] = linear_p3.to_xyz_matrix();
+/// assert!((y.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct RgbSpace {
+  /// The chromaticities of the space's primaries.
+  pub primaries: Primaries,
+  /// The reference white of the space, the color produced when every channel is at full intensity.
+  pub white: WhitePoint,
+  /// The transfer function the space's values are encoded with.
+  pub transfer: &'static dyn TransferFunction,
+}
+
+impl RgbSpace {
+  /// `SRGB` is the [`sRGB`](https://en.wikipedia.org/wiki/SRGB) color space.
+  ///
+  pub const SRGB: RgbSpace = RgbSpace {
+    primaries: Primaries {
+      red: [0.64, 0.33],
+      green: [0.30, 0.60],
+      blue: [0.15, 0.06],
+    },
+    white: WhitePoint::D65,
+    transfer: &Srgb,
+  };
+
+  /// `DISPLAY_P3` is the [`Display P3`](https://en.wikipedia.org/wiki/DCI-P3#Display_P3) color space, which combines
+  /// the `DCI-P3` primaries with the `D65` white point and the `sRGB` transfer function.
+  ///
+  pub const DISPLAY_P3: RgbSpace = RgbSpace {
+    primaries: Primaries {
+      red: [0.680, 0.320],
+      green: [0.265, 0.690],
+      blue: [0.150, 0.060],
+    },
+    white: WhitePoint::D65,
+    transfer: &Srgb,
+  };
+
+  /// `ADOBE_RGB` is the [`Adobe RGB (1998)`](https://en.wikipedia.org/wiki/Adobe_RGB_color_space) color space.
+  ///
+  pub const ADOBE_RGB: RgbSpace = RgbSpace {
+    primaries: Primaries {
+      red: [0.64, 0.33],
+      green: [0.21, 0.71],
+      blue: [0.15, 0.06],
+    },
+    white: WhitePoint::D65,
+    transfer: &AdobeRgb,
+  };
+
+  /// `REC2020` is the [`Rec.2020`](https://en.wikipedia.org/wiki/Rec._2020) color space, encoded with the `BT.709`
+  /// camera curve it shares with standard dynamic range video.
+  ///
+  pub const REC2020: RgbSpace = RgbSpace {
+    primaries: Primaries {
+      red: [0.708, 0.292],
+      green: [0.170, 0.797],
+      blue: [0.131, 0.046],
+    },
+    white: WhitePoint::D65,
+    transfer: &Rec709,
+  };
+
+  /// `PRO_PHOTO` is the [`ProPhoto RGB`](https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space) color space, relative
+  /// to the `D50` white point. Its transfer function is approximated by a pure `1.8` power law, ignoring the short
+  /// linear segment near black.
+  ///
+  pub const PRO_PHOTO: RgbSpace = RgbSpace {
+    primaries: Primaries {
+      red: [0.7347, 0.2653],
+      green: [0.1596, 0.8404],
+      blue: [0.0366, 0.0001],
+    },
+    white: WhitePoint::D50,
+    transfer: &Gamma(1.8),
+  };
+
+  /// `to_xyz_matrix` returns the matrix that converts linear values in this space to `XYZ`, relative to the space's
+  /// white point.
+  ///
+  #[must_use]
+  pub fn to_xyz_matrix(self) -> Matrix {
+    to_f32(self.xyz_matrix())
+  }
+
+  /// `from_xyz_matrix` returns the matrix that converts `XYZ` values, relative to the space's white point, to linear
+  /// values in this space. This is the inverse of `to_xyz_matrix`.
+  ///
+  #[must_use]
+  pub fn from_xyz_matrix(self) -> Matrix {
+    to_f32(inverse(self.xyz_matrix()))
+  }
+
+  /// `xyz_matrix` computes the `RGB` to `XYZ` matrix by scaling the `XYZ` values of the primaries so that they sum to
+  /// the white point.
+  ///
+  fn xyz_matrix(self) -> [[f64; 3]; 3] {
+    let [r, g, b] = [
+      self.primaries.red,
+      self.primaries.green,
+      self.primaries.blue,
+    ]
+    .map(|[x, y]| {
+      let (x, y) = (f64::from(x), f64::from(y));
+      [x / y, 1.0, (1.0 - x - y) / y]
+    });
+
+    let primaries = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+    let scale = apply(inverse(primaries), white_xyz(self.white));
+
+    primaries.map(|row| [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]])
+  }
+}
+
+impl std::fmt::Debug for RgbSpace {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RgbSpace")
+      .field("primaries", &self.primaries)
+      .field("white", &self.white)
+      .finish_non_exhaustive()
+  }
+}
+
+/// `Adaptation` selects the [chromatic adaptation transform](https://en.wikipedia.org/wiki/Chromatic_adaptation) used
+/// to map colors between white points. The [`Default`] is [`Adaptation::Bradford`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Adaptation {
+  /// The Bradford transform, used by ICC color management.
+  #[default]
+  Bradford,
+  /// The von Kries transform, using the Hunt-Pointer-Estevez cone response matrix.
+  VonKries,
+  /// The transform of the `CIECAM02` color appearance model.
+  Cat02,
+}
+
+impl Adaptation {
+  /// `cone_response` returns the matrix converting `XYZ` values to the transform's cone response domain.
+  ///
+  fn cone_response(self) -> [[f64; 3]; 3] {
+    match self {
+      Adaptation::Bradford => [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+      ],
+      Adaptation::VonKries => [
+        [0.400_24, 0.707_60, -0.080_81],
+        [-0.226_30, 1.165_32, 0.045_70],
+        [0.0, 0.0, 0.918_22],
+      ],
+      Adaptation::Cat02 => [
+        [0.7328, 0.4296, -0.1624],
+        [-0.7036, 1.6975, 0.0061],
+        [0.0030, 0.0136, 0.9834],
+      ],
+    }
+  }
+}
+
+/// `adaptation_matrix` returns the matrix that maps `XYZ` values relative to the `src` white point to `XYZ` values
+/// relative to the `dst` white point, using the supplied transform.
+///
+/// # Example
+/// ```
+/// use cvr::convert::gamut::{adaptation_matrix, Adaptation};
+/// use cvr::convert::WhitePoint;
+///
+/// let m = adaptation_matrix(WhitePoint::D65, WhitePoint::D50, Adaptation::Bradford);
+///
+/// let d65 = WhitePoint::D65.xyz();
+/// let d50 = WhitePoint::D50.xyz();
+///
+/// for (row, expected) in m.iter().zip(d50.iter()) {
+///     let adapted: f32 = row.iter().zip(d65.iter()).map(|(m, x)| m * x).sum();
+///     assert!((adapted - expected).abs() < 1e-6);
+/// }
+/// ```
+///
+#[must_use]
+pub fn adaptation_matrix(src: WhitePoint, dst: WhitePoint, method: Adaptation) -> Matrix {
+  to_f32(adaptation(src, dst, method))
+}
+
+/// `adaptation` computes the adaptation matrix in `f64`.
+///
+fn adaptation(src: WhitePoint, dst: WhitePoint, method: Adaptation) -> [[f64; 3]; 3] {
+  let cone = method.cone_response();
+
+  let src = apply(cone, white_xyz(src));
+  let dst = apply(cone, white_xyz(dst));
+  let scaled = [0, 1, 2].map(|i| cone[i].map(|x| x * dst[i] / src[i]));
+
+  multiply(inverse(cone), scaled)
+}
+
+/// `GamutMapping` selects how colors that fall outside of the destination gamut are brought back into it. The
+/// [`Default`] is [`GamutMapping::Clip`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GamutMapping {
+  /// Every channel is clamped to the range `[0.0, 1.0]` independently. This is cheap but shifts the luminance and hue
+  /// of saturated colors.
+  #[default]
+  Clip,
+  /// Out of gamut colors are desaturated towards the gray of the same luminance, in the destination space, until they
+  /// fit. Luminance is only altered when it is itself outside of the range `[0.0, 1.0]`.
+  PreserveLuminance,
+}
+
+/// `Conversion` is a precomputed conversion between two [`RgbSpace`]s.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Conversion {
+  src: RgbSpace,
+  dst: RgbSpace,
+  matrix: Matrix,
+  luminance: [f32; 3],
+  mapping: GamutMapping,
+}
+
+impl Conversion {
+  /// `new` computes the conversion from the `src` space to the `dst` space, adapting between their white points with
+  /// the supplied `adaptation` transform and handling out of gamut colors according to `mapping`.
+  ///
+  #[must_use]
+  pub fn new(src: RgbSpace, dst: RgbSpace, adaptation: Adaptation, mapping: GamutMapping) -> Self {
+    let dst_xyz = dst.xyz_matrix();
+    let matrix = multiply(
+      inverse(dst_xyz),
+      multiply(
+        self::adaptation(src.white, dst.white, adaptation),
+        src.xyz_matrix(),
+      ),
+    );
+
+    Self {
+      src,
+      dst,
+      matrix: to_f32(matrix),
+      luminance: to_f32(dst_xyz)[1],
+      mapping,
+    }
+  }
+
+  /// `matrix` returns the matrix that maps linear values in the source space to linear values in the destination
+  /// space, before any gamut mapping.
+  ///
+  #[must_use]
+  pub fn matrix(&self) -> Matrix {
+    self.matrix
+  }
+
+  /// `convert_linear` converts the provided linear value in the source space to a linear value in the destination
+  /// space, mapping it into the destination gamut.
+  ///
+  #[must_use]
+  pub fn convert_linear(&self, rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = self
+      .matrix
+      .map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]);
+
+    match self.mapping {
+      GamutMapping::Clip => [r, g, b].map(|x| x.clamp(0.0, 1.0)),
+      GamutMapping::PreserveLuminance => preserve_luminance([r, g, b], self.luminance),
+    }
+  }
+
+  /// `convert` converts the provided value, encoded with the source space's transfer function, to a value encoded
+  /// with the destination space's transfer function.
+  ///
+  #[must_use]
+  pub fn convert(&self, rgb: [f32; 3]) -> [f32; 3] {
+    let linear = self.convert_linear(rgb.map(|x| self.src.transfer.decode(x)));
+    linear.map(|x| self.dst.transfer.encode(x))
+  }
+}
+
+/// `preserve_luminance` moves the supplied linear value towards the gray of the same luminance until every channel is
+/// within the range `[0.0, 1.0]`.
+///
+fn preserve_luminance(rgb: [f32; 3], weights: [f32; 3]) -> [f32; 3] {
+  let y = (weights[0] * rgb[0] + weights[1] * rgb[1] + weights[2] * rgb[2]).clamp(0.0, 1.0);
+
+  let t = rgb.iter().fold(1.0_f32, |t, &x| {
+    if x < 0.0 {
+      t.min(y / (y - x))
+    } else if x > 1.0 {
+      t.min((1.0 - y) / (x - y))
+    } else {
+      t
+    }
+  });
+
+  rgb.map(|x| (y + t * (x - y)).clamp(0.0, 1.0))
+}
+
+/// `white_xyz` widens the supplied white point to `f64`.
+///
+fn white_xyz(white: WhitePoint) -> [f64; 3] {
+  white.xyz().map(f64::from)
+}
+
+/// `apply` multiplies the matrix `m` with the column vector `v`.
+///
+fn apply(m: [[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+  m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// `multiply` returns the matrix product `a * b`.
+///
+fn multiply(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+  a.map(|row| [0, 1, 2].map(|j| row[0] * b[0][j] + row[1] * b[1][j] + row[2] * b[2][j]))
+}
+
+/// `inverse` returns the inverse of the matrix `m` using its adjugate.
+///
+fn inverse(m: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+  let cofactor = |i: usize, j: usize| {
+    let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+    let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+    m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+  };
+
+  let determinant = m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+
+  [0, 1, 2].map(|i| [0, 1, 2].map(|j| cofactor(j, i) / determinant))
+}
+
+/// `to_f32` narrows the supplied matrix to `f32`.
+///
+#[allow(clippy::cast_possible_truncation)]
+fn to_f32<const N: usize>(m: [[f64; 3]; N]) -> [[f32; 3]; N] {
+  m.map(|row| row.map(|x| x as f32))
+}
@@ -0,0 +1,828 @@
+//! `kernels` contains slice-based versions of the most common per-sample conversions, operating on whole planes at a
+//! time.
+//!
+//! Unlike the adapters in [`iter`](super::iter), which convert one pixel at a time, these kernels are vectorized. On
+//! `x86_64` the fastest instruction set supported by the running CPU is selected at runtime: `AVX2` where it is
+//! available and `SSE2` otherwise. The table driven `sRGB` conversions require the gathers introduced by `AVX2` and use
+//! their scalar implementations without it, as does every kernel on other architectures.
+//!
+//! Every kernel produces results equal to those of its scalar counterpart in [`convert`](super), regardless of the
+//! instruction set used. The only exception is the sign of zero, which the floating point kernels may not preserve,
+//! e.g. [`linear_to_hsv`] yields a value of `0.0` for `[-0.0, 0.0, 0.0]` where the scalar conversion yields `-0.0`.
+//! The number of samples processed is the smallest of the supplied slices' lengths.
+//!
+//! The planar images use these kernels automatically, such as in
+//! [`rgb::Image::to_linear`](crate::rgb::Image::to_linear), [`rgb::Image::to_srgb`](crate::rgb::Image::to_srgb),
+//! [`rgb::cvt_linear_to_gray`](crate::rgb::cvt_linear_to_gray) and
+//! [`rgb::cvt_linear_to_hsv`](crate::rgb::cvt_linear_to_hsv).
+//!
+//! # Example
+//! ```
+//! let srgb = [0_u8, 64, 128, 255];
+//!
+//! let mut linear = [0_f32; 4];
+//! cvr::convert::kernels::srgb_to_linear(&srgb, &mut linear);
+//! assert_eq!(linear[1], cvr::convert::srgb_to_linear(64));
+//!
+//! let mut roundtrip = [0_u8; 4];
+//! cvr::convert::kernels::linear_to_srgb(&linear, &mut roundtrip);
+//! assert_eq!(roundtrip, srgb);
+//! ```
+//!
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_possible_wrap,
+  clippy::cast_ptr_alignment,
+  clippy::many_single_char_names
+)]
+
+/// `srgb_to_linear` converts the 8-bit `sRGB` values of `src` to linear floating point, storing the results in `dst`.
+///
+/// See [`srgb_to_linear`](super::srgb_to_linear).
+///
+pub fn srgb_to_linear(src: &[u8], dst: &mut [f32]) {
+  let n = src.len().min(dst.len());
+  let (src, dst) = (&src[..n], &mut dst[..n]);
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("avx2") {
+      done = unsafe { avx2::srgb_to_linear(src, dst) };
+    }
+  }
+
+  src[done..]
+    .iter()
+    .zip(dst[done..].iter_mut())
+    .for_each(|(x, y)| *y = super::srgb_to_linear(*x));
+}
+
+/// `linear_to_srgb` encodes the linear values of `src` as 8-bit `sRGB`, storing the results in `dst`.
+///
+/// See [`linear_to_srgb`](super::linear_to_srgb).
+///
+pub fn linear_to_srgb(src: &[f32], dst: &mut [u8]) {
+  let n = src.len().min(dst.len());
+  let (src, dst) = (&src[..n], &mut dst[..n]);
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("avx2") {
+      done = unsafe { avx2::linear_to_srgb(src, dst) };
+    }
+  }
+
+  src[done..]
+    .iter()
+    .zip(dst[done..].iter_mut())
+    .for_each(|(x, y)| *y = super::linear_to_srgb(*x));
+}
+
+/// `u8_to_f32` normalizes the 8-bit values of `src` to the range `[0.0, 1.0]`, storing the results in `dst`.
+///
+/// This matches the conversion performed by [`rgb::Image::convert_to`](crate::rgb::Image::convert_to) with a `scale`
+/// of `1.0 / 255.0`, as every value is divided by `255.0` and thereby correctly rounded.
+///
+pub fn u8_to_f32(src: &[u8], dst: &mut [f32]) {
+  let n = src.len().min(dst.len());
+  let (src, dst) = (&src[..n], &mut dst[..n]);
+
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe {
+    if is_x86_feature_detected!("avx2") {
+      avx2::u8_to_f32(src, dst)
+    } else {
+      sse2::u8_to_f32(src, dst)
+    }
+  };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  src[done..]
+    .iter()
+    .zip(dst[done..].iter_mut())
+    .for_each(|(x, y)| *y = f32::from(*x) / U8_MAX);
+}
+
+/// `f32_to_u8` scales the values of `src` from the range `[0.0, 1.0]` to 8-bit, rounding to the nearest integer and
+/// saturating, storing the results in `dst`. `NaN` is mapped to `0`.
+///
+/// This is the inverse of `u8_to_f32` and matches the conversion performed by
+/// [`rgb::Image::convert_to`](crate::rgb::Image::convert_to) with a `scale` of `255.0`.
+///
+pub fn f32_to_u8(src: &[f32], dst: &mut [u8]) {
+  let n = src.len().min(dst.len());
+  let (src, dst) = (&src[..n], &mut dst[..n]);
+
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe {
+    if is_x86_feature_detected!("avx2") {
+      avx2::f32_to_u8(src, dst)
+    } else {
+      sse2::f32_to_u8(src, dst)
+    }
+  };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  src[done..]
+    .iter()
+    .zip(dst[done..].iter_mut())
+    .for_each(|(x, y)| *y = crate::convert_sample(*x, 255.0, 0.0));
+}
+
+/// `linear_to_gray` computes the luminance of the linear pixels stored across the `r`, `g` and `b` planes, storing the
+/// results in `out`.
+///
+/// See [`linear_to_gray`](super::linear_to_gray).
+///
+pub fn linear_to_gray(r: &[f32], g: &[f32], b: &[f32], out: &mut [f32]) {
+  let n = r.len().min(g.len()).min(b.len()).min(out.len());
+  let (r, g, b, out) = (&r[..n], &g[..n], &b[..n], &mut out[..n]);
+
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe {
+    if is_x86_feature_detected!("avx2") {
+      avx2::linear_to_gray(r, g, b, out)
+    } else {
+      sse2::linear_to_gray(r, g, b, out)
+    }
+  };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  crate::rgb::make_iter(&r[done..], &g[done..], &b[done..])
+    .zip(out[done..].iter_mut())
+    .for_each(|(rgb, y)| *y = super::linear_to_gray(rgb));
+}
+
+/// `linear_to_hsv` converts the linear pixels stored across the `r`, `g` and `b` planes to `HSV`, storing the hue,
+/// saturation and value in the `h`, `s` and `v` planes respectively.
+///
+/// The results equal those of [`linear_to_hsv`](super::linear_to_hsv), including for `NaN` and out of range inputs,
+/// although zeros may differ in sign.
+///
+pub fn linear_to_hsv(r: &[f32], g: &[f32], b: &[f32], h: &mut [f32], s: &mut [f32], v: &mut [f32]) {
+  let n = [r.len(), g.len(), b.len(), h.len(), s.len(), v.len()]
+    .iter()
+    .copied()
+    .min()
+    .unwrap_or(0);
+
+  let (r, g, b) = (&r[..n], &g[..n], &b[..n]);
+  let (h, s, v) = (&mut h[..n], &mut s[..n], &mut v[..n]);
+
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe {
+    if is_x86_feature_detected!("avx2") {
+      avx2::linear_to_hsv([r, g, b], [h, s, v])
+    } else {
+      sse2::linear_to_hsv([r, g, b], [h, s, v])
+    }
+  };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  crate::rgb::make_iter(&r[done..], &g[done..], &b[done..])
+    .zip(crate::rgb::make_iter_mut(
+      &mut h[done..],
+      &mut s[done..],
+      &mut v[done..],
+    ))
+    .for_each(|(rgb, [h, s, v])| {
+      let [x, y, z] = super::linear_to_hsv(rgb);
+      *h = x;
+      *s = y;
+      *v = z;
+    });
+}
+
+/// `hsv_to_linear` converts the `HSV` pixels stored across the `h`, `s` and `v` planes to linear `RGB`, storing the
+/// results in the `r`, `g` and `b` planes respectively.
+///
+/// The results equal those of [`hsv_to_linear`](super::hsv_to_linear), including for `NaN` and out of range inputs,
+/// although zeros may differ in sign.
+///
+pub fn hsv_to_linear(h: &[f32], s: &[f32], v: &[f32], r: &mut [f32], g: &mut [f32], b: &mut [f32]) {
+  let n = [h.len(), s.len(), v.len(), r.len(), g.len(), b.len()]
+    .iter()
+    .copied()
+    .min()
+    .unwrap_or(0);
+
+  let (h, s, v) = (&h[..n], &s[..n], &v[..n]);
+  let (r, g, b) = (&mut r[..n], &mut g[..n], &mut b[..n]);
+
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe {
+    if is_x86_feature_detected!("avx2") {
+      avx2::hsv_to_linear([h, s, v], [r, g, b])
+    } else {
+      sse2::hsv_to_linear([h, s, v], [r, g, b])
+    }
+  };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  crate::rgb::make_iter(&h[done..], &s[done..], &v[done..])
+    .zip(crate::rgb::make_iter_mut(
+      &mut r[done..],
+      &mut g[done..],
+      &mut b[done..],
+    ))
+    .for_each(|(hsv, [r, g, b])| {
+      let [x, y, z] = super::hsv_to_linear(hsv);
+      *r = x;
+      *g = y;
+      *b = z;
+    });
+}
+
+/// `U8_MAX` is the divisor that maps the range `[0, 255]` onto `[0.0, 1.0]`.
+///
+const U8_MAX: f32 = 255.0;
+
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+  use core::arch::x86_64::{
+    __m128, __m128d, __m128i, _mm_add_pd, _mm_add_ps, _mm_and_ps, _mm_andnot_ps, _mm_cmpeq_ps,
+    _mm_cmpgt_ps, _mm_cmple_ps, _mm_cmplt_ps, _mm_cmpord_ps, _mm_cvtepi32_ps, _mm_cvtps_pd,
+    _mm_cvttpd_epi32, _mm_cvttps_epi32, _mm_div_ps, _mm_loadu_ps, _mm_loadu_si128, _mm_max_pd,
+    _mm_max_ps, _mm_min_pd, _mm_min_ps, _mm_movehl_ps, _mm_movemask_ps, _mm_mul_pd, _mm_mul_ps,
+    _mm_or_ps, _mm_packs_epi32, _mm_packus_epi16, _mm_set1_pd, _mm_set1_ps, _mm_setzero_pd,
+    _mm_setzero_ps, _mm_setzero_si128, _mm_storel_epi64, _mm_storeu_ps, _mm_sub_ps,
+    _mm_unpackhi_epi16, _mm_unpackhi_epi8, _mm_unpacklo_epi16, _mm_unpacklo_epi64,
+    _mm_unpacklo_epi8,
+  };
+
+  /// `select` picks the lanes of `a` where `mask` is set and those of `b` elsewhere.
+  ///
+  #[inline]
+  #[target_feature(enable = "sse2")]
+  unsafe fn select(mask: __m128, a: __m128, b: __m128) -> __m128 {
+    _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+  }
+
+  /// `nan_to_zero` replaces the `NaN` lanes of `x` with `0.0`.
+  ///
+  #[inline]
+  #[target_feature(enable = "sse2")]
+  unsafe fn nan_to_zero(x: __m128) -> __m128 {
+    _mm_and_ps(x, _mm_cmpord_ps(x, x))
+  }
+
+  /// `to_u8` rounds the scaled values to the nearest integer, saturating them to the range `[0, 255]`. The lower
+  /// bound is applied first so that `NaN` becomes `0`.
+  ///
+  #[inline]
+  #[target_feature(enable = "sse2")]
+  unsafe fn to_u8(x: __m128d) -> __m128i {
+    let x = _mm_max_pd(_mm_mul_pd(x, _mm_set1_pd(255.0)), _mm_setzero_pd());
+    let x = _mm_min_pd(x, _mm_set1_pd(255.0));
+    _mm_cvttpd_epi32(_mm_add_pd(x, _mm_set1_pd(0.5)))
+  }
+
+  /// Normalizes as many full blocks of 16 samples as possible, returning the number of samples written.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn u8_to_f32(src: &[u8], dst: &mut [f32]) -> usize {
+    let n = src.len() / 16 * 16;
+
+    let ps = src.as_ptr();
+    let pd = dst.as_mut_ptr();
+
+    let zero = _mm_setzero_si128();
+    let max = _mm_set1_ps(super::U8_MAX);
+
+    let mut i = 0;
+    while i < n {
+      let x = _mm_loadu_si128(ps.add(i).cast::<__m128i>());
+      let lo = _mm_unpacklo_epi8(x, zero);
+      let hi = _mm_unpackhi_epi8(x, zero);
+
+      let words = [
+        _mm_unpacklo_epi16(lo, zero),
+        _mm_unpackhi_epi16(lo, zero),
+        _mm_unpacklo_epi16(hi, zero),
+        _mm_unpackhi_epi16(hi, zero),
+      ];
+
+      for (j, w) in words.iter().enumerate() {
+        _mm_storeu_ps(pd.add(i + 4 * j), _mm_div_ps(_mm_cvtepi32_ps(*w), max));
+      }
+
+      i += 16;
+    }
+
+    n
+  }
+
+  /// Scales as many full blocks of 8 samples as possible, returning the number of samples written.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn f32_to_u8(src: &[f32], dst: &mut [u8]) -> usize {
+    let n = src.len() / 8 * 8;
+
+    let ps = src.as_ptr();
+    let pd = dst.as_mut_ptr();
+
+    let mut i = 0;
+    while i < n {
+      let x = _mm_loadu_ps(ps.add(i));
+      let y = _mm_loadu_ps(ps.add(i + 4));
+
+      let x = _mm_unpacklo_epi64(
+        to_u8(_mm_cvtps_pd(x)),
+        to_u8(_mm_cvtps_pd(_mm_movehl_ps(x, x))),
+      );
+      let y = _mm_unpacklo_epi64(
+        to_u8(_mm_cvtps_pd(y)),
+        to_u8(_mm_cvtps_pd(_mm_movehl_ps(y, y))),
+      );
+
+      let packed = _mm_packus_epi16(_mm_packs_epi32(x, y), _mm_setzero_si128());
+      _mm_storel_epi64(pd.add(i).cast::<__m128i>(), packed);
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Computes the luminance of as many full blocks of 4 pixels as possible, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn linear_to_gray(r: &[f32], g: &[f32], b: &[f32], out: &mut [f32]) -> usize {
+    let n = r.len() / 4 * 4;
+
+    let [wr, wg, wb] = crate::convert::GrayWeights::Luminance.coefficients();
+    let (wr, wg, wb) = (_mm_set1_ps(wr), _mm_set1_ps(wg), _mm_set1_ps(wb));
+
+    let mut i = 0;
+    while i < n {
+      let x = _mm_mul_ps(wr, _mm_loadu_ps(r.as_ptr().add(i)));
+      let y = _mm_mul_ps(wg, _mm_loadu_ps(g.as_ptr().add(i)));
+      let z = _mm_mul_ps(wb, _mm_loadu_ps(b.as_ptr().add(i)));
+
+      _mm_storeu_ps(out.as_mut_ptr().add(i), _mm_add_ps(_mm_add_ps(x, y), z));
+
+      i += 4;
+    }
+
+    n
+  }
+
+  /// Converts as many full blocks of 4 pixels as possible to `HSV`, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn linear_to_hsv([r, g, b]: [&[f32]; 3], [h, s, v]: [&mut [f32]; 3]) -> usize {
+    let n = r.len() / 4 * 4;
+
+    let zero = _mm_setzero_ps();
+    let two = _mm_set1_ps(2.0);
+    let four = _mm_set1_ps(4.0);
+    let sixty = _mm_set1_ps(60.0);
+    let infinity = _mm_set1_ps(f32::INFINITY);
+
+    let mut i = 0;
+    while i < n {
+      let [x, y, z] = [r, g, b].map(|plane| nan_to_zero(_mm_loadu_ps(plane.as_ptr().add(i))));
+
+      let x_max = _mm_max_ps(_mm_max_ps(x, y), z);
+      let x_min = _mm_min_ps(_mm_min_ps(x, y), z);
+      let c = _mm_sub_ps(x_max, x_min);
+
+      let k = select(
+        _mm_cmpeq_ps(c, infinity),
+        _mm_set1_ps(0.5),
+        _mm_set1_ps(1.0),
+      );
+      let [kx, ky, kz] = [x, y, z].map(|t| _mm_mul_ps(k, t));
+      let d = _mm_sub_ps(_mm_mul_ps(k, x_max), _mm_mul_ps(k, x_min));
+
+      let hue_r = _mm_mul_ps(sixty, _mm_add_ps(zero, _mm_div_ps(_mm_sub_ps(ky, kz), d)));
+      let hue_g = _mm_mul_ps(sixty, _mm_add_ps(two, _mm_div_ps(_mm_sub_ps(kz, kx), d)));
+      let hue_b = _mm_mul_ps(sixty, _mm_add_ps(four, _mm_div_ps(_mm_sub_ps(kx, ky), d)));
+
+      let hue = select(_mm_cmpeq_ps(x_max, y), hue_g, hue_b);
+      let hue = select(_mm_cmpeq_ps(x_max, x), hue_r, hue);
+      let hue = select(_mm_cmpeq_ps(c, zero), zero, hue);
+      let hue = select(
+        _mm_cmplt_ps(hue, zero),
+        _mm_add_ps(_mm_set1_ps(360.0), hue),
+        hue,
+      );
+
+      let saturation = select(_mm_cmpeq_ps(x_max, zero), zero, _mm_div_ps(c, x_max));
+
+      _mm_storeu_ps(h.as_mut_ptr().add(i), hue);
+      _mm_storeu_ps(s.as_mut_ptr().add(i), saturation);
+      _mm_storeu_ps(v.as_mut_ptr().add(i), x_max);
+
+      i += 4;
+    }
+
+    n
+  }
+
+  /// Converts as many full blocks of 4 `HSV` pixels as possible to linear `RGB`, returning the number of pixels
+  /// written.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn hsv_to_linear([h, s, v]: [&[f32]; 3], [r, g, b]: [&mut [f32]; 3]) -> usize {
+    let n = h.len() / 4 * 4;
+
+    let zero = _mm_setzero_ps();
+    let one = _mm_set1_ps(1.0);
+    let two = _mm_set1_ps(2.0);
+    let sign = _mm_set1_ps(-0.0);
+
+    let mut i = 0;
+    while i < n {
+      let [hue, saturation, value] =
+        [h, s, v].map(|plane| nan_to_zero(_mm_loadu_ps(plane.as_ptr().add(i))));
+
+      // hues outside of the color wheel are rare so they're wrapped one lane at a time
+      //
+      let outside = _mm_or_ps(
+        _mm_cmplt_ps(hue, zero),
+        _mm_cmpgt_ps(hue, _mm_set1_ps(360.0)),
+      );
+      let hue = if _mm_movemask_ps(outside) == 0 {
+        hue
+      } else {
+        let mut lanes = [0_f32; 4];
+        _mm_storeu_ps(lanes.as_mut_ptr(), hue);
+        _mm_loadu_ps(lanes.map(crate::convert::wrap_hue).as_ptr())
+      };
+
+      let c = _mm_mul_ps(saturation, value);
+      let hue = _mm_div_ps(hue, _mm_set1_ps(60.0));
+
+      // `hue % 2.0`, which is exact for the wrapped hues
+      //
+      let half = _mm_cvtepi32_ps(_mm_cvttps_epi32(_mm_mul_ps(hue, _mm_set1_ps(0.5))));
+      let rem = _mm_sub_ps(hue, _mm_mul_ps(two, half));
+      let x = _mm_mul_ps(
+        c,
+        _mm_sub_ps(one, _mm_andnot_ps(sign, _mm_sub_ps(rem, one))),
+      );
+
+      // the sextant the hue lies in determines which channel receives the chroma and which the intermediate value
+      //
+      let [le1, le2, le3, le4, le5] =
+        [1.0, 2.0, 3.0, 4.0, 5.0].map(|bound| _mm_cmple_ps(hue, _mm_set1_ps(bound)));
+      let (gt4, gt5) = (
+        _mm_cmpgt_ps(hue, _mm_set1_ps(4.0)),
+        _mm_cmpgt_ps(hue, _mm_set1_ps(5.0)),
+      );
+
+      let red = select(_mm_or_ps(le1, gt5), c, select(_mm_or_ps(le2, gt4), x, zero));
+      let green = select(le1, x, select(le3, c, select(le4, x, zero)));
+      let blue = select(le2, zero, select(le3, x, select(le5, c, x)));
+
+      let m = _mm_sub_ps(value, c);
+
+      _mm_storeu_ps(r.as_mut_ptr().add(i), _mm_add_ps(red, m));
+      _mm_storeu_ps(g.as_mut_ptr().add(i), _mm_add_ps(green, m));
+      _mm_storeu_ps(b.as_mut_ptr().add(i), _mm_add_ps(blue, m));
+
+      i += 4;
+    }
+
+    n
+  }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+  use core::arch::x86_64::{
+    __m128i, __m256, __m256d, _mm256_add_pd, _mm256_add_ps, _mm256_and_ps, _mm256_andnot_ps,
+    _mm256_blendv_ps, _mm256_castps_si256, _mm256_castsi256_si128, _mm256_cmp_ps,
+    _mm256_cvtepi32_ps, _mm256_cvtepu8_epi32, _mm256_cvtps_pd, _mm256_cvttpd_epi32,
+    _mm256_cvttps_epi32, _mm256_div_ps, _mm256_extracti128_si256, _mm256_i32gather_epi32,
+    _mm256_i32gather_ps, _mm256_loadu_ps, _mm256_max_pd, _mm256_max_ps, _mm256_min_pd,
+    _mm256_min_ps, _mm256_movemask_ps, _mm256_mul_pd, _mm256_mul_ps, _mm256_or_ps,
+    _mm256_packs_epi32, _mm256_packus_epi16, _mm256_set1_epi32, _mm256_set1_pd, _mm256_set1_ps,
+    _mm256_setzero_pd, _mm256_setzero_ps, _mm256_srli_epi32, _mm256_storeu_ps, _mm256_sub_epi32,
+    _mm256_sub_ps, _mm_loadl_epi64, _mm_loadu_ps, _mm_packs_epi32, _mm_packus_epi16,
+    _mm_setzero_si128, _mm_storel_epi64, _mm_unpacklo_epi32, _CMP_EQ_OQ, _CMP_GT_OQ, _CMP_LE_OQ,
+    _CMP_LT_OQ, _CMP_ORD_Q,
+  };
+
+  use crate::convert::lut;
+
+  /// `nan_to_zero` replaces the `NaN` lanes of `x` with `0.0`.
+  ///
+  #[inline]
+  #[target_feature(enable = "avx2")]
+  unsafe fn nan_to_zero(x: __m256) -> __m256 {
+    _mm256_and_ps(x, _mm256_cmp_ps::<_CMP_ORD_Q>(x, x))
+  }
+
+  /// `to_u8` rounds the scaled values to the nearest integer, saturating them to the range `[0, 255]`. The lower
+  /// bound is applied first so that `NaN` becomes `0`.
+  ///
+  #[inline]
+  #[target_feature(enable = "avx2")]
+  unsafe fn to_u8(x: __m256d) -> __m128i {
+    let x = _mm256_max_pd(_mm256_mul_pd(x, _mm256_set1_pd(255.0)), _mm256_setzero_pd());
+    let x = _mm256_min_pd(x, _mm256_set1_pd(255.0));
+    _mm256_cvttpd_epi32(_mm256_add_pd(x, _mm256_set1_pd(0.5)))
+  }
+
+  /// Decodes as many full blocks of 8 samples as possible, returning the number of samples written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn srgb_to_linear(src: &[u8], dst: &mut [f32]) -> usize {
+    let n = src.len() / 8 * 8;
+
+    let table = lut::SRGB_TO_LINEAR.as_ptr();
+
+    let mut i = 0;
+    while i < n {
+      let idx = _mm256_cvtepu8_epi32(_mm_loadl_epi64(src.as_ptr().add(i).cast::<__m128i>()));
+      _mm256_storeu_ps(
+        dst.as_mut_ptr().add(i),
+        _mm256_i32gather_ps::<4>(table, idx),
+      );
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Encodes as many full blocks of 8 samples as possible, returning the number of samples written.
+  ///
+  /// The vectorized form of [`linear_to_srgb`](crate::convert::linear_to_srgb): values are clamped to the range of the
+  /// bucket table, `NaN` becoming its lower bound, and each lane gathers its bucket and the threshold that follows it.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn linear_to_srgb(src: &[f32], dst: &mut [u8]) -> usize {
+    let n = src.len() / 8 * 8;
+
+    let buckets = lut::SRGB_BUCKETS.as_ptr();
+    let thresholds = lut::SRGB_THRESHOLDS.as_ptr();
+
+    let lower = _mm256_set1_ps(f32::from_bits(lut::BUCKET_MIN_BITS));
+    let min_bits = _mm256_set1_epi32(lut::BUCKET_MIN_BITS as i32);
+
+    let mut i = 0;
+    while i < n {
+      let x = _mm256_loadu_ps(src.as_ptr().add(i));
+      let x = _mm256_max_ps(
+        _mm256_min_ps(_mm256_max_ps(x, _mm256_setzero_ps()), _mm256_set1_ps(1.0)),
+        lower,
+      );
+
+      let idx = _mm256_srli_epi32::<{ lut::BUCKET_SHIFT as i32 }>(_mm256_sub_epi32(
+        _mm256_castps_si256(x),
+        min_bits,
+      ));
+      let k = _mm256_i32gather_epi32::<4>(buckets, idx);
+
+      let next = _mm256_i32gather_ps::<4>(thresholds.add(1), k);
+      let k = _mm256_sub_epi32(k, _mm256_castps_si256(_mm256_cmp_ps::<_CMP_LE_OQ>(next, x)));
+
+      let words = _mm256_packs_epi32(k, k);
+      let bytes = _mm256_packus_epi16(words, words);
+
+      let packed = _mm_unpacklo_epi32(
+        _mm256_castsi256_si128(bytes),
+        _mm256_extracti128_si256::<1>(bytes),
+      );
+      _mm_storel_epi64(dst.as_mut_ptr().add(i).cast::<__m128i>(), packed);
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Normalizes as many full blocks of 16 samples as possible, returning the number of samples written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn u8_to_f32(src: &[u8], dst: &mut [f32]) -> usize {
+    let n = src.len() / 16 * 16;
+
+    let max = _mm256_set1_ps(super::U8_MAX);
+
+    let mut i = 0;
+    while i < n {
+      for j in [0, 8] {
+        let x = _mm256_cvtepu8_epi32(_mm_loadl_epi64(src.as_ptr().add(i + j).cast::<__m128i>()));
+        _mm256_storeu_ps(
+          dst.as_mut_ptr().add(i + j),
+          _mm256_div_ps(_mm256_cvtepi32_ps(x), max),
+        );
+      }
+
+      i += 16;
+    }
+
+    n
+  }
+
+  /// Scales as many full blocks of 8 samples as possible, returning the number of samples written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn f32_to_u8(src: &[f32], dst: &mut [u8]) -> usize {
+    let n = src.len() / 8 * 8;
+
+    let mut i = 0;
+    while i < n {
+      let x = to_u8(_mm256_cvtps_pd(_mm_loadu_ps(src.as_ptr().add(i))));
+      let y = to_u8(_mm256_cvtps_pd(_mm_loadu_ps(src.as_ptr().add(i + 4))));
+
+      let packed = _mm_packus_epi16(_mm_packs_epi32(x, y), _mm_setzero_si128());
+      _mm_storel_epi64(dst.as_mut_ptr().add(i).cast::<__m128i>(), packed);
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Computes the luminance of as many full blocks of 8 pixels as possible, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn linear_to_gray(r: &[f32], g: &[f32], b: &[f32], out: &mut [f32]) -> usize {
+    let n = r.len() / 8 * 8;
+
+    let [wr, wg, wb] = crate::convert::GrayWeights::Luminance.coefficients();
+    let (wr, wg, wb) = (_mm256_set1_ps(wr), _mm256_set1_ps(wg), _mm256_set1_ps(wb));
+
+    let mut i = 0;
+    while i < n {
+      let x = _mm256_mul_ps(wr, _mm256_loadu_ps(r.as_ptr().add(i)));
+      let y = _mm256_mul_ps(wg, _mm256_loadu_ps(g.as_ptr().add(i)));
+      let z = _mm256_mul_ps(wb, _mm256_loadu_ps(b.as_ptr().add(i)));
+
+      _mm256_storeu_ps(
+        out.as_mut_ptr().add(i),
+        _mm256_add_ps(_mm256_add_ps(x, y), z),
+      );
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Converts as many full blocks of 8 pixels as possible to `HSV`, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn linear_to_hsv([r, g, b]: [&[f32]; 3], [h, s, v]: [&mut [f32]; 3]) -> usize {
+    let n = r.len() / 8 * 8;
+
+    let zero = _mm256_setzero_ps();
+    let two = _mm256_set1_ps(2.0);
+    let four = _mm256_set1_ps(4.0);
+    let sixty = _mm256_set1_ps(60.0);
+    let infinity = _mm256_set1_ps(f32::INFINITY);
+
+    let mut i = 0;
+    while i < n {
+      let [x, y, z] = [r, g, b].map(|plane| nan_to_zero(_mm256_loadu_ps(plane.as_ptr().add(i))));
+
+      let x_max = _mm256_max_ps(_mm256_max_ps(x, y), z);
+      let x_min = _mm256_min_ps(_mm256_min_ps(x, y), z);
+      let c = _mm256_sub_ps(x_max, x_min);
+
+      let k = _mm256_blendv_ps(
+        _mm256_set1_ps(1.0),
+        _mm256_set1_ps(0.5),
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(c, infinity),
+      );
+      let [kx, ky, kz] = [x, y, z].map(|t| _mm256_mul_ps(k, t));
+      let d = _mm256_sub_ps(_mm256_mul_ps(k, x_max), _mm256_mul_ps(k, x_min));
+
+      let hue_r = _mm256_mul_ps(
+        sixty,
+        _mm256_add_ps(zero, _mm256_div_ps(_mm256_sub_ps(ky, kz), d)),
+      );
+      let hue_g = _mm256_mul_ps(
+        sixty,
+        _mm256_add_ps(two, _mm256_div_ps(_mm256_sub_ps(kz, kx), d)),
+      );
+      let hue_b = _mm256_mul_ps(
+        sixty,
+        _mm256_add_ps(four, _mm256_div_ps(_mm256_sub_ps(kx, ky), d)),
+      );
+
+      let hue = _mm256_blendv_ps(hue_b, hue_g, _mm256_cmp_ps::<_CMP_EQ_OQ>(x_max, y));
+      let hue = _mm256_blendv_ps(hue, hue_r, _mm256_cmp_ps::<_CMP_EQ_OQ>(x_max, x));
+      let hue = _mm256_blendv_ps(hue, zero, _mm256_cmp_ps::<_CMP_EQ_OQ>(c, zero));
+      let hue = _mm256_blendv_ps(
+        hue,
+        _mm256_add_ps(_mm256_set1_ps(360.0), hue),
+        _mm256_cmp_ps::<_CMP_LT_OQ>(hue, zero),
+      );
+
+      let saturation = _mm256_blendv_ps(
+        _mm256_div_ps(c, x_max),
+        zero,
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(x_max, zero),
+      );
+
+      _mm256_storeu_ps(h.as_mut_ptr().add(i), hue);
+      _mm256_storeu_ps(s.as_mut_ptr().add(i), saturation);
+      _mm256_storeu_ps(v.as_mut_ptr().add(i), x_max);
+
+      i += 8;
+    }
+
+    n
+  }
+
+  /// Converts as many full blocks of 8 `HSV` pixels as possible to linear `RGB`, returning the number of pixels
+  /// written.
+  ///
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn hsv_to_linear([h, s, v]: [&[f32]; 3], [r, g, b]: [&mut [f32]; 3]) -> usize {
+    let n = h.len() / 8 * 8;
+
+    let zero = _mm256_setzero_ps();
+    let one = _mm256_set1_ps(1.0);
+    let two = _mm256_set1_ps(2.0);
+    let sign = _mm256_set1_ps(-0.0);
+
+    let mut i = 0;
+    while i < n {
+      let [hue, saturation, value] =
+        [h, s, v].map(|plane| nan_to_zero(_mm256_loadu_ps(plane.as_ptr().add(i))));
+
+      // hues outside of the color wheel are rare so they're wrapped one lane at a time
+      //
+      let outside = _mm256_or_ps(
+        _mm256_cmp_ps::<_CMP_LT_OQ>(hue, zero),
+        _mm256_cmp_ps::<_CMP_GT_OQ>(hue, _mm256_set1_ps(360.0)),
+      );
+      let hue = if _mm256_movemask_ps(outside) == 0 {
+        hue
+      } else {
+        let mut lanes = [0_f32; 8];
+        _mm256_storeu_ps(lanes.as_mut_ptr(), hue);
+        _mm256_loadu_ps(lanes.map(crate::convert::wrap_hue).as_ptr())
+      };
+
+      let c = _mm256_mul_ps(saturation, value);
+      let hue = _mm256_div_ps(hue, _mm256_set1_ps(60.0));
+
+      // `hue % 2.0`, which is exact for the wrapped hues
+      //
+      let half = _mm256_cvtepi32_ps(_mm256_cvttps_epi32(_mm256_mul_ps(hue, _mm256_set1_ps(0.5))));
+      let rem = _mm256_sub_ps(hue, _mm256_mul_ps(two, half));
+      let x = _mm256_mul_ps(
+        c,
+        _mm256_sub_ps(one, _mm256_andnot_ps(sign, _mm256_sub_ps(rem, one))),
+      );
+
+      // the sextant the hue lies in determines which channel receives the chroma and which the intermediate value
+      //
+      let [le1, le2, le3, le4, le5] = [1.0, 2.0, 3.0, 4.0, 5.0]
+        .map(|bound| _mm256_cmp_ps::<_CMP_LE_OQ>(hue, _mm256_set1_ps(bound)));
+      let (gt4, gt5) = (
+        _mm256_cmp_ps::<_CMP_GT_OQ>(hue, _mm256_set1_ps(4.0)),
+        _mm256_cmp_ps::<_CMP_GT_OQ>(hue, _mm256_set1_ps(5.0)),
+      );
+
+      let red = _mm256_blendv_ps(
+        _mm256_blendv_ps(zero, x, _mm256_or_ps(le2, gt4)),
+        c,
+        _mm256_or_ps(le1, gt5),
+      );
+      let green = _mm256_blendv_ps(
+        _mm256_blendv_ps(_mm256_blendv_ps(zero, x, le4), c, le3),
+        x,
+        le1,
+      );
+      let blue = _mm256_blendv_ps(
+        _mm256_blendv_ps(_mm256_blendv_ps(x, c, le5), x, le3),
+        zero,
+        le2,
+      );
+
+      let m = _mm256_sub_ps(value, c);
+
+      _mm256_storeu_ps(r.as_mut_ptr().add(i), _mm256_add_ps(red, m));
+      _mm256_storeu_ps(g.as_mut_ptr().add(i), _mm256_add_ps(green, m));
+      _mm256_storeu_ps(b.as_mut_ptr().add(i), _mm256_add_ps(blue, m));
+
+      i += 8;
+    }
+
+    n
+  }
+}
@@ -0,0 +1,586 @@
+//! `lut` holds the precomputed tables behind the 8-bit `sRGB` conversions, [`srgb_to_linear`](super::srgb_to_linear)
+//! and [`linear_to_srgb`](super::linear_to_srgb).
+//!
+//! Both tables were generated by evaluating the [`Srgb`](super::transfer::Srgb) curve in `f32` and must be kept in sync
+//! with it, the `srgb_lut` tests verify this.
+//!
+
+/// `SRGB_TO_LINEAR` maps every 8-bit `sRGB` value to its linear value.
+///
+pub(super) static SRGB_TO_LINEAR: [f32; 256] = [
+  0.0,
+  0.000_303_527,
+  0.000_607_054,
+  0.000_910_581_03,
+  0.001_214_108,
+  0.001_517_635,
+  0.001_821_162_1,
+  0.002_124_689,
+  0.002_428_216,
+  0.002_731_743,
+  0.003_035_27,
+  0.003_346_535,
+  0.003_676_507,
+  0.004_024_716_6,
+  0.004_391_441,
+  0.004_776_952_3,
+  0.005_181_516,
+  0.005_605_390_8,
+  0.006_048_831_6,
+  0.006_512_089,
+  0.006_995_408_3,
+  0.007_499_029_4,
+  0.008_023_192,
+  0.008_568_125,
+  0.009_134_057,
+  0.009_721_216,
+  0.010_329_82,
+  0.010_960_091,
+  0.011_612_242,
+  0.012_286_484,
+  0.012_983_031,
+  0.013_702_081,
+  0.014_443_841,
+  0.015_208_512,
+  0.015_996_29,
+  0.016_807_372,
+  0.017_641_949,
+  0.018_500_214,
+  0.019_382_358,
+  0.020_288_559,
+  0.021_219_006,
+  0.022_173_88,
+  0.023_153_361,
+  0.024_157_627,
+  0.025_186_852,
+  0.026_241_213,
+  0.027_320_888,
+  0.028_426_034,
+  0.029_556_837,
+  0.030_713_446,
+  0.031_896_036,
+  0.033_104_766,
+  0.034_339_808,
+  0.035_601_314,
+  0.036_889_452,
+  0.038_204_376,
+  0.039_546_236,
+  0.040_915_2,
+  0.042_311_41,
+  0.043_735_027,
+  0.045_186_203,
+  0.046_665_084,
+  0.048_171_822,
+  0.049_706_563,
+  0.051_269_453,
+  0.052_860_64,
+  0.054_480_27,
+  0.056_128_494,
+  0.057_805_434,
+  0.059_511_24,
+  0.061_246_056,
+  0.063_010_02,
+  0.064_803_265,
+  0.066_625_94,
+  0.068_478_17,
+  0.070_360_094,
+  0.072_271_846,
+  0.074_213_564,
+  0.076_185_375,
+  0.078_187_41,
+  0.080_219_81,
+  0.082_282_7,
+  0.084_376_2,
+  0.086_500_466,
+  0.088_655_59,
+  0.090_841_71,
+  0.093_058_966,
+  0.095_307_46,
+  0.097_587_35,
+  0.099_898_726,
+  0.102_241_725,
+  0.104_616_48,
+  0.107_023_1,
+  0.109_461_7,
+  0.111_932_42,
+  0.114_435_36,
+  0.116_970_67,
+  0.119_538_434,
+  0.122_138_776,
+  0.124_771_84,
+  0.127_437_7,
+  0.130_136_49,
+  0.132_868_33,
+  0.135_633_35,
+  0.138_431_62,
+  0.141_263_3,
+  0.144_128_49,
+  0.147_027_28,
+  0.149_959_8,
+  0.152_926_16,
+  0.155_926_47,
+  0.158_960_83,
+  0.162_029_37,
+  0.165_132_2,
+  0.168_269_4,
+  0.171_441_12,
+  0.174_647_42,
+  0.177_888_44,
+  0.181_164_26,
+  0.184_475,
+  0.187_820_79,
+  0.191_201_67,
+  0.194_617_81,
+  0.198_069_35,
+  0.201_556_3,
+  0.205_078_77,
+  0.208_636_9,
+  0.212_230_79,
+  0.215_860_53,
+  0.219_526_23,
+  0.223_227_98,
+  0.226_965_89,
+  0.230_740_07,
+  0.234_550_6,
+  0.238_397_58,
+  0.242_281_14,
+  0.246_201_34,
+  0.250_158_28,
+  0.254_152_1,
+  0.258_182_85,
+  0.262_250_66,
+  0.266_355_6,
+  0.270_497_8,
+  0.274_677_3,
+  0.278_894_25,
+  0.283_148_74,
+  0.287_440_84,
+  0.291_770_64,
+  0.296_138_26,
+  0.300_543_79,
+  0.304_987_28,
+  0.309_468_9,
+  0.313_988_7,
+  0.318_546_74,
+  0.323_143_18,
+  0.327_778_13,
+  0.332_451_58,
+  0.337_163_66,
+  0.341_914_45,
+  0.346_704_1,
+  0.351_532_64,
+  0.356_400_16,
+  0.361_306_8,
+  0.366_252_6,
+  0.371_237_7,
+  0.376_262_13,
+  0.381_326_02,
+  0.386_429_43,
+  0.391_572_48,
+  0.396_755_22,
+  0.401_977_78,
+  0.407_240_2,
+  0.412_542_6,
+  0.417_885_07,
+  0.423_267_66,
+  0.428_690_46,
+  0.434_153_62,
+  0.439_657_15,
+  0.445_201_16,
+  0.450_785_76,
+  0.456_410_97,
+  0.462_076_96,
+  0.467_783_75,
+  0.473_531_45,
+  0.479_320_14,
+  0.485_149_9,
+  0.491_020_9,
+  0.496_933_04,
+  0.502_886_5,
+  0.508_881_33,
+  0.514_917_7,
+  0.520_995_6,
+  0.527_115_2,
+  0.533_276_5,
+  0.539_479_6,
+  0.545_724_6,
+  0.552_011_5,
+  0.558_340_5,
+  0.564_711_63,
+  0.571_124_9,
+  0.577_580_5,
+  0.584_078_5,
+  0.590_618_9,
+  0.597_201_9,
+  0.603_827_4,
+  0.610_495_6,
+  0.617_206_63,
+  0.623_960_44,
+  0.630_757_2,
+  0.637_596_96,
+  0.644_479_75,
+  0.651_405_7,
+  0.658_374_85,
+  0.665_387_33,
+  0.672_443_2,
+  0.679_542_54,
+  0.686_685_3,
+  0.693_871_8,
+  0.701_101_9,
+  0.708_375_8,
+  0.715_693_65,
+  0.723_055_24,
+  0.730_460_9,
+  0.737_910_57,
+  0.745_404_36,
+  0.752_942_3,
+  0.760_524_63,
+  0.768_151_3,
+  0.775_822_34,
+  0.783_537_9,
+  0.791_298_03,
+  0.799_102_84,
+  0.806_952_36,
+  0.814_846_7,
+  0.822_785_85,
+  0.830_769_96,
+  0.838_799_1,
+  0.846_873_3,
+  0.854_992_7,
+  0.863_157_3,
+  0.871_367_2,
+  0.879_622_46,
+  0.887_923_06,
+  0.896_269_4,
+  0.904_661_1,
+  0.913_098_7,
+  0.921_581_75,
+  0.930_110_9,
+  0.938_685_6,
+  0.947_306_6,
+  0.955_973_4,
+  0.964_686_4,
+  0.973_445_3,
+  0.982_250_7,
+  0.991_102_1,
+  0.999_999_9,
+];
+
+/// `SRGB_THRESHOLDS` holds, for every 8-bit `sRGB` value `k`, the smallest linear value that encodes to at least `k`.
+/// The first entry is unused as every value encodes to at least `0` and the final entry is a sentinel.
+///
+/// The encoding is monotonic over all of `f32` so the encoded value of `x` is the largest `k` with
+/// `SRGB_THRESHOLDS[k] <= x`.
+///
+pub(super) static SRGB_THRESHOLDS: [f32; 257] = [
+  0.0,
+  0.000_151_763_5,
+  0.000_455_290_5,
+  0.000_758_817_5,
+  0.001_062_344_4,
+  0.001_365_871_5,
+  0.001_669_398_4,
+  0.001_972_925_4,
+  0.002_276_452_5,
+  0.002_579_979_4,
+  0.002_883_506_5,
+  0.003_188_301,
+  0.003_509_259_3,
+  0.003_848_315_2,
+  0.004_205_748_4,
+  0.004_581_833,
+  0.004_976_838,
+  0.005_391_024_5,
+  0.005_824_650_6,
+  0.006_277_969,
+  0.006_751_227,
+  0.007_244_668,
+  0.007_758_53,
+  0.008_293_048,
+  0.008_848_452,
+  0.009_424_971,
+  0.010_022_826,
+  0.010_642_237,
+  0.011_283_421,
+  0.011_946_592,
+  0.012_631_958,
+  0.013_339_73,
+  0.014_070_112,
+  0.014_823_305,
+  0.015_599_506,
+  0.016_398_912,
+  0.017_221_719,
+  0.018_068_116,
+  0.018_938_296,
+  0.019_832_447,
+  0.020_750_748,
+  0.021_693_386,
+  0.022_660_54,
+  0.023_652_392,
+  0.024_669_116,
+  0.025_710_888,
+  0.026_777_888,
+  0.027_870_275,
+  0.028_988_224,
+  0.030_131_904,
+  0.031_301_484,
+  0.032_497_13,
+  0.033_718_996,
+  0.034_967_247,
+  0.036_242_045,
+  0.037_543_554,
+  0.038_871_925,
+  0.040_227_33,
+  0.041_609_9,
+  0.043_019_794,
+  0.044_457_17,
+  0.045_922_183,
+  0.047_414_973,
+  0.048_935_693,
+  0.050_484_49,
+  0.052_061_513,
+  0.053_666_9,
+  0.055_300_806,
+  0.056_963_36,
+  0.058_654_718,
+  0.060_375_027,
+  0.062_124_398,
+  0.063_902_99,
+  0.065_710_93,
+  0.067_548_364,
+  0.069_415_43,
+  0.071_312_25,
+  0.073_238_97,
+  0.075_195_715,
+  0.077_182_62,
+  0.079_199_82,
+  0.081_247_45,
+  0.083_325_624,
+  0.085_434_49,
+  0.087_574_18,
+  0.089_744_784,
+  0.091_946_45,
+  0.094_179_32,
+  0.096_443_49,
+  0.098_739_1,
+  0.101_066_284,
+  0.103_425_145,
+  0.105_815_81,
+  0.108_238_41,
+  0.110_693_05,
+  0.113_179_87,
+  0.115_698_97,
+  0.118_250_48,
+  0.120_834_544,
+  0.123_451_22,
+  0.126_100_66,
+  0.128_782_97,
+  0.131_498_28,
+  0.134_246_69,
+  0.137_028_32,
+  0.139_843_29,
+  0.142_691_7,
+  0.145_573_68,
+  0.148_489_31,
+  0.151_438_74,
+  0.154_422_06,
+  0.157_439_4,
+  0.160_490_86,
+  0.163_576_51,
+  0.166_696_49,
+  0.169_850_93,
+  0.173_039_91,
+  0.176_263_56,
+  0.179_522,
+  0.182_815_27,
+  0.186_143_52,
+  0.189_506_83,
+  0.192_905_34,
+  0.196_339_15,
+  0.199_808_34,
+  0.203_313_04,
+  0.206_853_33,
+  0.210_429_33,
+  0.214_041_13,
+  0.217_688_83,
+  0.221_372_54,
+  0.225_092_37,
+  0.228_848_46,
+  0.232_640_79,
+  0.236_469_54,
+  0.240_334_8,
+  0.244_236_66,
+  0.248_175_23,
+  0.252_150_6,
+  0.256_162_88,
+  0.260_212_15,
+  0.264_298_5,
+  0.268_422_07,
+  0.272_582_9,
+  0.276_781_1,
+  0.281_016_83,
+  0.285_290_1,
+  0.289_601_03,
+  0.293_949_72,
+  0.298_336_3,
+  0.302_760_8,
+  0.307_223_35,
+  0.311_724_04,
+  0.316_262_96,
+  0.320_840_18,
+  0.325_455_8,
+  0.330_109_98,
+  0.334_802_72,
+  0.339_534_13,
+  0.344_304_35,
+  0.349_113_5,
+  0.353_961_53,
+  0.358_848_6,
+  0.363_774_84,
+  0.368_740_26,
+  0.373_745_02,
+  0.378_789_16,
+  0.383_872_8,
+  0.388_996_03,
+  0.394_158_9,
+  0.399_361_55,
+  0.404_604_02,
+  0.409_886_42,
+  0.415_208_85,
+  0.420_571_36,
+  0.425_974_04,
+  0.431_417_02,
+  0.436_900_35,
+  0.442_424_12,
+  0.447_988_4,
+  0.453_593_3,
+  0.459_238_9,
+  0.464_925_26,
+  0.470_652_5,
+  0.476_420_67,
+  0.482_229_9,
+  0.488_080_2,
+  0.493_971_82,
+  0.499_904_6,
+  0.505_878_75,
+  0.511_894_35,
+  0.517_951_5,
+  0.524_050_2,
+  0.530_190_6,
+  0.536_372_8,
+  0.542_596_76,
+  0.548_862_7,
+  0.555_170_66,
+  0.561_520_7,
+  0.567_912_94,
+  0.574_347_4,
+  0.580_824_14,
+  0.587_343_34,
+  0.593_905_03,
+  0.600_509_2,
+  0.607_156_1,
+  0.613_845_7,
+  0.620_578_1,
+  0.627_353_37,
+  0.634_171_6,
+  0.641_032_9,
+  0.647_937_24,
+  0.654_884_8,
+  0.661_875_6,
+  0.668_909_8,
+  0.675_987_5,
+  0.683_108_5,
+  0.690_273_17,
+  0.697_481_45,
+  0.704_733_43,
+  0.712_029_2,
+  0.719_368_9,
+  0.726_752_46,
+  0.734_180_1,
+  0.741_651_83,
+  0.749_167_74,
+  0.756_727_9,
+  0.764_332_3,
+  0.771_981_2,
+  0.779_674_5,
+  0.787_412_3,
+  0.795_194_75,
+  0.803_021_9,
+  0.810_893_83,
+  0.818_810_5,
+  0.826_772_2,
+  0.834_778_8,
+  0.842_830_5,
+  0.850_927_23,
+  0.859_069_2,
+  0.867_256_46,
+  0.875_489_06,
+  0.883_767_2,
+  0.892_090_5,
+  0.900_459_65,
+  0.908_874_15,
+  0.917_334_6,
+  0.925_840_56,
+  0.934_392_63,
+  0.942_990_3,
+  0.951_634_2,
+  0.960_323_9,
+  0.969_060_06,
+  0.977_842_03,
+  0.986_670_55,
+  0.995_545_27,
+  f32::INFINITY,
+];
+
+/// `BUCKET_MIN_BITS` is the bit pattern of `2^-13`, below which every linear value encodes to `0`.
+///
+pub(super) const BUCKET_MIN_BITS: u32 = 114 << 23;
+
+/// `BUCKET_SHIFT` splits every power of two between `2^-13` and `1.0` into 128 buckets, fine enough that no bucket
+/// spans more than a single threshold.
+///
+pub(super) const BUCKET_SHIFT: u32 = 16;
+
+/// `BUCKETS` is the number of buckets, including the final one that only holds `1.0`.
+///
+pub(super) const BUCKETS: usize = ((0x3f80_0000 - BUCKET_MIN_BITS) >> BUCKET_SHIFT) as usize + 1;
+
+/// `SRGB_BUCKETS` holds the value that the smallest linear value of every bucket encodes to. Values within a bucket
+/// encode to either that value or the one after it, which is resolved with a single comparison against
+/// `SRGB_THRESHOLDS`.
+///
+/// The entries are stored as `i32` so that they can be fetched with vector gathers.
+///
+pub(super) static SRGB_BUCKETS: [i32; BUCKETS] = srgb_buckets();
+
+/// `bucket` returns the index of the bucket `x` falls in. `x` must already be clamped to the range `[2^-13, 1.0]`.
+///
+pub(super) fn bucket(x: f32) -> usize {
+  ((x.to_bits() - BUCKET_MIN_BITS) >> BUCKET_SHIFT) as usize
+}
+
+/// `srgb_buckets` computes `SRGB_BUCKETS` by searching `SRGB_THRESHOLDS` for the start of every bucket.
+///
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn srgb_buckets() -> [i32; BUCKETS] {
+  let mut buckets = [0; BUCKETS];
+
+  let mut i = 0;
+  while i < BUCKETS {
+    let x = f32::from_bits(BUCKET_MIN_BITS + ((i as u32) << BUCKET_SHIFT));
+
+    let mut k = 0;
+    let mut step = 128;
+    while step > 0 {
+      if SRGB_THRESHOLDS[k + step] <= x {
+        k += step;
+      }
+      step /= 2;
+    }
+
+    buckets[i] = k as i32;
+    i += 1;
+  }
+
+  buckets
+}
@@ -0,0 +1,233 @@
+//! `transfer` contains the transfer functions, sometimes called "gamma curves", that map between linear light and the
+//! non-linear signal images are stored in.
+//!
+//! Every curve implements [`TransferFunction`], which operates on normalized values so that the same curve can be used
+//! regardless of the bit depth of the image. The trait is object safe, enabling the curve to be selected at runtime
+//! through a `&dyn TransferFunction`.
+//!
+//! # Example
+//! ```
+//! use cvr::convert::iter::{DecodeIterator, EncodeIterator};
+//! use cvr::convert::transfer::{Pq, Rec709, TransferFunction};
+//!
+//! // 100 nits is 1% of the 10,000 nit range covered by `PQ`
+//! //
+//! let signal = Pq.encode(0.01);
+//! assert!((signal - 0.508).abs() < 1e-3);
+//! assert!((Pq.decode(signal) - 0.01).abs() < 1e-6);
+//!
+//! let pixels = [[16_u8, 128, 235]];
+//! let roundtrip: Vec<[u8; 3]> = pixels.iter().copied().decode(Rec709).encode(Rec709).collect();
+//! assert_eq!(roundtrip, pixels);
+//! ```
+//!
+
+/// `TransferFunction` converts normalized values between a non-linear encoding and linear light.
+///
+/// Both directions operate on values in the range `[0.0, 1.0]`; behavior outside of that range is curve specific.
+///
+pub trait TransferFunction {
+  /// `decode` converts the supplied encoded signal value to linear light. This is the inverse of `encode`.
+  ///
+  fn decode(&self, x: f32) -> f32;
+
+  /// `encode` converts the supplied linear light value to its encoded signal value. This is the inverse of `decode`.
+  ///
+  fn encode(&self, x: f32) -> f32;
+}
+
+impl<F> TransferFunction for &F
+where
+  F: TransferFunction + ?Sized,
+{
+  fn decode(&self, x: f32) -> f32 {
+    (**self).decode(x)
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    (**self).encode(x)
+  }
+}
+
+/// `Linear` is the identity curve, for data that is already stored as linear light.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Linear;
+
+impl TransferFunction for Linear {
+  fn decode(&self, x: f32) -> f32 {
+    x
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    x
+  }
+}
+
+/// `Srgb` is the piecewise [`sRGB`](https://en.wikipedia.org/wiki/SRGB) curve used by
+/// [`srgb_to_linear`](crate::convert::srgb_to_linear) and [`linear_to_srgb`](crate::convert::linear_to_srgb).
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Srgb;
+
+impl TransferFunction for Srgb {
+  fn decode(&self, x: f32) -> f32 {
+    if x <= 0.04045 {
+      // 1 / 12.92 => 0.0773993808
+      //
+      x * 0.077_399_38
+    } else {
+      // 1/ 1.055 => 0.947867299
+      //
+      ((x + 0.055) * 0.947_867_3).powf(2.4)
+    }
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    if x <= 0.003_130_8 {
+      12.92 * x
+    } else {
+      // 1 / 2.4 => 0.416666667
+      //
+      1.055 * x.powf(0.416_666_66) - 0.055
+    }
+  }
+}
+
+/// `Rec709` is the [`BT.709`](https://en.wikipedia.org/wiki/Rec._709#Transfer_characteristics) camera curve, also
+/// used by `BT.601` and `BT.2020` standard dynamic range video.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rec709;
+
+impl TransferFunction for Rec709 {
+  fn decode(&self, x: f32) -> f32 {
+    if x < 0.081 {
+      x / 4.5
+    } else {
+      ((x + 0.099) / 1.099).powf(1.0 / 0.45)
+    }
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    if x < 0.018 {
+      4.5 * x
+    } else {
+      1.099 * x.powf(0.45) - 0.099
+    }
+  }
+}
+
+/// `Gamma` is a pure power law curve with the supplied exponent, such that `decode(x) = x ^ gamma`. Negative values are
+/// treated as `0.0`.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma(pub f32);
+
+impl TransferFunction for Gamma {
+  fn decode(&self, x: f32) -> f32 {
+    x.max(0.0).powf(self.0)
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    x.max(0.0).powf(self.0.recip())
+  }
+}
+
+/// `AdobeRgb` is the pure power law curve of the [`Adobe RGB (1998)`](https://en.wikipedia.org/wiki/Adobe_RGB_color_space)
+/// color space, with an exponent of `563 / 256`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AdobeRgb;
+
+impl TransferFunction for AdobeRgb {
+  fn decode(&self, x: f32) -> f32 {
+    Gamma(563.0 / 256.0).decode(x)
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    Gamma(563.0 / 256.0).encode(x)
+  }
+}
+
+/// `Pq` is the [`SMPTE ST 2084`](https://en.wikipedia.org/wiki/Perceptual_quantizer) perceptual quantizer used by
+/// `HDR10` and `Dolby Vision`.
+///
+/// `PQ` describes absolute luminance, a linear value of `1.0` corresponds to `10,000` nits.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Pq;
+
+impl Pq {
+  const M1: f32 = 2610.0 / 16384.0;
+  const M2: f32 = 2523.0 / 4096.0 * 128.0;
+  const C1: f32 = 3424.0 / 4096.0;
+  const C2: f32 = 2413.0 / 4096.0 * 32.0;
+  const C3: f32 = 2392.0 / 4096.0 * 32.0;
+}
+
+impl TransferFunction for Pq {
+  fn decode(&self, x: f32) -> f32 {
+    let p = x.max(0.0).powf(Self::M2.recip());
+    ((p - Self::C1).max(0.0) / (Self::C2 - Self::C3 * p)).powf(Self::M1.recip())
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    let y = x.max(0.0).powf(Self::M1);
+    ((Self::C1 + Self::C2 * y) / (1.0 + Self::C3 * y)).powf(Self::M2)
+  }
+}
+
+/// `Hlg` is the [`Hybrid Log-Gamma`](https://en.wikipedia.org/wiki/Hybrid_log%E2%80%93gamma) curve of `BT.2100`.
+///
+/// Linear values are relative scene light, the `HLG` opto-electronic transfer function is applied without any display
+/// specific system gamma.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hlg;
+
+impl Hlg {
+  const A: f32 = 0.178_832_77;
+  const B: f32 = 0.284_668_92;
+  const C: f32 = 0.559_910_7;
+}
+
+impl TransferFunction for Hlg {
+  fn decode(&self, x: f32) -> f32 {
+    if x <= 0.5 {
+      x * x / 3.0
+    } else {
+      (((x - Self::C) / Self::A).exp() + Self::B) / 12.0
+    }
+  }
+
+  fn encode(&self, x: f32) -> f32 {
+    if x <= 1.0 / 12.0 {
+      (3.0 * x.max(0.0)).sqrt()
+    } else {
+      Self::A * (12.0 * x - Self::B).ln() + Self::C
+    }
+  }
+}
+
+/// `decode_sample` normalizes `x` by the sample type's full intensity and linearizes it with `curve`.
+///
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn decode_sample<T, F>(curve: &F, x: T) -> f32
+where
+  T: crate::Numeric,
+  F: TransferFunction + ?Sized,
+{
+  curve.decode((x.to_f64() / T::SCALE) as f32)
+}
+
+/// `encode_sample` applies `curve` to the linear value `x` and scales the result to the sample type's full intensity,
+/// saturating it.
+///
+pub(crate) fn encode_sample<T, F>(curve: &F, x: f32) -> T
+where
+  T: crate::Numeric,
+  F: TransferFunction + ?Sized,
+{
+  T::saturating_from_f64(f64::from(curve.encode(x)) * T::SCALE)
+}
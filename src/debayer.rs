@@ -6,6 +6,7 @@
   clippy::cast_possible_wrap,
   clippy::cast_ptr_alignment,
   clippy::identity_op,
+  clippy::manual_midpoint,
   clippy::cast_possible_truncation,
   clippy::cast_lossless,
   clippy::too_many_lines,
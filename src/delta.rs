@@ -0,0 +1,321 @@
+//! `delta` contains perceptual color difference metrics, commonly written as `ΔE`, that measure how different two
+//! colors appear to a human observer rather than how far apart their encoded values are.
+//!
+//! Every metric operates on [CIELAB](https://en.wikipedia.org/wiki/CIELAB_color_space) values as produced by
+//! [`convert::linear_to_lab`](crate::convert::linear_to_lab). A difference of roughly `1.0` is the smallest one most
+//! observers can notice, though the exact threshold varies between the metrics.
+//!
+//! # Example
+//! ```
+//! use cvr::delta::Metric;
+//!
+//! let reference = cvr::rgb::Image::from_vec(vec![200_u8, 10], vec![30, 10], vec![60, 10], 2, 1).unwrap();
+//! let rendered = cvr::rgb::Image::from_vec(vec![200_u8, 14], vec![30, 10], vec![60, 10], 2, 1).unwrap();
+//!
+//! let map = cvr::delta::map(&reference, &rendered, Metric::Ciede2000);
+//! assert_eq!(map.v()[0], 0.0);
+//! assert!(map.v()[1] > 0.0);
+//!
+//! let stats = cvr::delta::Stats::from_map(&map);
+//! assert_eq!(stats.max, map.v()[1]);
+//! ```
+//!
+
+use crate::convert::iter::{LinearLabIterator, SRGBLinearIterator};
+use crate::{gray, rgb};
+
+/// `Metric` selects the color difference formula used by the whole-image routines.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Metric {
+  /// `Cie76` is the Euclidean distance in `L*a*b*`, see [`cie76`].
+  ///
+  Cie76,
+
+  /// `Cie94` weights the lightness, chroma and hue differences for the graphic arts, see [`cie94`].
+  ///
+  Cie94,
+
+  /// `Ciede2000` is the most perceptually uniform of the metrics, see [`ciede2000`].
+  ///
+  #[default]
+  Ciede2000,
+}
+
+impl Metric {
+  /// `distance` computes the difference between the `reference` and `sample` `L*a*b*` values using the selected
+  /// formula.
+  ///
+  #[must_use]
+  pub fn distance(self, reference: [f32; 3], sample: [f32; 3]) -> f32 {
+    match self {
+      Metric::Cie76 => cie76(reference, sample),
+      Metric::Cie94 => cie94(reference, sample),
+      Metric::Ciede2000 => ciede2000(reference, sample),
+    }
+  }
+}
+
+/// `cie76` computes the [CIE76](https://en.wikipedia.org/wiki/Color_difference#CIE76) color difference, which is the
+/// Euclidean distance between the two `L*a*b*` values.
+///
+/// CIE76 is cheap to compute but overstates differences between saturated colors.
+///
+#[must_use]
+pub fn cie76([l1, a1, b1]: [f32; 3], [l2, a2, b2]: [f32; 3]) -> f32 {
+  let (dl, da, db) = (l1 - l2, a1 - a2, b1 - b2);
+  (dl * dl + da * da + db * db).sqrt()
+}
+
+/// `cie94` computes the [CIE94](https://en.wikipedia.org/wiki/Color_difference#CIE94) color difference using the
+/// graphic arts weighting constants.
+///
+/// CIE94 is not symmetric, the chroma of `reference` determines the weighting of the chroma and hue differences.
+///
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn cie94(reference: [f32; 3], sample: [f32; 3]) -> f32 {
+  const K1: f64 = 0.045;
+  const K2: f64 = 0.015;
+
+  let [l1, a1, b1] = reference.map(f64::from);
+  let [l2, a2, b2] = sample.map(f64::from);
+
+  let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+  let (dl, dc) = (l1 - l2, c1 - c2);
+  let (da, db) = (a1 - a2, b1 - b2);
+
+  // rounding error can make the squared hue difference slightly negative for colors of identical hue
+  //
+  let dh2 = (da * da + db * db - dc * dc).max(0.0);
+
+  let sc = 1.0 + K1 * c1;
+  let sh = 1.0 + K2 * c1;
+
+  (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt() as f32
+}
+
+/// `ciede2000` computes the [CIEDE2000](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) color difference
+/// with the parametric weighting factors `kL`, `kC` and `kH` all set to `1.0`.
+///
+/// The implementation follows [Sharma et al.](https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/), including
+/// their treatment of achromatic colors and of hues on opposite sides of `0°`.
+///
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::similar_names)]
+pub fn ciede2000(reference: [f32; 3], sample: [f32; 3]) -> f32 {
+  let pow7 = |x: f64| x.powi(7);
+  let twenty_five_7 = pow7(25.0);
+
+  let [l1, a1, b1] = reference.map(f64::from);
+  let [l2, a2, b2] = sample.map(f64::from);
+
+  let c_bar = 0.5 * (a1.hypot(b1) + a2.hypot(b2));
+  let g = 0.5 * (1.0 - (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt());
+
+  let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+  let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+
+  let hue = |a: f64, b: f64| {
+    if a == 0.0 && b == 0.0 {
+      0.0
+    } else {
+      b.atan2(a).to_degrees().rem_euclid(360.0)
+    }
+  };
+  let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+  let chromatic = c1 * c2 != 0.0;
+
+  let dl = l2 - l1;
+  let dc = c2 - c1;
+  let dh = if !chromatic {
+    0.0
+  } else if (h2 - h1).abs() <= 180.0 {
+    h2 - h1
+  } else if h2 > h1 {
+    h2 - h1 - 360.0
+  } else {
+    h2 - h1 + 360.0
+  };
+  let dh = 2.0 * (c1 * c2).sqrt() * (0.5 * dh).to_radians().sin();
+
+  let l_bar = 0.5 * (l1 + l2);
+  let c_bar = 0.5 * (c1 + c2);
+  let h_bar = if !chromatic {
+    h1 + h2
+  } else if (h1 - h2).abs() <= 180.0 {
+    0.5 * (h1 + h2)
+  } else if h1 + h2 < 360.0 {
+    0.5 * (h1 + h2 + 360.0)
+  } else {
+    0.5 * (h1 + h2 - 360.0)
+  };
+
+  let cos = |degrees: f64| degrees.to_radians().cos();
+  let t = 1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+    - 0.20 * cos(4.0 * h_bar - 63.0);
+
+  let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+  let rc = 2.0 * (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt();
+  let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+  let l_offset = (l_bar - 50.0).powi(2);
+  let sl = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+  let sc = 1.0 + 0.045 * c_bar;
+  let sh = 1.0 + 0.015 * c_bar * t;
+
+  let (dl, dc, dh) = (dl / sl, dc / sc, dh / sh);
+
+  (dl * dl + dc * dc + dh * dh + rt * dc * dh).sqrt() as f32
+}
+
+/// `map` computes the per-pixel color difference between two `sRGB` encoded images, returning the result as a
+/// grayscale image of the same dimensions.
+///
+/// # Panics
+///
+/// Panics if `reference` and `sample` do not have the same width and height.
+///
+#[must_use]
+pub fn map(
+  reference: &rgb::Image<u8>,
+  sample: &rgb::Image<u8>,
+  metric: Metric,
+) -> gray::Image<f32> {
+  assert_same_dimensions(reference, sample);
+
+  let mut out = gray::Image::new();
+  out.resize(reference.width(), reference.height());
+
+  reference
+    .rgb_iter()
+    .srgb_to_linear()
+    .linear_to_lab()
+    .zip(sample.rgb_iter().srgb_to_linear().linear_to_lab())
+    .zip(out.iter_mut())
+    .for_each(|((x, y), d)| *d = metric.distance(x, y));
+
+  out
+}
+
+/// `map_lab` computes the per-pixel color difference between two images that have already been converted to
+/// `L*a*b*`, such as by [`rgb::cvt_linear_to_lab`], returning the result as a grayscale image of the same dimensions.
+///
+/// # Panics
+///
+/// Panics if `reference` and `sample` do not have the same width and height.
+///
+#[must_use]
+pub fn map_lab(
+  reference: &rgb::Image<f32>,
+  sample: &rgb::Image<f32>,
+  metric: Metric,
+) -> gray::Image<f32> {
+  assert_same_dimensions(reference, sample);
+
+  let mut out = gray::Image::new();
+  out.resize(reference.width(), reference.height());
+
+  reference
+    .rgb_iter()
+    .zip(sample.rgb_iter())
+    .zip(out.iter_mut())
+    .for_each(|((x, y), d)| *d = metric.distance(x, y));
+
+  out
+}
+
+/// `Stats` summarizes a per-pixel color difference map.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+  /// The mean difference across every pixel.
+  pub mean: f32,
+  /// The largest difference of any pixel.
+  pub max: f32,
+  /// The 50th percentile of the differences.
+  pub median: f32,
+  /// The 95th percentile of the differences.
+  pub p95: f32,
+  /// The 99th percentile of the differences.
+  pub p99: f32,
+}
+
+impl Stats {
+  /// `from_map` computes the summary statistics of the supplied difference map. An empty map produces all zeroes.
+  ///
+  #[must_use]
+  #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+  pub fn from_map(map: &gray::Image<f32>) -> Self {
+    let sorted = sorted_values(map);
+    if sorted.is_empty() {
+      return Self::default();
+    }
+
+    let sum: f64 = sorted.iter().copied().map(f64::from).sum();
+
+    Stats {
+      mean: (sum / sorted.len() as f64) as f32,
+      max: sorted[sorted.len() - 1],
+      median: nearest_rank(&sorted, 50.0),
+      p95: nearest_rank(&sorted, 95.0),
+      p99: nearest_rank(&sorted, 99.0),
+    }
+  }
+}
+
+/// `percentile` returns the `p`-th percentile of the supplied difference map using the nearest-rank method, such that
+/// at least `p` percent of the pixels have a difference no larger than the returned value. An empty map returns
+/// `0.0`.
+///
+/// # Panics
+///
+/// Panics if `p` is not within the range `[0.0, 100.0]`.
+///
+#[must_use]
+pub fn percentile(map: &gray::Image<f32>, p: f32) -> f32 {
+  assert!(
+    (0.0..=100.0).contains(&p),
+    "percentile must be within [0, 100]"
+  );
+
+  let sorted = sorted_values(map);
+  if sorted.is_empty() {
+    return 0.0;
+  }
+
+  nearest_rank(&sorted, p)
+}
+
+/// `sorted_values` copies the pixels of the map into ascending order.
+///
+fn sorted_values(map: &gray::Image<f32>) -> Vec<f32> {
+  let mut values = map.v().to_vec();
+  values.sort_unstable_by(f32::total_cmp);
+  values
+}
+
+/// `nearest_rank` returns the `p`-th percentile of the non-empty, ascending `sorted` values.
+///
+#[allow(
+  clippy::cast_precision_loss,
+  clippy::cast_possible_truncation,
+  clippy::cast_sign_loss
+)]
+fn nearest_rank(sorted: &[f32], p: f32) -> f32 {
+  let rank = (f64::from(p) / 100.0 * sorted.len() as f64).ceil() as usize;
+  sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// `assert_same_dimensions` panics if the two images differ in size.
+///
+fn assert_same_dimensions<T>(reference: &rgb::Image<T>, sample: &rgb::Image<T>)
+where
+  T: crate::Numeric,
+{
+  assert!(
+    reference.width() == sample.width() && reference.height() == sample.height(),
+    "image dimensions must match"
+  );
+}
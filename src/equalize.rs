@@ -0,0 +1,336 @@
+//! `equalize` stretches the contrast of 8-bit images by
+//! [equalizing their histograms](https://en.wikipedia.org/wiki/Histogram_equalization), in the spirit of `OpenCV`'s
+//! `equalizeHist` and `createCLAHE`.
+//!
+//! [`global`] remaps every pixel through the cumulative histogram of the entire image, which spreads the values that
+//! occur most often over the full range. [`clahe`] implements contrast-limited adaptive histogram equalization, which
+//! equalizes each tile of a grid separately, limits how much any single value can be stretched and interpolates
+//! between neighbouring tiles so that no seams appear at their borders.
+//!
+//! Equalizing the channels of an `RGB` image separately shifts its colors, so [`global_rgb`] and [`clahe_rgb`] only
+//! equalize the luminance of every pixel and scale its linear channels accordingly, which preserves hue.
+//!
+//! # Example
+//! ```
+//! let img = cvr::gray::Image::from_vec(vec![100_u8, 100, 101, 102, 110, 110], 3, 2).unwrap();
+//!
+//! let mut out = cvr::gray::Image::new();
+//! cvr::equalize::global(&img, &mut out);
+//!
+//! assert_eq!(out.v(), [0, 0, 64, 128, 255, 255]);
+//! ```
+//!
+
+use crate::{gray, rgb};
+
+/// `Clahe` holds the parameters of contrast-limited adaptive histogram equalization.
+///
+/// The [`Default`] matches that of `OpenCV`, an `8x8` grid of tiles with a clip limit of `40.0`.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clahe {
+  /// The number of tiles the image is divided into horizontally.
+  pub columns: usize,
+  /// The number of tiles the image is divided into vertically.
+  pub rows: usize,
+  /// The maximum count of any bin of a tile's histogram, relative to the average count of its 256 bins. Counts above
+  /// the limit are redistributed evenly over all bins. A limit of `0.0` or less disables clipping.
+  pub clip_limit: f32,
+}
+
+impl Clahe {
+  /// `new` constructs the parameters for a grid of `columns` by `rows` tiles with the supplied clip limit.
+  ///
+  #[must_use]
+  pub fn new(columns: usize, rows: usize, clip_limit: f32) -> Self {
+    Clahe {
+      columns,
+      rows,
+      clip_limit,
+    }
+  }
+}
+
+impl Default for Clahe {
+  fn default() -> Self {
+    Clahe::new(8, 8, 40.0)
+  }
+}
+
+/// `global` equalizes the histogram of the entire image `x`, storing the result in `y`.
+///
+/// The smallest value present in the image maps to `0` and the largest to `255`. Images that consist of a single value
+/// are copied unchanged.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+#[allow(clippy::cast_precision_loss)]
+pub fn global(x: &gray::Image<u8>, y: &mut gray::Image<u8>) {
+  let cumulative = crate::histogram::gray(x, None).cumulative();
+
+  let total = cumulative[255];
+  let min = cumulative
+    .iter()
+    .copied()
+    .find(|count| *count > 0)
+    .unwrap_or(0);
+
+  let mut lut = [0_u8; 256];
+  if total > min {
+    let scale = 255.0 / (total - min) as f64;
+    lut
+      .iter_mut()
+      .zip(cumulative.iter())
+      .for_each(|(out, count)| *out = to_u8(count.saturating_sub(min) as f64 * scale));
+  } else {
+    lut.iter_mut().zip(0..=255).for_each(|(out, v)| *out = v);
+  }
+
+  y.resize(x.width(), x.height());
+  y.iter_mut()
+    .zip(x.iter())
+    .for_each(|(out, v)| *out = lut[usize::from(*v)]);
+}
+
+/// `clahe` applies contrast-limited adaptive histogram equalization to the image `x`, storing the result in `y`.
+///
+/// The image is divided into a grid of equally sized tiles, each of which is equalized using its own clipped
+/// histogram. Every pixel is then mapped through the equalizations of the four tiles whose centers surround it, which
+/// are bilinearly interpolated. Grids with more tiles than the image has pixels along a dimension are reduced to one
+/// tile per pixel.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+/// # Panics
+///
+/// Panics if the grid has zero columns or rows.
+///
+/// # Example
+/// ```
+/// use cvr::equalize::Clahe;
+///
+/// // a faint gradient whose values only span `[96, 159]`
+/// //
+/// let v = (0..64 * 64).map(|i| 96 + (i % 64) as u8).collect();
+/// let img = cvr::gray::Image::from_vec(v, 64, 64).unwrap();
+///
+/// let mut out = cvr::gray::Image::new();
+/// cvr::equalize::clahe(&img, &mut out, Clahe::default());
+///
+/// let (min, max) = (out.iter().min().unwrap(), out.iter().max().unwrap());
+/// assert!(max - min > 200);
+/// ```
+///
+#[allow(clippy::cast_precision_loss)]
+pub fn clahe(x: &gray::Image<u8>, y: &mut gray::Image<u8>, params: Clahe) {
+  assert!(
+    params.columns > 0 && params.rows > 0,
+    "the grid must contain at least one tile"
+  );
+
+  let (width, height) = (x.width(), x.height());
+  y.resize(width, height);
+
+  if width == 0 || height == 0 {
+    return;
+  }
+
+  let (columns, rows) = (params.columns.min(width), params.rows.min(height));
+
+  let mut luts = Vec::with_capacity(columns * rows);
+  for row in 0..rows {
+    let (top, bottom) = (row * height / rows, (row + 1) * height / rows);
+
+    for column in 0..columns {
+      let (left, right) = (column * width / columns, (column + 1) * width / columns);
+
+      let mut counts = [0_u64; 256];
+      x.rows()
+        .skip(top)
+        .take(bottom - top)
+        .flat_map(|pixels| pixels[left..right].iter())
+        .for_each(|v| counts[usize::from(*v)] += 1);
+
+      let area = ((right - left) * (bottom - top)) as u64;
+      if params.clip_limit > 0.0 {
+        clip(&mut counts, limit(params.clip_limit, area));
+      }
+
+      let scale = 255.0 / area as f64;
+      let mut lut = [0_f32; 256];
+      let mut sum = 0;
+      lut.iter_mut().zip(counts.iter()).for_each(|(out, count)| {
+        sum += count;
+        *out = f32::from(to_u8(sum as f64 * scale));
+      });
+
+      luts.push(lut);
+    }
+  }
+
+  let horizontal = neighbours(width, columns);
+  let vertical = neighbours(height, rows);
+
+  y.rows_mut()
+    .zip(x.rows())
+    .zip(vertical.iter())
+    .for_each(|((out, pixels), (top, bottom, ty))| {
+      out
+        .iter_mut()
+        .zip(pixels.iter())
+        .zip(horizontal.iter())
+        .for_each(|((out, v), (left, right, tx))| {
+          let v = usize::from(*v);
+          let lookup = |row: usize, column: usize| luts[row * columns + column][v];
+
+          let upper = lookup(*top, *left) * (1.0 - tx) + lookup(*top, *right) * tx;
+          let lower = lookup(*bottom, *left) * (1.0 - tx) + lookup(*bottom, *right) * tx;
+
+          *out = to_u8(f64::from(upper * (1.0 - ty) + lower * ty));
+        });
+    });
+}
+
+/// `global_rgb` equalizes the histogram of the luminance of the 8-bit `sRGB` image `x`, storing the result in `y`.
+///
+/// See [`global`] for details, and the module documentation for how the luminance is equalized.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn global_rgb(x: &rgb::Image<u8>, y: &mut rgb::Image<u8>) {
+  equalize_luminance(x, y, global);
+}
+
+/// `clahe_rgb` applies contrast-limited adaptive histogram equalization to the luminance of the 8-bit `sRGB` image
+/// `x`, storing the result in `y`.
+///
+/// See [`clahe`] for details, and the module documentation for how the luminance is equalized.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+/// # Panics
+///
+/// Panics if the grid has zero columns or rows.
+///
+pub fn clahe_rgb(x: &rgb::Image<u8>, y: &mut rgb::Image<u8>, params: Clahe) {
+  equalize_luminance(x, y, |luminance, out| clahe(luminance, out, params));
+}
+
+/// `equalize_luminance` equalizes the `sRGB`-encoded luminance of every pixel using `f`, then scales the pixel's
+/// linear channels by the ratio of its new luminance to its old one.
+///
+/// The scale is limited so that no channel exceeds `1.0`, as clipping the channels individually would shift the hue.
+/// Pixels whose luminance isn't changed are copied unchanged, and black pixels become gray.
+///
+fn equalize_luminance<F>(x: &rgb::Image<u8>, y: &mut rgb::Image<u8>, f: F)
+where
+  F: FnOnce(&gray::Image<u8>, &mut gray::Image<u8>),
+{
+  use crate::convert::{linear_to_gray, linear_to_srgb, srgb_to_linear};
+
+  let mut linear = rgb::Image::new();
+  x.to_linear(&mut linear);
+
+  let mut luminance = gray::Image::new();
+  luminance.resize(x.width(), x.height());
+  luminance
+    .iter_mut()
+    .zip(linear.rgb_iter())
+    .for_each(|(out, pixel)| *out = linear_to_srgb(linear_to_gray(pixel)));
+
+  let mut equalized = gray::Image::new();
+  f(&luminance, &mut equalized);
+
+  y.resize(x.width(), x.height());
+  y.rgb_iter_mut()
+    .zip(x.rgb_iter().zip(linear.rgb_iter()))
+    .zip(luminance.iter().zip(equalized.iter()))
+    .for_each(|(([r, g, b], (pixel, linear)), (before, after))| {
+      let out = if before == after {
+        pixel
+      } else {
+        let target = srgb_to_linear(*after);
+        let current = linear_to_gray(linear);
+
+        if current > 0.0 {
+          let max = linear[0].max(linear[1]).max(linear[2]);
+          let scale = (target / current).min(1.0 / max);
+          linear.map(|c| linear_to_srgb(c * scale))
+        } else {
+          [*after; 3]
+        }
+      };
+
+      *r = out[0];
+      *g = out[1];
+      *b = out[2];
+    });
+}
+
+/// `limit` converts the relative clip limit into the maximum count of a bin of a tile with `area` pixels, which is at
+/// least `1`.
+///
+#[allow(
+  clippy::cast_precision_loss,
+  clippy::cast_possible_truncation,
+  clippy::cast_sign_loss
+)]
+fn limit(clip_limit: f32, area: u64) -> u64 {
+  ((f64::from(clip_limit) * area as f64 / 256.0) as u64).max(1)
+}
+
+/// `clip` limits every bin to `limit` and redistributes the excess evenly over all bins, handing out the remainder
+/// that doesn't divide evenly to bins spread across the range.
+///
+#[allow(clippy::cast_possible_truncation)]
+fn clip(counts: &mut [u64; 256], limit: u64) {
+  let excess: u64 = counts
+    .iter_mut()
+    .map(|count| {
+      let excess = count.saturating_sub(limit);
+      *count -= excess;
+      excess
+    })
+    .sum();
+
+  let (batch, residual) = (excess / 256, (excess % 256) as usize);
+  for count in counts.iter_mut() {
+    *count += batch;
+  }
+
+  if let Some(step) = 256_usize.checked_div(residual) {
+    counts
+      .iter_mut()
+      .step_by(step)
+      .take(residual)
+      .for_each(|count| *count += 1);
+  }
+}
+
+/// `neighbours` returns, for every pixel along a dimension of length `len` divided into `n` tiles, the indices of the
+/// tiles whose centers lie on either side of it and the interpolation weight of the second one.
+///
+#[allow(
+  clippy::cast_precision_loss,
+  clippy::cast_possible_truncation,
+  clippy::cast_sign_loss
+)]
+fn neighbours(len: usize, n: usize) -> Vec<(usize, usize, f32)> {
+  let size = len as f32 / n as f32;
+
+  (0..len)
+    .map(|p| {
+      let t = ((p as f32 + 0.5) / size - 0.5).max(0.0);
+      let first = (t as usize).min(n - 1);
+      let second = (first + 1).min(n - 1);
+
+      (first, second, (t - first as f32).min(1.0))
+    })
+    .collect()
+}
+
+/// `to_u8` rounds the value to the nearest 8-bit value.
+///
+fn to_u8(x: f64) -> u8 {
+  crate::Numeric::saturating_from_f64(x)
+}
@@ -7,7 +7,7 @@ use crate::Numeric;
 
 /// `Image` represents any grayscale image.
 ///
-#[derive(Default)]
+#[derive(Default, Clone, PartialEq, Eq)]
 pub struct Image<T>
 where
   T: Numeric,
@@ -28,6 +28,41 @@ where
     <Self as Default>::default()
   }
 
+  /// `from_plane` constructs an `Image` by taking ownership of the supplied data, which must be laid out in a
+  /// row-major ordering.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if the plane does not contain exactly `width * height` number of elements
+  /// and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_plane(
+    v: minivec::MiniVec<T>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = crate::plane_len(width, height)?;
+    crate::check_plane(&v, size)?;
+
+    Ok(Self {
+      v,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Image` from the supplied data, which must be laid out in a row-major ordering. The
+  /// elements of the `Vec` are moved into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if the plane does not contain exactly `width * height` number of elements
+  /// and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(v: Vec<T>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    Self::from_plane(v.into_iter().collect(), width, height)
+  }
+
   /// `v` returns an immutable reference to the image's color data
   ///
   #[must_use]
@@ -35,6 +70,12 @@ where
     self.v.as_slice()
   }
 
+  /// `v_mut` returns a mutable reference to the image's color data
+  ///
+  pub fn v_mut(&mut self) -> &mut [T] {
+    self.v.as_mut_slice()
+  }
+
   /// `width` returns the number of columns in the image.
   ///
   #[must_use]
@@ -59,10 +100,839 @@ where
   /// `iter` returns a iterator to the underlying slice.
   ///
   #[must_use]
+  #[allow(clippy::double_must_use)]
   pub fn iter(&self) -> std::slice::Iter<'_, T>
   where
     T: Numeric,
   {
     self.v.iter()
   }
+
+  /// `iter_mut` returns a mutable iterator to the underlying slice.
+  ///
+  pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+    self.v.iter_mut()
+  }
+
+  /// `resize` readjusts the internal image buffer until its size is _at least_ `width * height` number of elements
+  /// and resets the internal `width` and `height` data members.
+  ///
+  /// Does not allocate if the buffer is already large enough.
+  ///
+  /// `Default`-initializes new elements and does not attempt to preserve the quality of the underlying image. This
+  /// operation, while safe, should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.v.resize(width * height, Default::default());
+
+    self.h = height;
+    self.w = width;
+  }
+
+  /// `view` returns an [`ImageRef`] that borrows the image's data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      v: &self.v,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `view_mut` returns an [`ImageMut`] that mutably borrows the image's data.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      v: &mut self.v,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `rows` returns an iterator over the rows of the image, yielding each one as a `&[T]`.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = &'_ [T]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the image, yielding each one as a `&mut [T]` so that the
+  /// underlying pixel values can be manipulated.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = &'_ mut [T]> + '_ {
+    crate::strided::RowsMut::new(&mut self.v, self.w, self.h, self.w)
+  }
+
+  /// `split_rows_mut` divides the image into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
+  }
+
+  /// `convert_to` converts the image into a different sample type, computing `saturate(x * scale + offset)` for every
+  /// value and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn convert_to<U>(&self, out: &mut Image<U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().convert_to(&mut out.view_mut(), scale, offset);
+  }
+
+  /// `decode` linearizes the image with the supplied transfer `curve`, normalizing every value by `T::SCALE` first
+  /// and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut Image<f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    out.resize(self.w, self.h);
+    self.view().decode(curve, &mut out.view_mut());
+  }
+
+  /// `get` returns the value at column `x` and row `y`, or `None` if the coordinate lies outside of the
+  /// image.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<T> {
+    self.view().get(x, y)
+  }
+
+  /// `get_mut` returns a mutable reference to the value at column `x` and row `y`, or `None` if the coordinate
+  /// lies outside of the image.
+  ///
+  pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+    if x < self.w && y < self.h {
+      Some(&mut self.v[y * self.w + x])
+    } else {
+      None
+    }
+  }
+
+  /// `get_unchecked` returns the value at column `x` and row `y` without performing any bounds checking.
+  ///
+  /// # Safety
+  ///
+  /// `x` must be less than `self.width()` and `y` must be less than `self.height()`.
+  ///
+  #[must_use]
+  pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> T {
+    *self.v.get_unchecked(y * self.w + x)
+  }
+
+  /// `enumerate_pixels` returns an iterator that traverses the image in a row-major ordering, yielding each value
+  /// along with its coordinate as `(x, y, value)`.
+  ///
+  pub fn enumerate_pixels(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+    let w = self.w;
+    self
+      .v
+      .iter()
+      .copied()
+      .enumerate()
+      .map(move |(idx, value)| (idx % w, idx / w, value))
+  }
+
+  /// `sample` reads the image at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> T {
+    self.view().sample(x, y, interpolation, border)
+  }
+}
+
+impl Image<u8> {
+  /// `to_linear` will take the input 8-bit `sRGB`-encoded image and convert it to its linear floating point
+  /// representation.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Image<f32>) {
+    out.resize(self.w, self.h);
+    self.view().to_linear(&mut out.view_mut());
+  }
+}
+
+impl Image<f32> {
+  /// `to_srgb` will take the input linear floating point image and convert it to its 8-bit `sRGB`-encoded
+  /// representation.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_srgb(&self, out: &mut Image<u8>) {
+    out.resize(self.w, self.h);
+    self.view().to_srgb(&mut out.view_mut());
+  }
+
+  /// `encode` applies the supplied transfer `curve` to the linear image, scaling every encoded value by `U::SCALE`
+  /// and storing the saturated result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut Image<U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().encode(curve, &mut out.view_mut());
+  }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Image<T>
+where
+  T: Numeric,
+{
+  type Output = T;
+
+  /// Returns a reference to the value at the `(x, y)` coordinate.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the coordinate lies outside of the image.
+  ///
+  fn index(&self, (x, y): (usize, usize)) -> &T {
+    assert!(x < self.w && y < self.h, "coordinate out of bounds");
+    &self.v[y * self.w + x]
+  }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Image<T>
+where
+  T: Numeric,
+{
+  /// Returns a mutable reference to the value at the `(x, y)` coordinate.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the coordinate lies outside of the image.
+  ///
+  fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+    assert!(x < self.w && y < self.h, "coordinate out of bounds");
+    &mut self.v[y * self.w + x]
+  }
+}
+
+impl<'a, T> IntoIterator for &'a Image<T>
+where
+  T: Numeric,
+{
+  type Item = &'a T;
+  type IntoIter = std::slice::Iter<'a, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, T> IntoIterator for &'a mut Image<T>
+where
+  T: Numeric,
+{
+  type Item = &'a mut T;
+  type IntoIter = std::slice::IterMut<'a, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<'a, T> std::convert::From<&'a Image<T>> for ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a Image<T>) -> Self {
+    img.view()
+  }
+}
+
+impl<'a, T> std::convert::From<&'a mut Image<T>> for ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a mut Image<T>) -> Self {
+    img.view_mut()
+  }
+}
+
+/// `ImageRef` is a borrowed, read-only view of grayscale image data that is owned elsewhere, such as by a driver's
+/// ring buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+#[derive(Clone, Copy)]
+pub struct ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  v: &'a [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a view over the supplied row-major plane where each row begins `stride` elements after the start
+  /// of the previous one.
+  ///
+  /// The plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if the plane is
+  /// too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  pub fn new(v: &'a [T], width: usize, height: usize, stride: usize) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+    crate::strided::check_view_plane(v, len)?;
+
+    Ok(Self {
+      v: &v[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `v` returns the viewed color data as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn v(&self) -> &'a [T] {
+    self.v
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding.
+  ///
+  pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+    self.rows().flat_map(<[T]>::iter)
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `&[T]` that excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+    crate::strided::Rows::new(self.v, self.w, self.h, self.stride)
+  }
+
+  /// `roi` returns a view of the `width * height` rectangle whose top-left corner is at column `x` and row `y` of this
+  /// view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageRef {
+      v: &self.v[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `convert_to` converts the viewed data into a different sample type, computing `saturate(x * scale + offset)` for
+  /// every value and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn convert_to<U>(&self, out: &mut ImageMut<'_, U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    crate::strided::map_plane(
+      self.v,
+      self.stride,
+      out.v,
+      out.stride,
+      (self.w, self.h),
+      |x: T| crate::convert_sample(x, scale, offset),
+    );
+  }
+
+  /// `decode` linearizes the viewed data with the supplied transfer `curve`, normalizing every value by `T::SCALE`
+  /// first and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut ImageMut<'_, f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    crate::strided::map_plane(
+      self.v,
+      self.stride,
+      out.v,
+      out.stride,
+      (self.w, self.h),
+      |x: T| crate::convert::transfer::decode_sample(curve, x),
+    );
+  }
+}
+
+impl ImageRef<'_, u8> {
+  /// `to_linear` will take the viewed 8-bit `sRGB`-encoded data and convert it to its linear floating point
+  /// representation, storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_linear(&self, out: &mut ImageMut<'_, f32>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    crate::strided::map_rows(
+      self.v,
+      self.stride,
+      out.v,
+      out.stride,
+      (self.w, self.h),
+      crate::convert::kernels::srgb_to_linear,
+    );
+  }
+}
+
+impl ImageRef<'_, f32> {
+  /// `to_srgb` will take the viewed linear floating point data and convert it to its 8-bit `sRGB`-encoded
+  /// representation, storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_srgb(&self, out: &mut ImageMut<'_, u8>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    crate::strided::map_rows(
+      self.v,
+      self.stride,
+      out.v,
+      out.stride,
+      (self.w, self.h),
+      crate::convert::kernels::linear_to_srgb,
+    );
+  }
+
+  /// `encode` applies the supplied transfer `curve` to the viewed linear data, scaling every encoded value by
+  /// `U::SCALE` and storing the saturated result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut ImageMut<'_, U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    crate::strided::map_plane(
+      self.v,
+      self.stride,
+      out.v,
+      out.stride,
+      (self.w, self.h),
+      |x: f32| crate::convert::transfer::encode_sample(curve, x),
+    );
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `get` returns the value at column `x` and row `y` of the view, or `None` if the coordinate lies outside
+  /// of the view.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<T> {
+    if x >= self.w || y >= self.h {
+      return None;
+    }
+
+    let idx = y * self.stride + x;
+    Some(self.v[idx])
+  }
+
+  /// `sample` reads the view at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> T {
+    let dims = (self.w, self.h, self.stride);
+    crate::sample::sample_plane(self.v, dims, (x, y), interpolation, border)
+  }
+}
+
+/// `ImageMut` is a borrowed, mutable view of grayscale image data that is owned elsewhere, such as by a driver's ring
+/// buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+pub struct ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  v: &'a mut [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a mutable view over the supplied row-major plane where each row begins `stride` elements after
+  /// the start of the previous one.
+  ///
+  /// The plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if the plane is
+  /// too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  pub fn new(
+    v: &'a mut [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+  ) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+    crate::strided::check_view_plane(v, len)?;
+
+    Ok(Self {
+      v: &mut v[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `view` returns a read-only [`ImageRef`] of the same data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      v: self.v,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `view_mut` reborrows the view, returning an `ImageMut` with a shorter lifetime.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      v: self.v,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `v` returns the viewed color data as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn v(&self) -> &[T] {
+    self.v
+  }
+
+  /// `v_mut` returns the viewed color data as a `&mut [T]`, including any padding elements between rows.
+  ///
+  pub fn v_mut(&mut self) -> &mut [T] {
+    self.v
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding.
+  ///
+  pub fn iter(&self) -> impl Iterator<Item = &'_ T> + '_ {
+    self.view().iter()
+  }
+
+  /// `iter_mut` returns an iterator that traverses the view in a row-major ordering, skipping any padding and yielding
+  /// mutable references to each value.
+  ///
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = &'_ mut T> + '_ {
+    self.rows_mut().flat_map(<[T]>::iter_mut)
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `&[T]` that excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = &'_ [T]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the view, yielding each one as a `&mut [T]` that excludes any
+  /// padding.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = &'_ mut [T]> + '_ {
+    crate::strided::RowsMut::new(self.v, self.w, self.h, self.stride)
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `into_roi` consumes the view, narrowing it to the `width * height` rectangle whose top-left corner is at column
+  /// `x` and row `y`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn into_roi(
+    self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageMut {
+      v: &mut self.v[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+
+  /// `into_split_rows` consumes the view, dividing it into `n` disjoint horizontal bands of consecutive rows. Band
+  /// heights differ by at most one, with any remainder going to the leading bands.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  #[must_use]
+  pub fn into_split_rows(self, n: usize) -> Vec<ImageMut<'a, T>> {
+    let heights = crate::strided::band_heights(self.h, n);
+    let (w, stride) = (self.w, self.stride);
+
+    let v = crate::strided::split_plane_mut(self.v, w, stride, &heights);
+
+    v.into_iter()
+      .zip(heights)
+      .map(|(v, h)| ImageMut { v, h, w, stride })
+      .collect()
+  }
+
+  /// `split_rows_mut` divides the view into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
+  }
+}
+
+/// `cvt_gray_to_rgb` replicates the supplied grayscale image across all three channels of `y`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_gray_to_rgb<T>(x: &Image<T>, y: &mut crate::rgb::Image<T>)
+where
+  T: Numeric,
+{
+  y.resize(x.width(), x.height());
+
+  let (red, green, blue) = y.rgb_mut();
+  red.copy_from_slice(x.v());
+  green.copy_from_slice(x.v());
+  blue.copy_from_slice(x.v());
 }
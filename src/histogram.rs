@@ -0,0 +1,388 @@
+//! `histogram` counts the distribution of pixel values within images, in the spirit of `OpenCV`'s `calcHist`.
+//!
+//! 8-bit images are counted into 256 bins, one for every value, while floating point images are counted into any
+//! number of equally sized [`Bins`] spanning an explicit range. [`JointHistogram`] counts combinations of channels,
+//! such as the hue and saturation of an `HSV` image. Every routine accepts an optional mask, in which case only the
+//! pixels whose mask value is non-zero are counted.
+//!
+//! # Example
+//! ```
+//! use cvr::histogram::Bins;
+//!
+//! let img = cvr::rgb::Image::from_vec(
+//!   vec![1.0_f32, 0.0, 0.9, 0.2],
+//!   vec![0.0_f32, 1.0, 0.1, 0.2],
+//!   vec![0.0_f32, 0.0, 0.1, 0.2],
+//!   2,
+//!   2,
+//! )
+//! .unwrap();
+//!
+//! let mut hsv = cvr::rgb::Image::new();
+//! cvr::rgb::cvt_linear_to_hsv(&img, &mut hsv);
+//!
+//! // 6 hue bins of 60 degrees each by 2 saturation bins
+//! //
+//! let hist = cvr::histogram::joint(&hsv, [0, 1], [Bins::new(6, 0.0, 360.0), Bins::new(2, 0.0, 1.0)], None);
+//!
+//! assert_eq!(hist.get([0, 1]), Some(2));
+//! assert_eq!(hist.get([2, 1]), Some(1));
+//! assert_eq!(hist.get([0, 0]), Some(1));
+//! assert_eq!(hist.total(), 4);
+//! ```
+//!
+
+use crate::{gray, rgb};
+
+/// `Bins` divides the inclusive range `[min, max]` into `count` equally sized bins, the last of which also contains
+/// `max` itself.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bins {
+  count: usize,
+  min: f32,
+  max: f32,
+}
+
+impl Bins {
+  /// `U8` places every 8-bit value into a bin of its own.
+  ///
+  pub const U8: Bins = Bins {
+    count: 256,
+    min: 0.0,
+    max: 256.0,
+  };
+
+  /// `new` divides the range `[min, max]` into `count` equally sized bins.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `count` is zero or if `min` and `max` are not finite with `min < max`.
+  ///
+  #[must_use]
+  pub fn new(count: usize, min: f32, max: f32) -> Self {
+    assert!(count > 0, "bin count must be non-zero");
+    assert!(
+      min.is_finite() && max.is_finite() && min < max,
+      "bin range must be finite and non-empty"
+    );
+
+    Bins { count, min, max }
+  }
+
+  /// `count` returns the number of bins.
+  ///
+  #[must_use]
+  pub fn count(self) -> usize {
+    self.count
+  }
+
+  /// `range` returns the `[min, max]` range covered by the bins.
+  ///
+  #[must_use]
+  pub fn range(self) -> [f32; 2] {
+    [self.min, self.max]
+  }
+
+  /// `index` returns the bin that `x` falls into, or `None` if it lies outside of the range or is `NaN`.
+  ///
+  #[must_use]
+  #[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+  )]
+  pub fn index(self, x: f32) -> Option<usize> {
+    if !(self.min..=self.max).contains(&x) {
+      return None;
+    }
+
+    let (min, max) = (f64::from(self.min), f64::from(self.max));
+    let bin = (f64::from(x) - min) / (max - min) * self.count as f64;
+
+    Some((bin as usize).min(self.count - 1))
+  }
+}
+
+/// `Histogram` counts the number of samples that fall into each of its bins.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+  bins: Bins,
+  counts: Vec<u64>,
+}
+
+impl Histogram {
+  /// `new` returns an empty histogram with the supplied bins.
+  ///
+  #[must_use]
+  pub fn new(bins: Bins) -> Self {
+    Histogram {
+      bins,
+      counts: vec![0; bins.count],
+    }
+  }
+
+  /// `add` counts the sample if it lies within the range of the bins.
+  ///
+  pub fn add(&mut self, x: f32) {
+    if let Some(bin) = self.bins.index(x) {
+      self.counts[bin] += 1;
+    }
+  }
+
+  /// `bins` returns the bins the samples are counted into.
+  ///
+  #[must_use]
+  pub fn bins(&self) -> Bins {
+    self.bins
+  }
+
+  /// `counts` returns the number of samples in each bin.
+  ///
+  #[must_use]
+  pub fn counts(&self) -> &[u64] {
+    &self.counts
+  }
+
+  /// `total` returns the number of samples counted across all bins.
+  ///
+  #[must_use]
+  pub fn total(&self) -> u64 {
+    self.counts.iter().sum()
+  }
+
+  /// `cumulative` returns the cumulative histogram, where each bin holds the number of samples in it and every bin
+  /// before it.
+  ///
+  #[must_use]
+  pub fn cumulative(&self) -> Vec<u64> {
+    self
+      .counts
+      .iter()
+      .scan(0, |sum, count| {
+        *sum += count;
+        Some(*sum)
+      })
+      .collect()
+  }
+}
+
+impl Extend<f32> for Histogram {
+  fn extend<I: IntoIterator<Item = f32>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|x| self.add(x));
+  }
+}
+
+/// `JointHistogram` counts the number of `N` channel samples that fall into each combination of the bins of their
+/// channels.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct JointHistogram<const N: usize> {
+  bins: [Bins; N],
+  counts: Vec<u64>,
+}
+
+impl<const N: usize> JointHistogram<N> {
+  /// `new` returns an empty histogram using the supplied bins for each channel.
+  ///
+  #[must_use]
+  pub fn new(bins: [Bins; N]) -> Self {
+    JointHistogram {
+      bins,
+      counts: vec![0; bins.iter().map(|bins| bins.count).product()],
+    }
+  }
+
+  /// `add` counts the sample if every one of its channels lies within the range of its bins.
+  ///
+  pub fn add(&mut self, x: [f32; N]) {
+    let offset = self
+      .bins
+      .iter()
+      .zip(x.iter())
+      .try_fold(0, |offset, (bins, x)| {
+        bins.index(*x).map(|bin| offset * bins.count + bin)
+      });
+
+    if let Some(offset) = offset {
+      self.counts[offset] += 1;
+    }
+  }
+
+  /// `bins` returns the bins of each channel.
+  ///
+  #[must_use]
+  pub fn bins(&self) -> [Bins; N] {
+    self.bins
+  }
+
+  /// `get` returns the number of samples counted into the supplied combination of bins, or `None` if any of the bin
+  /// indices are out of bounds.
+  ///
+  #[must_use]
+  pub fn get(&self, bin: [usize; N]) -> Option<u64> {
+    let offset = self
+      .bins
+      .iter()
+      .zip(bin.iter())
+      .try_fold(0, |offset, (bins, bin)| {
+        (*bin < bins.count).then(|| offset * bins.count + bin)
+      })?;
+
+    Some(self.counts[offset])
+  }
+
+  /// `counts` returns the number of samples in each combination of bins in row-major ordering, i.e. with the bins of
+  /// the last channel being adjacent.
+  ///
+  #[must_use]
+  pub fn counts(&self) -> &[u64] {
+    &self.counts
+  }
+
+  /// `total` returns the number of samples counted across all bins.
+  ///
+  #[must_use]
+  pub fn total(&self) -> u64 {
+    self.counts.iter().sum()
+  }
+}
+
+impl<const N: usize> Extend<[f32; N]> for JointHistogram<N> {
+  fn extend<I: IntoIterator<Item = [f32; N]>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|x| self.add(x));
+  }
+}
+
+/// `gray` computes the 256 bin histogram of the 8-bit image, counting only the pixels selected by the mask if one is
+/// supplied.
+///
+/// # Panics
+///
+/// Panics if the mask does not have the same width and height as the image.
+///
+#[must_use]
+pub fn gray(img: &gray::Image<u8>, mask: Option<&gray::Image<u8>>) -> Histogram {
+  check_mask(img.width(), img.height(), mask);
+  count_u8(img.v(), mask)
+}
+
+/// `rgb` computes the 256 bin histograms of the red, green and blue channels of the 8-bit image, counting only the
+/// pixels selected by the mask if one is supplied.
+///
+/// # Panics
+///
+/// Panics if the mask does not have the same width and height as the image.
+///
+#[must_use]
+pub fn rgb(img: &rgb::Image<u8>, mask: Option<&gray::Image<u8>>) -> [Histogram; 3] {
+  check_mask(img.width(), img.height(), mask);
+  [img.r(), img.g(), img.b()].map(|plane| count_u8(plane, mask))
+}
+
+/// `gray_f32` computes the histogram of the floating point image using the supplied bins, counting only the pixels
+/// selected by the mask if one is supplied. Values outside of the range of the bins are not counted.
+///
+/// # Panics
+///
+/// Panics if the mask does not have the same width and height as the image.
+///
+#[must_use]
+pub fn gray_f32(img: &gray::Image<f32>, bins: Bins, mask: Option<&gray::Image<u8>>) -> Histogram {
+  check_mask(img.width(), img.height(), mask);
+
+  let mut hist = Histogram::new(bins);
+  hist.extend(masked(img.v().iter().copied(), mask));
+  hist
+}
+
+/// `rgb_f32` computes the histograms of the red, green and blue channels of the floating point image using the
+/// supplied bins, counting only the pixels selected by the mask if one is supplied. Values outside of the range of the
+/// bins are not counted.
+///
+/// # Panics
+///
+/// Panics if the mask does not have the same width and height as the image.
+///
+#[must_use]
+pub fn rgb_f32(
+  img: &rgb::Image<f32>,
+  bins: Bins,
+  mask: Option<&gray::Image<u8>>,
+) -> [Histogram; 3] {
+  check_mask(img.width(), img.height(), mask);
+
+  [img.r(), img.g(), img.b()].map(|plane| {
+    let mut hist = Histogram::new(bins);
+    hist.extend(masked(plane.iter().copied(), mask));
+    hist
+  })
+}
+
+/// `joint` computes the joint histogram of the selected channels of the floating point image, where `channels` holds
+/// the indices of the channels in `RGB` ordering and `bins` the bins used for each of them. Only the pixels selected
+/// by the mask are counted if one is supplied, and pixels with any selected channel outside of the range of its bins
+/// are not counted.
+///
+/// Images that store other color spaces in their planes, such as those produced by [`rgb::cvt_linear_to_hsv`], can be
+/// used as well.
+///
+/// # Panics
+///
+/// Panics if any of the channel indices exceed `2` or if the mask does not have the same width and height as the
+/// image.
+///
+#[must_use]
+pub fn joint<const N: usize>(
+  img: &rgb::Image<f32>,
+  channels: [usize; N],
+  bins: [Bins; N],
+  mask: Option<&gray::Image<u8>>,
+) -> JointHistogram<N> {
+  assert!(
+    channels.iter().all(|c| *c < 3),
+    "channel indices must be within [0, 2]"
+  );
+  check_mask(img.width(), img.height(), mask);
+
+  let mut hist = JointHistogram::new(bins);
+  hist.extend(masked(
+    img.rgb_iter().map(|pixel| channels.map(|c| pixel[c])),
+    mask,
+  ));
+  hist
+}
+
+/// `count_u8` counts the 8-bit values of the plane directly into 256 bins.
+///
+fn count_u8(plane: &[u8], mask: Option<&gray::Image<u8>>) -> Histogram {
+  let mut hist = Histogram::new(Bins::U8);
+  for x in masked(plane.iter().copied(), mask) {
+    hist.counts[usize::from(x)] += 1;
+  }
+
+  hist
+}
+
+/// `masked` filters the per-pixel values down to those whose mask value is non-zero, passing all of them through if
+/// there's no mask.
+///
+fn masked<'a, T>(
+  values: impl Iterator<Item = T> + 'a,
+  mask: Option<&'a gray::Image<u8>>,
+) -> impl Iterator<Item = T> + 'a {
+  let mut mask = mask.map(|mask| mask.v().iter());
+  values.filter(move |_| mask.as_mut().and_then(Iterator::next) != Some(&0))
+}
+
+/// `check_mask` panics if the mask does not match the dimensions of the image.
+///
+fn check_mask(width: usize, height: usize, mask: Option<&gray::Image<u8>>) {
+  if let Some(mask) = mask {
+    assert!(
+      mask.width() == width && mask.height() == height,
+      "mask dimensions must match the image"
+    );
+  }
+}
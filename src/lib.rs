@@ -4,16 +4,226 @@
 
 #![warn(clippy::pedantic, missing_docs)]
 
+pub mod balance;
+pub mod composite;
 pub mod convert;
 pub mod debayer;
+pub mod delta;
+pub mod equalize;
 pub mod gray;
+pub mod histogram;
+pub mod packed;
+pub mod planar;
 pub mod png;
 pub mod rgb;
 pub mod rgba;
+pub mod sample;
+pub mod space;
+pub mod yuv;
 
-/// `Numeric` represents such types as `u8` and `f32`.
+mod strided;
+
+/// `Numeric` represents the sample types an image can be made of, such as `u8`, `u16` and `f32`.
+///
+/// Every `Numeric` type can be converted to and from `f64`, which enables generic routines like
+/// [`rgb::Image::convert_to`] to be written once for all sample types. A conversion that maps the full intensity of
+/// one sample type onto the full intensity of another uses a scale factor of `U::SCALE / T::SCALE`.
+///
+/// # Example
+/// ```
+/// use cvr::Numeric;
+///
+/// assert_eq!(u8::SCALE, 255.0);
+/// assert_eq!(u16::SCALE, 65535.0);
+/// assert_eq!(f32::SCALE, 1.0);
+///
+/// assert_eq!(u8::saturating_from_f64(300.0), 255);
+/// assert_eq!(u8::saturating_from_f64(-4.0), 0);
+/// assert_eq!(i16::saturating_from_f64(-12.5), -13);
+/// assert_eq!(u16::saturating_from_f64(f64::NAN), 0);
+/// assert_eq!(f32::saturating_from_f64(1e300), f32::MAX);
+/// ```
+///
+pub trait Numeric: Copy + std::default::Default + PartialOrd + Send + Sync {
+  /// The smallest value representable by the type.
+  const MIN: Self;
+
+  /// The largest value representable by the type.
+  const MAX: Self;
+
+  /// The value that represents full intensity: `MAX` for the integral types and `1.0` for the floating point ones.
+  const SCALE: f64;
+
+  /// `to_f64` losslessly widens the value to an `f64`.
+  ///
+  fn to_f64(self) -> f64;
+
+  /// `saturating_from_f64` converts the supplied `f64` to the nearest representable value. Integral types round to
+  /// the nearest integer and clamp to `[MIN, MAX]`, mapping `NaN` to `0`. `f32` clamps to `[MIN, MAX]` as well,
+  /// so that no finite value overflows to infinity, but keeps `NaN`. `f64` is returned unchanged.
+  ///
+  fn saturating_from_f64(x: f64) -> Self;
+}
+
+impl Numeric for u8 {
+  const MIN: Self = u8::MIN;
+  const MAX: Self = u8::MAX;
+  const SCALE: f64 = 255.0;
+
+  fn to_f64(self) -> f64 {
+    f64::from(self)
+  }
+
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  fn saturating_from_f64(x: f64) -> Self {
+    x.round() as Self
+  }
+}
+
+impl Numeric for u16 {
+  const MIN: Self = u16::MIN;
+  const MAX: Self = u16::MAX;
+  const SCALE: f64 = 65535.0;
+
+  fn to_f64(self) -> f64 {
+    f64::from(self)
+  }
+
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  fn saturating_from_f64(x: f64) -> Self {
+    x.round() as Self
+  }
+}
+
+impl Numeric for i16 {
+  const MIN: Self = i16::MIN;
+  const MAX: Self = i16::MAX;
+  const SCALE: f64 = 32767.0;
+
+  fn to_f64(self) -> f64 {
+    f64::from(self)
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  fn saturating_from_f64(x: f64) -> Self {
+    x.round() as Self
+  }
+}
+
+impl Numeric for i32 {
+  const MIN: Self = i32::MIN;
+  const MAX: Self = i32::MAX;
+  const SCALE: f64 = 2_147_483_647.0;
+
+  fn to_f64(self) -> f64 {
+    f64::from(self)
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  fn saturating_from_f64(x: f64) -> Self {
+    x.round() as Self
+  }
+}
+
+impl Numeric for f32 {
+  const MIN: Self = f32::MIN;
+  const MAX: Self = f32::MAX;
+  const SCALE: f64 = 1.0;
+
+  fn to_f64(self) -> f64 {
+    f64::from(self)
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  fn saturating_from_f64(x: f64) -> Self {
+    x.clamp(f64::from(f32::MIN), f64::from(f32::MAX)) as Self
+  }
+}
+
+impl Numeric for f64 {
+  const MIN: Self = f64::MIN;
+  const MAX: Self = f64::MAX;
+  const SCALE: f64 = 1.0;
+
+  fn to_f64(self) -> f64 {
+    self
+  }
+
+  fn saturating_from_f64(x: f64) -> Self {
+    x
+  }
+}
+
+/// `convert_sample` computes `saturate(x * scale + offset)`, the per-sample operation behind the `convert_to` family
+/// of functions.
 ///
-pub trait Numeric: Copy + std::default::Default {}
+pub(crate) fn convert_sample<T, U>(x: T, scale: f64, offset: f64) -> U
+where
+  T: Numeric,
+  U: Numeric,
+{
+  U::saturating_from_f64(x.to_f64() * scale + offset)
+}
+
+/// `Error` conveys that the data supplied when constructing an image does not agree with the requested image
+/// dimensions.
+///
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+  /// A supplied channel plane did not contain the number of elements required by the image's dimensions.
+  InvalidPlaneLength {
+    /// The number of elements the image's dimensions require.
+    expected: usize,
+    /// The number of elements the supplied plane actually contained.
+    actual: usize,
+  },
+  /// The product `width * height` overflowed `usize`.
+  DimensionOverflow,
+  /// The row stride supplied for a borrowed view was smaller than the view's width.
+  InvalidStride {
+    /// The width of the view.
+    width: usize,
+    /// The supplied row stride.
+    stride: usize,
+  },
+  /// The requested region of interest does not lie entirely within the image.
+  InvalidRoi,
+}
+
+impl std::fmt::Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Error::InvalidPlaneLength { expected, actual } => write!(
+        f,
+        "invalid plane length: expected {expected} elements, got {actual}"
+      ),
+      Error::DimensionOverflow => write!(f, "image dimensions overflow usize"),
+      Error::InvalidStride { width, stride } => write!(
+        f,
+        "invalid stride: {stride} is smaller than the width {width}"
+      ),
+      Error::InvalidRoi => write!(f, "region of interest does not lie within the image"),
+    }
+  }
+}
 
-impl Numeric for u8 {}
-impl Numeric for f32 {}
+impl std::error::Error for Error {}
+
+/// `plane_len` returns the number of elements a single channel of a `width * height` image must contain.
+///
+pub(crate) fn plane_len(width: usize, height: usize) -> Result<usize, Error> {
+  width.checked_mul(height).ok_or(Error::DimensionOverflow)
+}
+
+/// `check_plane` verifies that the supplied channel plane contains exactly `expected` number of elements.
+///
+pub(crate) fn check_plane<T>(plane: &[T], expected: usize) -> Result<(), Error> {
+  if plane.len() == expected {
+    Ok(())
+  } else {
+    Err(Error::InvalidPlaneLength {
+      expected,
+      actual: plane.len(),
+    })
+  }
+}
@@ -0,0 +1,851 @@
+//! `packed` contains image containers that store their pixels interleaved (`HWC` ordering), along with routines for
+//! converting between them and the planar images found in [`rgb`](crate::rgb) and [`rgba`](crate::rgba).
+//!
+//! Planar images are what `cvr` prefers to work with but many APIs (windowing toolkits, video encoders and the like)
+//! expect packed buffers. The conversions here are vectorized where the target supports it.
+//!
+//! # Example
+//! ```
+//! let img = cvr::rgb::Image::from_vec(vec![1_u8, 2], vec![3_u8, 4], vec![5_u8, 6], 2, 1).unwrap();
+//!
+//! let mut rgb8 = cvr::packed::Rgb8::new();
+//! cvr::packed::pack_rgb(img.view(), &mut rgb8);
+//! assert_eq!(rgb8.data(), [1, 3, 5, 2, 4, 6]);
+//!
+//! let mut bgr8 = cvr::packed::Bgr8::new();
+//! cvr::packed::pack_bgr(img.view(), &mut bgr8);
+//! assert_eq!(bgr8.data(), [5, 3, 1, 6, 4, 2]);
+//!
+//! let mut planar = cvr::rgb::Image::new();
+//! cvr::packed::unpack_bgr(&bgr8, &mut planar);
+//! assert!(planar == img);
+//! ```
+//!
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_ptr_alignment,
+  clippy::many_single_char_names
+)]
+
+extern crate minivec;
+
+use crate::{rgb, rgba};
+
+/// `Rgb8` represents an 8-bit `RGB` image whose pixels are stored interleaved as `[R, G, B, R, G, B, ...]`.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Rgb8 {
+  pub(super) data: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Rgb8 {
+  /// `new` returns an empty `Rgb8` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_data` constructs an `Rgb8` by taking ownership of the supplied interleaved pixel data.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `3 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_data(
+    data: minivec::MiniVec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = packed_len(width, height, 3)?;
+    crate::check_plane(&data, size)?;
+
+    Ok(Self {
+      data,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Rgb8` from the supplied interleaved pixel data. The elements of the `Vec` are moved
+  /// into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `3 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_vec(data: Vec<u8>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    Self::from_data(data.into_iter().collect(), width, height)
+  }
+
+  /// `data` returns an immutable reference to the interleaved pixel data.
+  ///
+  #[must_use]
+  pub fn data(&self) -> &[u8] {
+    self.data.as_slice()
+  }
+
+  /// `data_mut` returns a mutable reference to the interleaved pixel data.
+  ///
+  pub fn data_mut(&mut self) -> &mut [u8] {
+    self.data.as_mut_slice()
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `total` returns the total number of pixels in the image.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `[u8; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+    self.data.chunks_exact(3).map(|p| [p[0], p[1], p[2]])
+  }
+
+  /// `rgb_iter_mut` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `&mut [u8]` of length 3 in `RGB` ordering.
+  ///
+  pub fn rgb_iter_mut(&mut self) -> impl Iterator<Item = &'_ mut [u8]> + '_ {
+    self.data.chunks_exact_mut(3)
+  }
+
+  /// `resize` readjusts the internal buffer until it holds exactly `3 * width * height` number of elements and resets
+  /// the internal `width` and `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.data.resize(3 * width * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `Bgr8` represents an 8-bit `RGB` image whose pixels are stored interleaved in the reversed order
+/// `[B, G, R, B, G, R, ...]`, as is common in `OpenCV` and Windows APIs.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Bgr8 {
+  pub(super) data: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Bgr8 {
+  /// `new` returns an empty `Bgr8` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_data` constructs a `Bgr8` by taking ownership of the supplied interleaved pixel data.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `3 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_data(
+    data: minivec::MiniVec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = packed_len(width, height, 3)?;
+    crate::check_plane(&data, size)?;
+
+    Ok(Self {
+      data,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs a `Bgr8` from the supplied interleaved pixel data. The elements of the `Vec` are moved into
+  /// the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `3 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_vec(data: Vec<u8>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    Self::from_data(data.into_iter().collect(), width, height)
+  }
+
+  /// `data` returns an immutable reference to the interleaved pixel data.
+  ///
+  #[must_use]
+  pub fn data(&self) -> &[u8] {
+    self.data.as_slice()
+  }
+
+  /// `data_mut` returns a mutable reference to the interleaved pixel data.
+  ///
+  pub fn data_mut(&mut self) -> &mut [u8] {
+    self.data.as_mut_slice()
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `total` returns the total number of pixels in the image.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `bgr_iter` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `[u8; 3]` in its stored `BGR` ordering.
+  ///
+  pub fn bgr_iter(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+    self.data.chunks_exact(3).map(|p| [p[0], p[1], p[2]])
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `[u8; 3]` in `RGB` ordering so that it composes with the [`convert::iter`](crate::convert::iter) adapters.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+    self.data.chunks_exact(3).map(|p| [p[2], p[1], p[0]])
+  }
+
+  /// `bgr_iter_mut` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `&mut [u8]` of length 3 in `BGR` ordering.
+  ///
+  pub fn bgr_iter_mut(&mut self) -> impl Iterator<Item = &'_ mut [u8]> + '_ {
+    self.data.chunks_exact_mut(3)
+  }
+
+  /// `resize` readjusts the internal buffer until it holds exactly `3 * width * height` number of elements and resets
+  /// the internal `width` and `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.data.resize(3 * width * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `Rgba8` represents an 8-bit `RGBA` image whose pixels are stored interleaved as `[R, G, B, A, R, G, B, A, ...]`.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Rgba8 {
+  pub(super) data: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Rgba8 {
+  /// `new` returns an empty `Rgba8` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_data` constructs an `Rgba8` by taking ownership of the supplied interleaved pixel data.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `4 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_data(
+    data: minivec::MiniVec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = packed_len(width, height, 4)?;
+    crate::check_plane(&data, size)?;
+
+    Ok(Self {
+      data,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Rgba8` from the supplied interleaved pixel data. The elements of the `Vec` are moved
+  /// into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` does not contain exactly `4 * width * height` number of elements
+  /// and an `Error::DimensionOverflow` if that product overflows.
+  ///
+  pub fn from_vec(data: Vec<u8>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    Self::from_data(data.into_iter().collect(), width, height)
+  }
+
+  /// `data` returns an immutable reference to the interleaved pixel data.
+  ///
+  #[must_use]
+  pub fn data(&self) -> &[u8] {
+    self.data.as_slice()
+  }
+
+  /// `data_mut` returns a mutable reference to the interleaved pixel data.
+  ///
+  pub fn data_mut(&mut self) -> &mut [u8] {
+    self.data.as_mut_slice()
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `total` returns the total number of pixels in the image.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgba_iter` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `[u8; 4]`.
+  ///
+  pub fn rgba_iter(&self) -> impl Iterator<Item = [u8; 4]> + '_ {
+    self.data.chunks_exact(4).map(|p| [p[0], p[1], p[2], p[3]])
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the image in a row-major ordering, yielding the color components
+  /// of each pixel as a `[u8; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+    self.data.chunks_exact(4).map(|p| [p[0], p[1], p[2]])
+  }
+
+  /// `rgba_iter_mut` returns an iterator that traverses the image in a row-major ordering, yielding each pixel as a
+  /// `&mut [u8]` of length 4 in `RGBA` ordering.
+  ///
+  pub fn rgba_iter_mut(&mut self) -> impl Iterator<Item = &'_ mut [u8]> + '_ {
+    self.data.chunks_exact_mut(4)
+  }
+
+  /// `resize` readjusts the internal buffer until it holds exactly `4 * width * height` number of elements and resets
+  /// the internal `width` and `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.data.resize(4 * width * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `pack_rgb` interleaves the supplied planar image into `out`, resizing it to match.
+///
+pub fn pack_rgb(img: rgb::ImageRef<'_, u8>, out: &mut Rgb8) {
+  let (width, height) = (img.width(), img.height());
+  out.resize(width, height);
+
+  img
+    .rows()
+    .zip(out.data.chunks_exact_mut(3 * width.max(1)))
+    .for_each(|([r, g, b], row)| interleave3(r, g, b, row));
+}
+
+/// `unpack_rgb` separates the supplied packed image into the planes of `out`, resizing it to match.
+///
+pub fn unpack_rgb(img: &Rgb8, out: &mut rgb::Image<u8>) {
+  out.resize(img.width(), img.height());
+
+  let (r, g, b) = out.rgb_mut();
+  deinterleave3(img.data(), r, g, b);
+}
+
+/// `pack_bgr` interleaves the supplied planar image into `out` using a `BGR` ordering, resizing it to match.
+///
+pub fn pack_bgr(img: rgb::ImageRef<'_, u8>, out: &mut Bgr8) {
+  let (width, height) = (img.width(), img.height());
+  out.resize(width, height);
+
+  img
+    .rows()
+    .zip(out.data.chunks_exact_mut(3 * width.max(1)))
+    .for_each(|([r, g, b], row)| interleave3(b, g, r, row));
+}
+
+/// `unpack_bgr` separates the supplied `BGR` packed image into the planes of `out`, resizing it to match.
+///
+pub fn unpack_bgr(img: &Bgr8, out: &mut rgb::Image<u8>) {
+  out.resize(img.width(), img.height());
+
+  let (r, g, b) = out.rgb_mut();
+  deinterleave3(img.data(), b, g, r);
+}
+
+/// `pack_rgba` interleaves the supplied planar image into `out`, resizing it to match.
+///
+pub fn pack_rgba(img: rgba::ImageRef<'_, u8>, out: &mut Rgba8) {
+  let (width, height) = (img.width(), img.height());
+  out.resize(width, height);
+
+  img
+    .rows()
+    .zip(out.data.chunks_exact_mut(4 * width.max(1)))
+    .for_each(|([r, g, b, a], row)| interleave4(r, g, b, a, row));
+}
+
+/// `unpack_rgba` separates the supplied packed image into the planes of `out`, resizing it to match.
+///
+pub fn unpack_rgba(img: &Rgba8, out: &mut rgba::Image<u8>) {
+  out.resize(img.width(), img.height());
+
+  let (r, g, b, a) = out.rgba_mut();
+  deinterleave4(img.data(), r, g, b, a);
+}
+
+/// `interleave3` writes the three supplied planes into `out` as `[x, y, z, x, y, z, ...]`.
+///
+/// The number of pixels written is the smallest of the planes' lengths and `out.len() / 3`.
+///
+pub fn interleave3(x: &[u8], y: &[u8], z: &[u8], out: &mut [u8]) {
+  let n = x.len().min(y.len()).min(z.len()).min(out.len() / 3);
+  let (x, y, z, out) = (&x[..n], &y[..n], &z[..n], &mut out[..3 * n]);
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("ssse3") {
+      done = unsafe { simd::interleave3(x, y, z, out) };
+    }
+  }
+
+  out[3 * done..]
+    .chunks_exact_mut(3)
+    .zip(crate::rgb::make_iter(&x[done..], &y[done..], &z[done..]))
+    .for_each(|(p, [x, y, z])| {
+      p[0] = x;
+      p[1] = y;
+      p[2] = z;
+    });
+}
+
+/// `deinterleave3` splits the packed `[x, y, z, x, y, z, ...]` data into the three supplied planes.
+///
+/// The number of pixels read is the smallest of the planes' lengths and `data.len() / 3`.
+///
+pub fn deinterleave3(data: &[u8], x: &mut [u8], y: &mut [u8], z: &mut [u8]) {
+  let n = x.len().min(y.len()).min(z.len()).min(data.len() / 3);
+  let (data, x, y, z) = (&data[..3 * n], &mut x[..n], &mut y[..n], &mut z[..n]);
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("ssse3") {
+      done = unsafe { simd::deinterleave3(data, x, y, z) };
+    }
+  }
+
+  data[3 * done..]
+    .chunks_exact(3)
+    .zip(crate::rgb::make_iter_mut(
+      &mut x[done..],
+      &mut y[done..],
+      &mut z[done..],
+    ))
+    .for_each(|(p, [x, y, z])| {
+      *x = p[0];
+      *y = p[1];
+      *z = p[2];
+    });
+}
+
+/// `interleave4` writes the four supplied planes into `out` as `[x, y, z, w, x, y, z, w, ...]`.
+///
+/// The number of pixels written is the smallest of the planes' lengths and `out.len() / 4`.
+///
+pub fn interleave4(x: &[u8], y: &[u8], z: &[u8], w: &[u8], out: &mut [u8]) {
+  let n = x
+    .len()
+    .min(y.len())
+    .min(z.len())
+    .min(w.len())
+    .min(out.len() / 4);
+  let (x, y, z, w, out) = (&x[..n], &y[..n], &z[..n], &w[..n], &mut out[..4 * n]);
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("ssse3") {
+      done = unsafe { simd::interleave4(x, y, z, w, out) };
+    }
+  }
+
+  out[4 * done..]
+    .chunks_exact_mut(4)
+    .zip(crate::rgba::make_iter(
+      &x[done..],
+      &y[done..],
+      &z[done..],
+      &w[done..],
+    ))
+    .for_each(|(p, [x, y, z, w])| {
+      p[0] = x;
+      p[1] = y;
+      p[2] = z;
+      p[3] = w;
+    });
+}
+
+/// `deinterleave4` splits the packed `[x, y, z, w, x, y, z, w, ...]` data into the four supplied planes.
+///
+/// The number of pixels read is the smallest of the planes' lengths and `data.len() / 4`.
+///
+pub fn deinterleave4(data: &[u8], x: &mut [u8], y: &mut [u8], z: &mut [u8], w: &mut [u8]) {
+  let n = x
+    .len()
+    .min(y.len())
+    .min(z.len())
+    .min(w.len())
+    .min(data.len() / 4);
+  let (data, x, y, z, w) = (
+    &data[..4 * n],
+    &mut x[..n],
+    &mut y[..n],
+    &mut z[..n],
+    &mut w[..n],
+  );
+
+  let mut done = 0;
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    if is_x86_feature_detected!("ssse3") {
+      done = unsafe { simd::deinterleave4(data, x, y, z, w) };
+    }
+  }
+
+  data[4 * done..]
+    .chunks_exact(4)
+    .zip(crate::rgba::make_iter_mut(
+      &mut x[done..],
+      &mut y[done..],
+      &mut z[done..],
+      &mut w[done..],
+    ))
+    .for_each(|(p, [x, y, z, w])| {
+      *x = p[0];
+      *y = p[1];
+      *z = p[2];
+      *w = p[3];
+    });
+}
+
+fn packed_len(width: usize, height: usize, channels: usize) -> Result<usize, crate::Error> {
+  crate::plane_len(width, height)?
+    .checked_mul(channels)
+    .ok_or(crate::Error::DimensionOverflow)
+}
+
+#[cfg(target_arch = "x86_64")]
+mod simd {
+  use core::arch::x86_64::{
+    __m128i, _mm_loadu_si128, _mm_or_si128, _mm_shuffle_epi8, _mm_storeu_si128, _mm_unpackhi_epi16,
+    _mm_unpackhi_epi32, _mm_unpackhi_epi64, _mm_unpackhi_epi8, _mm_unpacklo_epi16,
+    _mm_unpacklo_epi32, _mm_unpacklo_epi64, _mm_unpacklo_epi8,
+  };
+
+  /// `interleave_mask` builds the `pshufb` mask that moves the bytes of `channel` into their place within the
+  /// `block`-th 16 byte section of 3-channel packed output.
+  ///
+  const fn interleave_mask(block: usize, channel: usize) -> [u8; 16] {
+    let mut mask = [0x80_u8; 16];
+    let mut i = 0;
+    while i < 16 {
+      let k = 16 * block + i;
+      if k % 3 == channel {
+        mask[i] = (k / 3) as u8;
+      }
+      i += 1;
+    }
+    mask
+  }
+
+  /// `deinterleave_mask` builds the `pshufb` mask that gathers the bytes of `channel` out of the `block`-th 16 byte
+  /// section of 3-channel packed input.
+  ///
+  const fn deinterleave_mask(block: usize, channel: usize) -> [u8; 16] {
+    let mut mask = [0x80_u8; 16];
+    let mut p = 0;
+    while p < 16 {
+      let k = 3 * p + channel;
+      if k >= 16 * block && k < 16 * (block + 1) {
+        mask[p] = (k - 16 * block) as u8;
+      }
+      p += 1;
+    }
+    mask
+  }
+
+  const INTERLEAVE: [[[u8; 16]; 3]; 3] = [
+    [
+      interleave_mask(0, 0),
+      interleave_mask(0, 1),
+      interleave_mask(0, 2),
+    ],
+    [
+      interleave_mask(1, 0),
+      interleave_mask(1, 1),
+      interleave_mask(1, 2),
+    ],
+    [
+      interleave_mask(2, 0),
+      interleave_mask(2, 1),
+      interleave_mask(2, 2),
+    ],
+  ];
+
+  const DEINTERLEAVE: [[[u8; 16]; 3]; 3] = [
+    [
+      deinterleave_mask(0, 0),
+      deinterleave_mask(1, 0),
+      deinterleave_mask(2, 0),
+    ],
+    [
+      deinterleave_mask(0, 1),
+      deinterleave_mask(1, 1),
+      deinterleave_mask(2, 1),
+    ],
+    [
+      deinterleave_mask(0, 2),
+      deinterleave_mask(1, 2),
+      deinterleave_mask(2, 2),
+    ],
+  ];
+
+  /// `group_channels` is the `pshufb` mask that reorders four packed 4-channel pixels so that each channel's bytes are
+  /// contiguous.
+  ///
+  const GROUP_CHANNELS: [u8; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+
+  unsafe fn load(mask: &[u8; 16]) -> __m128i {
+    _mm_loadu_si128(mask.as_ptr().cast::<__m128i>())
+  }
+
+  /// Interleaves as many full blocks of 16 pixels as possible, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn interleave3(x: &[u8], y: &[u8], z: &[u8], out: &mut [u8]) -> usize {
+    let n = x.len() / 16 * 16;
+
+    let px = x.as_ptr();
+    let py = y.as_ptr();
+    let pz = z.as_ptr();
+    let po = out.as_mut_ptr();
+
+    let mut i = 0;
+    while i < n {
+      let vx = _mm_loadu_si128(px.add(i).cast::<__m128i>());
+      let vy = _mm_loadu_si128(py.add(i).cast::<__m128i>());
+      let vz = _mm_loadu_si128(pz.add(i).cast::<__m128i>());
+
+      for (block, masks) in INTERLEAVE.iter().enumerate() {
+        let v = _mm_or_si128(
+          _mm_or_si128(
+            _mm_shuffle_epi8(vx, load(&masks[0])),
+            _mm_shuffle_epi8(vy, load(&masks[1])),
+          ),
+          _mm_shuffle_epi8(vz, load(&masks[2])),
+        );
+
+        _mm_storeu_si128(po.add(3 * i + 16 * block).cast::<__m128i>(), v);
+      }
+
+      i += 16;
+    }
+
+    n
+  }
+
+  /// Deinterleaves as many full blocks of 16 pixels as possible, returning the number of pixels read.
+  ///
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn deinterleave3(
+    data: &[u8],
+    x: &mut [u8],
+    y: &mut [u8],
+    z: &mut [u8],
+  ) -> usize {
+    let n = x.len() / 16 * 16;
+
+    let pd = data.as_ptr();
+    let outputs = [x.as_mut_ptr(), y.as_mut_ptr(), z.as_mut_ptr()];
+
+    let mut i = 0;
+    while i < n {
+      let blocks = [
+        _mm_loadu_si128(pd.add(3 * i).cast::<__m128i>()),
+        _mm_loadu_si128(pd.add(3 * i + 16).cast::<__m128i>()),
+        _mm_loadu_si128(pd.add(3 * i + 32).cast::<__m128i>()),
+      ];
+
+      for (channel, masks) in DEINTERLEAVE.iter().enumerate() {
+        let v = _mm_or_si128(
+          _mm_or_si128(
+            _mm_shuffle_epi8(blocks[0], load(&masks[0])),
+            _mm_shuffle_epi8(blocks[1], load(&masks[1])),
+          ),
+          _mm_shuffle_epi8(blocks[2], load(&masks[2])),
+        );
+
+        _mm_storeu_si128(outputs[channel].add(i).cast::<__m128i>(), v);
+      }
+
+      i += 16;
+    }
+
+    n
+  }
+
+  /// Interleaves as many full blocks of 16 pixels as possible, returning the number of pixels written.
+  ///
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn interleave4(
+    x: &[u8],
+    y: &[u8],
+    z: &[u8],
+    w: &[u8],
+    out: &mut [u8],
+  ) -> usize {
+    let n = x.len() / 16 * 16;
+
+    let po = out.as_mut_ptr();
+
+    let mut i = 0;
+    while i < n {
+      let vx = _mm_loadu_si128(x.as_ptr().add(i).cast::<__m128i>());
+      let vy = _mm_loadu_si128(y.as_ptr().add(i).cast::<__m128i>());
+      let vz = _mm_loadu_si128(z.as_ptr().add(i).cast::<__m128i>());
+      let vw = _mm_loadu_si128(w.as_ptr().add(i).cast::<__m128i>());
+
+      let xy_lo = _mm_unpacklo_epi8(vx, vy);
+      let xy_hi = _mm_unpackhi_epi8(vx, vy);
+      let zw_lo = _mm_unpacklo_epi8(vz, vw);
+      let zw_hi = _mm_unpackhi_epi8(vz, vw);
+
+      let outputs = [
+        _mm_unpacklo_epi16(xy_lo, zw_lo),
+        _mm_unpackhi_epi16(xy_lo, zw_lo),
+        _mm_unpacklo_epi16(xy_hi, zw_hi),
+        _mm_unpackhi_epi16(xy_hi, zw_hi),
+      ];
+
+      for (block, v) in outputs.iter().enumerate() {
+        _mm_storeu_si128(po.add(4 * i + 16 * block).cast::<__m128i>(), *v);
+      }
+
+      i += 16;
+    }
+
+    n
+  }
+
+  /// Deinterleaves as many full blocks of 16 pixels as possible, returning the number of pixels read.
+  ///
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn deinterleave4(
+    data: &[u8],
+    x: &mut [u8],
+    y: &mut [u8],
+    z: &mut [u8],
+    w: &mut [u8],
+  ) -> usize {
+    let n = x.len() / 16 * 16;
+
+    let pd = data.as_ptr();
+    let group = load(&GROUP_CHANNELS);
+
+    let mut i = 0;
+    while i < n {
+      // each register now holds [x x x x y y y y z z z z w w w w] for four consecutive pixels
+      //
+      let s0 = _mm_shuffle_epi8(_mm_loadu_si128(pd.add(4 * i).cast::<__m128i>()), group);
+      let s1 = _mm_shuffle_epi8(_mm_loadu_si128(pd.add(4 * i + 16).cast::<__m128i>()), group);
+      let s2 = _mm_shuffle_epi8(_mm_loadu_si128(pd.add(4 * i + 32).cast::<__m128i>()), group);
+      let s3 = _mm_shuffle_epi8(_mm_loadu_si128(pd.add(4 * i + 48).cast::<__m128i>()), group);
+
+      // transpose the 4x4 matrix of 32-bit lanes
+      //
+      let t0 = _mm_unpacklo_epi32(s0, s1);
+      let t1 = _mm_unpacklo_epi32(s2, s3);
+      let t2 = _mm_unpackhi_epi32(s0, s1);
+      let t3 = _mm_unpackhi_epi32(s2, s3);
+
+      _mm_storeu_si128(
+        x.as_mut_ptr().add(i).cast::<__m128i>(),
+        _mm_unpacklo_epi64(t0, t1),
+      );
+      _mm_storeu_si128(
+        y.as_mut_ptr().add(i).cast::<__m128i>(),
+        _mm_unpackhi_epi64(t0, t1),
+      );
+      _mm_storeu_si128(
+        z.as_mut_ptr().add(i).cast::<__m128i>(),
+        _mm_unpacklo_epi64(t2, t3),
+      );
+      _mm_storeu_si128(
+        w.as_mut_ptr().add(i).cast::<__m128i>(),
+        _mm_unpackhi_epi64(t2, t3),
+      );
+
+      i += 16;
+    }
+
+    n
+  }
+}
@@ -0,0 +1,333 @@
+//! `planar` contains a generic image container for planar data with an arbitrary number of channels, such as
+//! gray-alpha images, optical flow fields or multispectral captures.
+//!
+//! The dedicated [`gray`](crate::gray), [`rgb`](crate::rgb) and [`rgba`](crate::rgba) images can be converted to and
+//! from their `planar` equivalents without copying any of the image data.
+//!
+//! # Example
+//! ```
+//! let rgb = cvr::rgb::Image::from_vec(vec![1_u8, 2], vec![3, 4], vec![5, 6], 2, 1).unwrap();
+//!
+//! let mut img = cvr::planar::Image::<u8, 3>::from(rgb);
+//! img[2].copy_from_slice(&[7, 8]);
+//!
+//! let pixels: Vec<_> = img.pixel_iter().collect();
+//! assert_eq!(pixels, [[1, 3, 7], [2, 4, 8]]);
+//!
+//! let rgb = cvr::rgb::Image::from(img);
+//! assert_eq!(rgb.b(), [7, 8]);
+//! ```
+//!
+
+extern crate minivec;
+
+use crate::Numeric;
+
+/// `Image` represents any image made up of `C` independently allocated channel planes, each stored in a row-major
+/// ordering.
+///
+#[derive(Clone, PartialEq, Eq)]
+pub struct Image<T, const C: usize>
+where
+  T: Numeric,
+{
+  pub(super) planes: [minivec::MiniVec<T>; C],
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl<T, const C: usize> Default for Image<T, C>
+where
+  T: Numeric,
+{
+  fn default() -> Self {
+    Self {
+      planes: std::array::from_fn(|_| minivec::MiniVec::new()),
+      h: 0,
+      w: 0,
+    }
+  }
+}
+
+impl<T, const C: usize> Image<T, C>
+where
+  T: Numeric,
+{
+  /// `new` returns an empty `Image` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_planes` constructs an `Image` by taking ownership of the supplied channel data, which must be laid out in
+  /// a row-major ordering.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_planes(
+    planes: [minivec::MiniVec<T>; C],
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = crate::plane_len(width, height)?;
+
+    for plane in &planes {
+      crate::check_plane(plane, size)?;
+    }
+
+    Ok(Self {
+      planes,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Image` from the supplied channel data, which must be laid out in a row-major ordering.
+  /// The elements of each `Vec` are moved into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(planes: [Vec<T>; C], width: usize, height: usize) -> Result<Self, crate::Error> {
+    Self::from_planes(
+      planes.map(|plane| plane.into_iter().collect()),
+      width,
+      height,
+    )
+  }
+
+  /// `channels` returns the number of channels in the image, `C`.
+  ///
+  #[must_use]
+  pub fn channels(&self) -> usize {
+    C
+  }
+
+  /// `channel` returns an immutable reference to the `idx`-th channel as a `&[T]`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `idx` is not less than `C`.
+  ///
+  #[must_use]
+  pub fn channel(&self, idx: usize) -> &[T] {
+    self.planes[idx].as_slice()
+  }
+
+  /// `channel_mut` returns a mutable reference to the `idx`-th channel as a `&mut [T]`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `idx` is not less than `C`.
+  ///
+  pub fn channel_mut(&mut self, idx: usize) -> &mut [T] {
+    self.planes[idx].as_mut_slice()
+  }
+
+  /// `planes` returns immutable references to every channel of the image.
+  ///
+  #[must_use]
+  pub fn planes(&self) -> [&[T]; C] {
+    self.planes.each_ref().map(minivec::MiniVec::as_slice)
+  }
+
+  /// `planes_mut` returns mutable references to every channel of the image.
+  ///
+  pub fn planes_mut(&mut self) -> [&mut [T]; C] {
+    self.planes.each_mut().map(minivec::MiniVec::as_mut_slice)
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `total` returns the total number of pixels in the image, equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `pixel_iter` returns an iterator that traverses the planar image data in a row-major ordering, yielding each
+  /// pixel as a `[T; C]`.
+  ///
+  pub fn pixel_iter(&self) -> impl Iterator<Item = [T; C]> + '_ {
+    (0..self.total()).map(move |idx| std::array::from_fn(|c| self.planes[c][idx]))
+  }
+
+  /// `pixel_iter_mut` returns an iterator that traverses the planar image data in a row-major ordering, yielding each
+  /// pixel as a `[&mut T; C]` so that the underlying pixel values can be manipulated.
+  ///
+  pub fn pixel_iter_mut(&mut self) -> impl Iterator<Item = [&'_ mut T; C]> + '_ {
+    let total = self.total();
+    let mut iters = self.planes.each_mut().map(|plane| plane.iter_mut());
+
+    std::iter::from_fn(move || {
+      let next = iters.each_mut().map(Iterator::next);
+      next
+        .iter()
+        .all(Option::is_some)
+        .then(|| next.map(Option::unwrap))
+    })
+    .take(total)
+  }
+
+  /// `resize` readjusts the internal image buffers until their size is _at least_ `width * height` number of elements
+  /// and resets the internal `width` and `height` data members.
+  ///
+  /// `Default`-initializes new elements and does not attempt to preserve the quality of the underlying image. This
+  /// operation, while safe, should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    for plane in &mut self.planes {
+      plane.resize(width * height, Default::default());
+    }
+
+    self.h = height;
+    self.w = width;
+  }
+
+  /// `convert_to` converts the image into a different sample type, computing `saturate(x * scale + offset)` for every
+  /// channel value and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn convert_to<U>(&self, out: &mut Image<U, C>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+
+    for (src, dst) in self.planes.iter().zip(out.planes.iter_mut()) {
+      src
+        .iter()
+        .zip(dst.iter_mut())
+        .for_each(|(&x, y)| *y = crate::convert_sample(x, scale, offset));
+    }
+  }
+}
+
+impl<T, const C: usize> std::ops::Index<usize> for Image<T, C>
+where
+  T: Numeric,
+{
+  type Output = [T];
+
+  fn index(&self, idx: usize) -> &[T] {
+    self.channel(idx)
+  }
+}
+
+impl<T, const C: usize> std::ops::IndexMut<usize> for Image<T, C>
+where
+  T: Numeric,
+{
+  fn index_mut(&mut self, idx: usize) -> &mut [T] {
+    self.channel_mut(idx)
+  }
+}
+
+impl<T> std::convert::From<crate::gray::Image<T>> for Image<T, 1>
+where
+  T: Numeric,
+{
+  fn from(img: crate::gray::Image<T>) -> Self {
+    Self {
+      planes: [img.v],
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
+
+impl<T> std::convert::From<Image<T, 1>> for crate::gray::Image<T>
+where
+  T: Numeric,
+{
+  fn from(img: Image<T, 1>) -> Self {
+    let [v] = img.planes;
+
+    Self {
+      v,
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
+
+impl<T> std::convert::From<crate::rgb::Image<T>> for Image<T, 3>
+where
+  T: Numeric,
+{
+  fn from(img: crate::rgb::Image<T>) -> Self {
+    Self {
+      planes: [img.r, img.g, img.b],
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
+
+impl<T> std::convert::From<Image<T, 3>> for crate::rgb::Image<T>
+where
+  T: Numeric,
+{
+  fn from(img: Image<T, 3>) -> Self {
+    let [r, g, b] = img.planes;
+
+    Self {
+      r,
+      g,
+      b,
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
+
+impl<T> std::convert::From<crate::rgba::Image<T>> for Image<T, 4>
+where
+  T: Numeric,
+{
+  fn from(img: crate::rgba::Image<T>) -> Self {
+    Self {
+      planes: [img.r, img.g, img.b, img.a],
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
+
+impl<T> std::convert::From<Image<T, 4>> for crate::rgba::Image<T>
+where
+  T: Numeric,
+{
+  fn from(img: Image<T, 4>) -> Self {
+    let [r, g, b, a] = img.planes;
+
+    Self {
+      r,
+      g,
+      b,
+      a,
+      h: img.h,
+      w: img.w,
+    }
+  }
+}
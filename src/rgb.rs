@@ -32,6 +32,76 @@ where
     <Self as Default>::default()
   }
 
+  /// `from_planes` constructs an `Image` by taking ownership of the supplied channel data, which must be laid out in
+  /// a row-major ordering.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  /// # Example
+  /// ```
+  /// let r = minivec::mini_vec![1_u8, 2, 3, 4];
+  /// let g = minivec::mini_vec![5_u8, 6, 7, 8];
+  /// let b = minivec::mini_vec![9_u8, 10, 11, 12];
+  ///
+  /// let img = cvr::rgb::Image::from_planes(r, g, b, 2, 2).unwrap();
+  /// assert_eq!(img.total(), 4);
+  ///
+  /// let r = minivec::mini_vec![1_u8, 2, 3];
+  /// let g = minivec::mini_vec![5_u8, 6, 7, 8];
+  /// let b = minivec::mini_vec![9_u8, 10, 11, 12];
+  ///
+  /// assert!(cvr::rgb::Image::from_planes(r, g, b, 2, 2).is_err());
+  /// ```
+  ///
+  pub fn from_planes(
+    r: minivec::MiniVec<T>,
+    g: minivec::MiniVec<T>,
+    b: minivec::MiniVec<T>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = crate::plane_len(width, height)?;
+
+    crate::check_plane(&r, size)?;
+    crate::check_plane(&g, size)?;
+    crate::check_plane(&b, size)?;
+
+    Ok(Self {
+      r,
+      g,
+      b,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Image` from the supplied channel data, which must be laid out in a row-major ordering.
+  /// The elements of each `Vec` are moved into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(
+    r: Vec<T>,
+    g: Vec<T>,
+    b: Vec<T>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    Self::from_planes(
+      r.into_iter().collect(),
+      g.into_iter().collect(),
+      b.into_iter().collect(),
+      width,
+      height,
+    )
+  }
+
   /// `r` returns an immutable reference to the image's red channel as a `&[T]`.
   ///
   #[must_use]
@@ -111,6 +181,227 @@ where
     self.h = height;
     self.w = width;
   }
+
+  /// `swap_rb` exchanges the red and blue channels in constant time, converting between `RGB` and `BGR` orderings
+  /// without copying any image data.
+  ///
+  pub fn swap_rb(&mut self) {
+    std::mem::swap(&mut self.r, &mut self.b);
+  }
+
+  /// `view` returns an [`ImageRef`] that borrows the image's data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      r: &self.r,
+      g: &self.g,
+      b: &self.b,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `view_mut` returns an [`ImageMut`] that mutably borrows the image's data.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      r: &mut self.r,
+      g: &mut self.g,
+      b: &mut self.b,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  /// # Example
+  /// ```
+  /// use cvr::convert::iter::SRGBLinearIterator;
+  ///
+  /// let img = cvr::rgb::Image::from_vec(
+  ///     (0_u8..16).collect(),
+  ///     (16_u8..32).collect(),
+  ///     (32_u8..48).collect(),
+  ///     4,
+  ///     4,
+  /// )
+  /// .unwrap();
+  ///
+  /// let roi = img.roi(1, 1, 2, 2).unwrap();
+  ///
+  /// let reds: Vec<_> = roi.rgb_iter().map(|[r, _, _]| r).collect();
+  /// assert_eq!(reds, [5, 6, 9, 10]);
+  ///
+  /// // the `convert::iter` adapters compose with regions of interest like any other image
+  /// //
+  /// let linear: Vec<[f32; 3]> = roi.rgb_iter().srgb_to_linear().collect();
+  /// assert_eq!(linear.len(), 4);
+  /// ```
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `rows` returns an iterator over the rows of the image, yielding each one as a `[&[T]; 3]` in `RGB` ordering.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'_ [T]; 3]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the image, yielding each one as a `[&mut [T]; 3]` in `RGB`
+  /// ordering so that the underlying pixel values can be manipulated.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = [&'_ mut [T]; 3]> + '_ {
+    let (w, h) = (self.w, self.h);
+
+    crate::strided::RowsMut::new(&mut self.r, w, h, w)
+      .zip(crate::strided::RowsMut::new(&mut self.g, w, h, w))
+      .zip(crate::strided::RowsMut::new(&mut self.b, w, h, w))
+      .map(|((r, g), b)| [r, g, b])
+  }
+
+  /// `split_rows_mut` divides the image into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
+  }
+
+  /// `convert_to` converts the image into a different sample type, computing `saturate(x * scale + offset)` for every
+  /// channel value and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  /// # Example
+  /// ```
+  /// use cvr::Numeric;
+  ///
+  /// let img = cvr::rgb::Image::from_vec(vec![0_u8, 255], vec![1, 2], vec![3, 4], 2, 1).unwrap();
+  ///
+  /// let mut out = cvr::rgb::Image::<u16>::new();
+  /// img.convert_to(&mut out, u16::SCALE / u8::SCALE, 0.0);
+  /// assert_eq!(out.r(), [0, 65535]);
+  /// ```
+  ///
+  pub fn convert_to<U>(&self, out: &mut Image<U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().convert_to(&mut out.view_mut(), scale, offset);
+  }
+
+  /// `decode` linearizes the image with the supplied transfer `curve`, normalizing every channel value by
+  /// `T::SCALE` first and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut Image<f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    out.resize(self.w, self.h);
+    self.view().decode(curve, &mut out.view_mut());
+  }
+
+  /// `get` returns the pixel at column `x` and row `y` in `RGB` ordering, or `None` if the coordinate lies outside of
+  /// the image.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<[T; 3]> {
+    self.view().get(x, y)
+  }
+
+  /// `get_mut` returns a mutable reference to the pixel at column `x` and row `y` in `RGB` ordering, or `None` if the
+  /// coordinate lies outside of the image.
+  ///
+  pub fn get_mut(&mut self, x: usize, y: usize) -> Option<[&mut T; 3]> {
+    if x >= self.w || y >= self.h {
+      return None;
+    }
+
+    let idx = y * self.w + x;
+    Some([&mut self.r[idx], &mut self.g[idx], &mut self.b[idx]])
+  }
+
+  /// `get_unchecked` returns the pixel at column `x` and row `y` in `RGB` ordering without performing any bounds
+  /// checking.
+  ///
+  /// # Safety
+  ///
+  /// `x` must be less than `self.width()` and `y` must be less than `self.height()`.
+  ///
+  #[must_use]
+  pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> [T; 3] {
+    let idx = y * self.w + x;
+    [
+      *self.r.get_unchecked(idx),
+      *self.g.get_unchecked(idx),
+      *self.b.get_unchecked(idx),
+    ]
+  }
+
+  /// `enumerate_pixels` returns an iterator that traverses the image in a row-major ordering, yielding each pixel
+  /// along with its coordinate as `(x, y, pixel)`.
+  ///
+  pub fn enumerate_pixels(&self) -> impl Iterator<Item = (usize, usize, [T; 3])> + '_ {
+    let w = self.w;
+    self
+      .rgb_iter()
+      .enumerate()
+      .map(move |(idx, pixel)| (idx % w, idx / w, pixel))
+  }
+
+  /// `sample` reads the image at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> [T; 3] {
+    self.view().sample(x, y, interpolation, border)
+  }
 }
 
 impl Image<u8> {
@@ -121,29 +412,8 @@ impl Image<u8> {
   /// buffers of `out` are too small.
   ///
   pub fn to_linear(&self, out: &mut Image<f32>) {
-    let (width, height) = (self.w, self.h);
-    out.resize(width, height);
-
-    self
-      .r
-      .iter()
-      .copied()
-      .zip(out.r.iter_mut())
-      .for_each(|(r8, r)| *r = crate::convert::srgb_to_linear(r8));
-
-    self
-      .g
-      .iter()
-      .copied()
-      .zip(out.g.iter_mut())
-      .for_each(|(g8, g)| *g = crate::convert::srgb_to_linear(g8));
-
-    self
-      .b
-      .iter()
-      .copied()
-      .zip(out.b.iter_mut())
-      .for_each(|(b8, b)| *b = crate::convert::srgb_to_linear(b8));
+    out.resize(self.w, self.h);
+    self.view().to_linear(&mut out.view_mut());
   }
 }
 
@@ -156,28 +426,664 @@ impl Image<f32> {
   /// buffers of `out` are too small.
   ///
   pub fn to_srgb(&self, out: &mut Image<u8>) {
-    let (width, height) = (self.w, self.h);
-    out.resize(width, height);
-    self
-      .r
-      .iter()
-      .copied()
-      .zip(out.r.iter_mut())
-      .for_each(|(r32, r)| *r = crate::convert::linear_to_srgb(r32));
+    out.resize(self.w, self.h);
+    self.view().to_srgb(&mut out.view_mut());
+  }
 
-    self
-      .g
-      .iter()
-      .copied()
-      .zip(out.g.iter_mut())
-      .for_each(|(g32, g)| *g = crate::convert::linear_to_srgb(g32));
+  /// `encode` applies the supplied transfer `curve` to the linear image, scaling every encoded channel value by
+  /// `U::SCALE` and storing the saturated result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut Image<U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().encode(curve, &mut out.view_mut());
+  }
+}
 
-    self
-      .b
-      .iter()
-      .copied()
-      .zip(out.b.iter_mut())
-      .for_each(|(b32, b)| *b = crate::convert::linear_to_srgb(b32));
+impl<'a, T> std::convert::From<&'a Image<T>> for ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a Image<T>) -> Self {
+    img.view()
+  }
+}
+
+impl<'a, T> std::convert::From<&'a mut Image<T>> for ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a mut Image<T>) -> Self {
+    img.view_mut()
+  }
+}
+
+/// `ImageRef` is a borrowed, read-only view of `RGB` image data that is owned elsewhere, such as by a driver's ring
+/// buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+#[derive(Clone, Copy)]
+pub struct ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  r: &'a [T],
+  g: &'a [T],
+  b: &'a [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a view over the supplied row-major planes where each row begins `stride` elements after the
+  /// start of the previous one.
+  ///
+  /// Each plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if any of the
+  /// planes are too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  /// # Example
+  /// ```
+  /// // a 2x2 image stored with a padding of one element at the end of each row
+  /// //
+  /// let r = [1_u8, 2, 0, 3, 4];
+  /// let g = [5_u8, 6, 0, 7, 8];
+  /// let b = [9_u8, 10, 0, 11, 12];
+  ///
+  /// let img = cvr::rgb::ImageRef::new(&r, &g, &b, 2, 2, 3).unwrap();
+  ///
+  /// let pixels: Vec<_> = img.rgb_iter().collect();
+  /// assert_eq!(pixels, [[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]]);
+  /// ```
+  ///
+  pub fn new(
+    r: &'a [T],
+    g: &'a [T],
+    b: &'a [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+  ) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+
+    crate::strided::check_view_plane(r, len)?;
+    crate::strided::check_view_plane(g, len)?;
+    crate::strided::check_view_plane(b, len)?;
+
+    Ok(Self {
+      r: &r[..len],
+      g: &g[..len],
+      b: &b[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `r` returns the view's red channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn r(&self) -> &'a [T] {
+    self.r
+  }
+
+  /// `g` returns the view's green channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn g(&self) -> &'a [T] {
+    self.g
+  }
+
+  /// `b` returns the view's blue channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn b(&self) -> &'a [T] {
+    self.b
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view, equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and yielding
+  /// each pixel as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [T; 3]> + 'a {
+    self.rows().flat_map(|[r, g, b]| make_iter(r, g, b))
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `[&[T]; 3]` in `RGB` ordering that
+  /// excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'a [T]; 3]> + 'a {
+    let (w, h, stride) = (self.w, self.h, self.stride);
+
+    crate::strided::Rows::new(self.r, w, h, stride)
+      .zip(crate::strided::Rows::new(self.g, w, h, stride))
+      .zip(crate::strided::Rows::new(self.b, w, h, stride))
+      .map(|((r, g), b)| [r, g, b])
+  }
+
+  /// `roi` returns a view of the `width * height` rectangle whose top-left corner is at column `x` and row `y` of this
+  /// view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageRef {
+      r: &self.r[range.clone()],
+      g: &self.g[range.clone()],
+      b: &self.b[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `convert_to` converts the viewed data into a different sample type, computing `saturate(x * scale + offset)` for
+  /// every channel value and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn convert_to<U>(&self, out: &mut ImageMut<'_, U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: T| crate::convert_sample(x, scale, offset);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+
+  /// `decode` linearizes the viewed data with the supplied transfer `curve`, normalizing every channel value
+  /// by `T::SCALE` first and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut ImageMut<'_, f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: T| crate::convert::transfer::decode_sample(curve, x);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+}
+
+impl ImageRef<'_, u8> {
+  /// `to_linear` will take the viewed 8-bit `sRGB` data and convert it to its linear floating point representation,
+  /// storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_linear(&self, out: &mut ImageMut<'_, f32>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = crate::convert::kernels::srgb_to_linear;
+
+    crate::strided::map_rows(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_rows(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_rows(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+
+  /// `to_f32` converts the viewed 8-bit data into floating point, normalizing the channel values to the range
+  /// `[0.0, 1.0]` and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_f32(&self, out: &mut ImageMut<'_, f32>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = crate::convert::kernels::u8_to_f32;
+
+    crate::strided::map_rows(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_rows(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_rows(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+}
+
+impl ImageRef<'_, f32> {
+  /// `to_srgb` will take the viewed linear floating point data and convert it to its 8-bit `sRGB` representation,
+  /// storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_srgb(&self, out: &mut ImageMut<'_, u8>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = crate::convert::kernels::linear_to_srgb;
+
+    crate::strided::map_rows(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_rows(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_rows(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+
+  /// `encode` applies the supplied transfer `curve` to the viewed linear data, scaling every encoded channel value by
+  /// `U::SCALE` and storing the saturated result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut ImageMut<'_, U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: f32| crate::convert::transfer::encode_sample(curve, x);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `get` returns the pixel at column `x` and row `y` of the view in `RGB` ordering, or `None` if the coordinate lies
+  /// outside of the view.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<[T; 3]> {
+    if x >= self.w || y >= self.h {
+      return None;
+    }
+
+    let idx = y * self.stride + x;
+    Some([self.r[idx], self.g[idx], self.b[idx]])
+  }
+
+  /// `sample` reads the view at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> [T; 3] {
+    let dims = (self.w, self.h, self.stride);
+    [self.r, self.g, self.b]
+      .map(|plane| crate::sample::sample_plane(plane, dims, (x, y), interpolation, border))
+  }
+}
+
+/// `ImageMut` is a borrowed, mutable view of `RGB` image data that is owned elsewhere, such as by a driver's ring
+/// buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+pub struct ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  r: &'a mut [T],
+  g: &'a mut [T],
+  b: &'a mut [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a mutable view over the supplied row-major planes where each row begins `stride` elements after
+  /// the start of the previous one.
+  ///
+  /// Each plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if any of the
+  /// planes are too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  pub fn new(
+    r: &'a mut [T],
+    g: &'a mut [T],
+    b: &'a mut [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+  ) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+
+    crate::strided::check_view_plane(r, len)?;
+    crate::strided::check_view_plane(g, len)?;
+    crate::strided::check_view_plane(b, len)?;
+
+    Ok(Self {
+      r: &mut r[..len],
+      g: &mut g[..len],
+      b: &mut b[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `view` returns a read-only [`ImageRef`] of the same data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      r: self.r,
+      g: self.g,
+      b: self.b,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `view_mut` reborrows the view, returning an `ImageMut` with a shorter lifetime.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      r: self.r,
+      g: self.g,
+      b: self.b,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `r` returns the view's red channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn r(&self) -> &[T] {
+    self.r
+  }
+
+  /// `g` returns the view's green channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn g(&self) -> &[T] {
+    self.g
+  }
+
+  /// `b` returns the view's blue channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn b(&self) -> &[T] {
+    self.b
+  }
+
+  /// `rgb_mut` returns a tuple containing mutable references to the viewed data in `RGB` ordering, including any
+  /// padding elements between rows.
+  ///
+  pub fn rgb_mut(&mut self) -> (&mut [T], &mut [T], &mut [T]) {
+    (self.r, self.g, self.b)
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view, equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and yielding
+  /// each pixel as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [T; 3]> + '_ {
+    self.view().rgb_iter()
+  }
+
+  /// `rgb_iter_mut` returns an iterator that traverses the view in a row-major ordering, skipping any padding and
+  /// yielding each pixel as a `[&mut T; 3]` so that the underlying pixel values can be manipulated.
+  ///
+  pub fn rgb_iter_mut(&mut self) -> impl Iterator<Item = [&'_ mut T; 3]> + '_ {
+    self.rows_mut().flat_map(|[r, g, b]| make_iter_mut(r, g, b))
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `[&[T]; 3]` in `RGB` ordering that
+  /// excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'_ [T]; 3]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the view, yielding each one as a `[&mut [T]; 3]` in `RGB`
+  /// ordering that excludes any padding.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = [&'_ mut [T]; 3]> + '_ {
+    let (w, h, stride) = (self.w, self.h, self.stride);
+
+    crate::strided::RowsMut::new(self.r, w, h, stride)
+      .zip(crate::strided::RowsMut::new(self.g, w, h, stride))
+      .zip(crate::strided::RowsMut::new(self.b, w, h, stride))
+      .map(|((r, g), b)| [r, g, b])
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `into_roi` consumes the view, narrowing it to the `width * height` rectangle whose top-left corner is at column
+  /// `x` and row `y`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn into_roi(
+    self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageMut {
+      r: &mut self.r[range.clone()],
+      g: &mut self.g[range.clone()],
+      b: &mut self.b[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+
+  /// `into_split_rows` consumes the view, dividing it into `n` disjoint horizontal bands of consecutive rows. Band
+  /// heights differ by at most one, with any remainder going to the leading bands.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  #[must_use]
+  pub fn into_split_rows(self, n: usize) -> Vec<ImageMut<'a, T>> {
+    let heights = crate::strided::band_heights(self.h, n);
+    let (w, stride) = (self.w, self.stride);
+
+    let red = crate::strided::split_plane_mut(self.r, w, stride, &heights);
+    let green = crate::strided::split_plane_mut(self.g, w, stride, &heights);
+    let blue = crate::strided::split_plane_mut(self.b, w, stride, &heights);
+
+    red
+      .into_iter()
+      .zip(green)
+      .zip(blue)
+      .zip(heights)
+      .map(|(((red, green), blue), h)| ImageMut {
+        r: red,
+        g: green,
+        b: blue,
+        h,
+        w,
+        stride,
+      })
+      .collect()
+  }
+
+  /// `split_rows_mut` divides the view into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
   }
 }
 
@@ -211,22 +1117,309 @@ pub fn make_iter_mut<'a, T: Numeric>(
 }
 
 /// `cvt_u8_to_f32` converts the current 8-bit image into floating point, normalizing the channel values to the range
-/// `[0.0, 1.0]`.
+/// `[0.0, 1.0]`. The results are the same as those of [`Image::convert_to`] with a `scale` of `1.0 / 255.0`, which
+/// handles conversions between arbitrary sample types.
+///
+/// See [`ImageRef::to_f32`] for converting regions of interest and other borrowed views.
 ///
 pub fn cvt_u8_to_f32(x: &Image<u8>, y: &mut Image<f32>) {
-  const N: f32 = 1.0 / 255.0;
-
   y.resize(x.width(), x.height());
+  x.view().to_f32(&mut y.view_mut());
+}
 
-  x.r.iter().copied().zip(y.r.iter_mut()).for_each(|(b, f)| {
-    *f = N * f32::from(b);
-  });
+/// `cvt_rgb_to_gray` reduces the supplied `RGB` image to a single gray channel by computing a weighted sum of each
+/// pixel's channels, storing the result in `y`.
+///
+/// [`GrayWeights::Luminance`](crate::convert::GrayWeights::Luminance) should be used with linear data while
+/// [`GrayWeights::Bt601`](crate::convert::GrayWeights::Bt601) is intended for gamma-encoded data. Integral results are
+/// rounded to the nearest value.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly. See [`cvt_linear_to_gray`] for the vectorized
+/// conversion of linear floating point images.
+///
+/// # Example
+/// ```
+/// use cvr::convert::GrayWeights;
+///
+/// let img = cvr::rgb::Image::from_vec(vec![255_u8, 0], vec![0, 0], vec![0, 255], 2, 1).unwrap();
+///
+/// let mut gray = cvr::gray::Image::new();
+/// cvr::rgb::cvt_rgb_to_gray(&img, &mut gray, GrayWeights::Bt601);
+/// assert_eq!(gray.v(), [76, 29]);
+/// ```
+///
+pub fn cvt_rgb_to_gray<T>(
+  x: &Image<T>,
+  y: &mut crate::gray::Image<T>,
+  weights: crate::convert::GrayWeights,
+) where
+  T: Numeric,
+{
+  let [wr, wg, wb] = weights.coefficients().map(f64::from);
 
-  x.g.iter().copied().zip(y.g.iter_mut()).for_each(|(b, f)| {
-    *f = N * f32::from(b);
+  y.resize(x.width(), x.height());
+  y.iter_mut().zip(x.rgb_iter()).for_each(|(out, [r, g, b])| {
+    *out = T::saturating_from_f64(wr * r.to_f64() + wg * g.to_f64() + wb * b.to_f64());
   });
+}
 
-  x.b.iter().copied().zip(y.b.iter_mut()).for_each(|(b, f)| {
-    *f = N * f32::from(b);
-  });
+/// `cvt_linear_to_gray` computes the luminance of every pixel of the supplied linear image, storing the result in `y`.
+///
+/// This is equivalent to [`cvt_rgb_to_gray`] with [`GrayWeights::Luminance`](crate::convert::GrayWeights::Luminance)
+/// but is vectorized and yields exactly the values of [`linear_to_gray`](crate::convert::linear_to_gray).
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_gray(x: &Image<f32>, y: &mut crate::gray::Image<f32>) {
+  y.resize(x.width(), x.height());
+  crate::convert::kernels::linear_to_gray(x.r(), x.g(), x.b(), y.v_mut());
+}
+
+/// `cvt_rgb_to_rgba` combines the supplied `RGB` image with a separate alpha plane, storing the result in `y`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+/// # Panics
+///
+/// Panics if `alpha` does not have the same width and height as `x`.
+///
+pub fn cvt_rgb_to_rgba<T>(
+  x: &Image<T>,
+  alpha: &crate::gray::Image<T>,
+  y: &mut crate::rgba::Image<T>,
+) where
+  T: Numeric,
+{
+  assert!(
+    x.width() == alpha.width() && x.height() == alpha.height(),
+    "alpha dimensions must match the input"
+  );
+
+  y.resize(x.width(), x.height());
+
+  let (red, green, blue, opacity) = y.rgba_mut();
+  red.copy_from_slice(x.r());
+  green.copy_from_slice(x.g());
+  blue.copy_from_slice(x.b());
+  opacity.copy_from_slice(alpha.v());
+}
+
+/// `cvt_rgb_to_bgr` copies the supplied image into `y` with its red and blue channels exchanged.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly. See [`Image::swap_rb`] for converting an image
+/// in place.
+///
+pub fn cvt_rgb_to_bgr<T>(x: &Image<T>, y: &mut Image<T>)
+where
+  T: Numeric,
+{
+  y.resize(x.width(), x.height());
+
+  let (red, green, blue) = y.rgb_mut();
+  red.copy_from_slice(x.b());
+  green.copy_from_slice(x.g());
+  blue.copy_from_slice(x.r());
+}
+
+/// `cvt_pixels` applies the per-pixel conversion `f` to every pixel of `x`, storing the result in `y`.
+///
+fn cvt_pixels<F>(x: &Image<f32>, y: &mut Image<f32>, f: F)
+where
+  F: Fn([f32; 3]) -> [f32; 3],
+{
+  y.resize(x.width(), x.height());
+  y.rgb_iter_mut()
+    .zip(x.rgb_iter().map(f))
+    .for_each(|([out_r, out_g, out_b], [r, g, b])| {
+      *out_r = r;
+      *out_g = g;
+      *out_b = b;
+    });
+}
+
+/// `cvt_linear_to_hsv` converts the supplied linear image to `HSV`, storing the hue, saturation and value in the red,
+/// green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_hsv(x: &Image<f32>, y: &mut Image<f32>) {
+  y.resize(x.width(), x.height());
+
+  let (hue, saturation, value) = y.rgb_mut();
+  crate::convert::kernels::linear_to_hsv(x.r(), x.g(), x.b(), hue, saturation, value);
+}
+
+/// `cvt_hsv_to_linear` converts the supplied `HSV` image, stored as in [`cvt_linear_to_hsv`], back to linear `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_hsv_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  y.resize(x.width(), x.height());
+
+  let (red, green, blue) = y.rgb_mut();
+  crate::convert::kernels::hsv_to_linear(x.r(), x.g(), x.b(), red, green, blue);
+}
+
+/// `cvt_linear_to_hsl` converts the supplied linear image to `HSL`, storing the hue, saturation and lightness in the
+/// red, green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_hsl(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::linear_to_hsl);
+}
+
+/// `cvt_hsl_to_linear` converts the supplied `HSL` image, stored as in [`cvt_linear_to_hsl`], back to linear `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_hsl_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::hsl_to_linear);
+}
+
+/// `cvt_linear_to_hsi` converts the supplied linear image to `HSI`, storing the hue, saturation and intensity in the
+/// red, green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_hsi(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::linear_to_hsi);
+}
+
+/// `cvt_hsi_to_linear` converts the supplied `HSI` image, stored as in [`cvt_linear_to_hsi`], back to linear `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_hsi_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::hsi_to_linear);
+}
+
+/// `cvt_linear_to_xyz` converts the supplied linear image to CIE `XYZ`, storing the `X`, `Y` and `Z` components in the
+/// red, green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_xyz(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::linear_to_xyz);
+}
+
+/// `cvt_xyz_to_linear` converts the supplied `XYZ` image, stored as in [`cvt_linear_to_xyz`], back to linear `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_xyz_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::xyz_to_linear);
+}
+
+/// `cvt_linear_to_lab` converts the supplied linear image to `L*a*b*` relative to the white point of `reference`,
+/// storing the `L*`, `a*` and `b*` components in the red, green and blue planes of `y` respectively. The linear values
+/// are chromatically adapted to the white point first, see [`CieReference`](crate::convert::CieReference).
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_lab(
+  x: &Image<f32>,
+  y: &mut Image<f32>,
+  reference: crate::convert::CieReference,
+) {
+  cvt_pixels(x, y, |rgb| reference.linear_to_lab(rgb));
+}
+
+/// `cvt_lab_to_linear` converts the supplied `L*a*b*` image, stored as in [`cvt_linear_to_lab`], back to linear
+/// `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_lab_to_linear(
+  x: &Image<f32>,
+  y: &mut Image<f32>,
+  reference: crate::convert::CieReference,
+) {
+  cvt_pixels(x, y, |lab| reference.lab_to_linear(lab));
+}
+
+/// `cvt_linear_to_luv` converts the supplied linear image to `L*u*v*` relative to the white point of `reference`,
+/// storing the `L*`, `u*` and `v*` components in the red, green and blue planes of `y` respectively. The linear values
+/// are chromatically adapted to the white point first, see [`CieReference`](crate::convert::CieReference).
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_luv(
+  x: &Image<f32>,
+  y: &mut Image<f32>,
+  reference: crate::convert::CieReference,
+) {
+  cvt_pixels(x, y, |rgb| reference.linear_to_luv(rgb));
+}
+
+/// `cvt_luv_to_linear` converts the supplied `L*u*v*` image, stored as in [`cvt_linear_to_luv`], back to linear
+/// `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_luv_to_linear(
+  x: &Image<f32>,
+  y: &mut Image<f32>,
+  reference: crate::convert::CieReference,
+) {
+  cvt_pixels(x, y, |luv| reference.luv_to_linear(luv));
+}
+
+/// `cvt_linear_to_oklab` converts the supplied linear image to Oklab, storing the `L`, `a` and `b` components in the
+/// red, green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_oklab(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::linear_to_oklab);
+}
+
+/// `cvt_oklab_to_linear` converts the supplied Oklab image, stored as in [`cvt_linear_to_oklab`], back to linear
+/// `RGB`.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_oklab_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::oklab_to_linear);
+}
+
+/// `cvt_linear_to_oklch` converts the supplied linear image to `OkLCh`, storing the `L`, `C` and `h` components in the
+/// red, green and blue planes of `y` respectively.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_linear_to_oklch(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::linear_to_oklch);
+}
+
+/// `cvt_oklch_to_linear` converts the supplied `OkLCh` image, stored as in [`cvt_linear_to_oklch`], back to linear
+/// `RGB`. Colors outside of the `sRGB` gamut are not clamped; use [`cvt_oklch_to_linear_clipped`] to map them into it.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_oklch_to_linear(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::oklch_to_linear);
+}
+
+/// `cvt_oklch_to_linear_clipped` converts the supplied `OkLCh` image to linear `RGB` like [`cvt_oklch_to_linear`],
+/// but maps colors outside of the `sRGB` gamut back into it with
+/// [`gamut_clip_oklch`](crate::convert::gamut_clip_oklch), preserving their hue.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_oklch_to_linear_clipped(x: &Image<f32>, y: &mut Image<f32>) {
+  cvt_pixels(x, y, crate::convert::oklch_to_linear_clipped);
+}
+
+/// `cvt_rgb_space` converts the supplied image from the source to the destination space of the `conversion`. The
+/// values of both images are encoded with the transfer functions of their respective spaces.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_rgb_space(
+  x: &Image<f32>,
+  y: &mut Image<f32>,
+  conversion: &crate::convert::gamut::Conversion,
+) {
+  cvt_pixels(x, y, |rgb| conversion.convert(rgb));
 }
@@ -9,7 +9,7 @@ use crate::Numeric;
 /// allocation which enables such things as constant-time channel swapping along with making the
 /// data cheaper to copy to a GPU which expects `CHW` ordering vs the packed format `HWC`.
 ///
-#[derive(Default)]
+#[derive(Default, Clone, PartialEq, Eq)]
 pub struct Image<T>
 where
   T: Numeric,
@@ -26,6 +26,72 @@ impl<T> Image<T>
 where
   T: Numeric,
 {
+  /// `new` returns an empty `Image` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_planes` constructs an `Image` by taking ownership of the supplied channel data, which must be laid out in
+  /// a row-major ordering.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_planes(
+    r: minivec::MiniVec<T>,
+    g: minivec::MiniVec<T>,
+    b: minivec::MiniVec<T>,
+    a: minivec::MiniVec<T>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = crate::plane_len(width, height)?;
+
+    crate::check_plane(&r, size)?;
+    crate::check_plane(&g, size)?;
+    crate::check_plane(&b, size)?;
+    crate::check_plane(&a, size)?;
+
+    Ok(Self {
+      r,
+      g,
+      b,
+      a,
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `from_vec` constructs an `Image` from the supplied channel data, which must be laid out in a row-major ordering.
+  /// The elements of each `Vec` are moved into the image's internal storage.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes do not contain exactly `width * height` number of
+  /// elements and an `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(
+    r: Vec<T>,
+    g: Vec<T>,
+    b: Vec<T>,
+    a: Vec<T>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    Self::from_planes(
+      r.into_iter().collect(),
+      g.into_iter().collect(),
+      b.into_iter().collect(),
+      a.into_iter().collect(),
+      width,
+      height,
+    )
+  }
+
   /// `r` returns an immutable reference to the image's red channel as a `&[T]`.
   ///
   #[must_use]
@@ -71,7 +137,6 @@ where
   /// `rgba_iter` returns an iterator that traverses the planar image data in a row-major ordering, yielding each pixel
   /// as a `[T; 4]`.
   ///
-
   pub fn rgba_iter(&self) -> impl Iterator<Item = [T; 4]> + '_ {
     make_iter(&self.r, &self.g, &self.b, &self.a)
   }
@@ -82,6 +147,953 @@ where
   pub fn rgb_iter(&self) -> impl Iterator<Item = [T; 3]> + '_ {
     crate::rgb::make_iter(&self.r, &self.g, &self.b)
   }
+
+  /// `rgba_mut` returns a tuple containing mutable references to the underlying image data in `RGBA` ordering.
+  ///
+  pub fn rgba_mut(&mut self) -> (&mut [T], &mut [T], &mut [T], &mut [T]) {
+    (&mut self.r, &mut self.g, &mut self.b, &mut self.a)
+  }
+
+  /// `rgba_iter_mut` returns an iterator that traverses the planar image data in a row-major ordering, yielding each
+  /// pixel as a `[&mut T; 4]` so that the underlying pixel values can be manipulated.
+  ///
+  pub fn rgba_iter_mut(&mut self) -> impl Iterator<Item = [&'_ mut T; 4]> + '_ {
+    make_iter_mut(&mut self.r, &mut self.g, &mut self.b, &mut self.a)
+  }
+
+  /// `total` returns the total number of pixels in the image. This function's name comes from the corresponding one
+  /// from `OpenCV`'s `Mat` class and is equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `resize` readjusts the internal image buffers until their size is _at least_ `width * height` number of elements
+  /// and resets the internal `width` and `height` data members.
+  ///
+  /// Does not allocate if the buffers are already large enough.
+  ///
+  /// `Default`-initializes new elements and does not attempt to preserve the quality of the underlying image. This
+  /// operation, while safe, should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.r.resize(width * height, Default::default());
+    self.g.resize(width * height, Default::default());
+    self.b.resize(width * height, Default::default());
+    self.a.resize(width * height, Default::default());
+
+    self.h = height;
+    self.w = width;
+  }
+
+  /// `view` returns an [`ImageRef`] that borrows the image's data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      r: &self.r,
+      g: &self.g,
+      b: &self.b,
+      a: &self.a,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `view_mut` returns an [`ImageMut`] that mutably borrows the image's data.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      r: &mut self.r,
+      g: &mut self.g,
+      b: &mut self.b,
+      a: &mut self.a,
+      h: self.h,
+      w: self.w,
+      stride: self.w,
+    }
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of the image. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the image.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `rows` returns an iterator over the rows of the image, yielding each one as a `[&[T]; 4]` in `RGBA` ordering.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'_ [T]; 4]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the image, yielding each one as a `[&mut [T]; 4]` in `RGBA`
+  /// ordering so that the underlying pixel values can be manipulated.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = [&'_ mut [T]; 4]> + '_ {
+    let (w, h) = (self.w, self.h);
+
+    crate::strided::RowsMut::new(&mut self.r, w, h, w)
+      .zip(crate::strided::RowsMut::new(&mut self.g, w, h, w))
+      .zip(crate::strided::RowsMut::new(&mut self.b, w, h, w))
+      .zip(crate::strided::RowsMut::new(&mut self.a, w, h, w))
+      .map(|(((r, g), b), a)| [r, g, b, a])
+  }
+
+  /// `split_rows_mut` divides the image into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
+  }
+
+  /// `convert_to` converts the image into a different sample type, computing `saturate(x * scale + offset)` for every
+  /// channel value, including alpha, and storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn convert_to<U>(&self, out: &mut Image<U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().convert_to(&mut out.view_mut(), scale, offset);
+  }
+
+  /// `decode` linearizes the color channels of the image with the supplied transfer `curve`, normalizing every channel
+  /// value by `T::SCALE` first and storing the result in `out`. The alpha channel is not gamma-encoded and is only
+  /// normalized to the range `[0.0, 1.0]`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut Image<f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    out.resize(self.w, self.h);
+    self.view().decode(curve, &mut out.view_mut());
+  }
+
+  /// `get` returns the pixel at column `x` and row `y` in `RGBA` ordering, or `None` if the coordinate lies outside of
+  /// the image.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<[T; 4]> {
+    self.view().get(x, y)
+  }
+
+  /// `get_mut` returns a mutable reference to the pixel at column `x` and row `y` in `RGBA` ordering, or `None` if the
+  /// coordinate lies outside of the image.
+  ///
+  pub fn get_mut(&mut self, x: usize, y: usize) -> Option<[&mut T; 4]> {
+    if x >= self.w || y >= self.h {
+      return None;
+    }
+
+    let idx = y * self.w + x;
+    Some([
+      &mut self.r[idx],
+      &mut self.g[idx],
+      &mut self.b[idx],
+      &mut self.a[idx],
+    ])
+  }
+
+  /// `get_unchecked` returns the pixel at column `x` and row `y` in `RGBA` ordering without performing any bounds
+  /// checking.
+  ///
+  /// # Safety
+  ///
+  /// `x` must be less than `self.width()` and `y` must be less than `self.height()`.
+  ///
+  #[must_use]
+  pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> [T; 4] {
+    let idx = y * self.w + x;
+    [
+      *self.r.get_unchecked(idx),
+      *self.g.get_unchecked(idx),
+      *self.b.get_unchecked(idx),
+      *self.a.get_unchecked(idx),
+    ]
+  }
+
+  /// `enumerate_pixels` returns an iterator that traverses the image in a row-major ordering, yielding each pixel
+  /// along with its coordinate as `(x, y, pixel)`.
+  ///
+  pub fn enumerate_pixels(&self) -> impl Iterator<Item = (usize, usize, [T; 4])> + '_ {
+    let w = self.w;
+    self
+      .rgba_iter()
+      .enumerate()
+      .map(move |(idx, pixel)| (idx % w, idx / w, pixel))
+  }
+
+  /// `sample` reads the image at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> [T; 4] {
+    self.view().sample(x, y, interpolation, border)
+  }
+}
+
+impl Image<u8> {
+  /// `to_linear` will take the input 8-bit `sRGBA` image and convert its color channels to their linear floating point
+  /// representation. The alpha channel is not gamma-encoded and is only normalized to the range `[0.0, 1.0]`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Image<f32>) {
+    out.resize(self.w, self.h);
+    self.view().to_linear(&mut out.view_mut());
+  }
+}
+
+impl Image<f32> {
+  /// `to_srgb` will take the input linear floating point image and convert its color channels to their 8-bit `sRGB`
+  /// representation. The alpha channel is not gamma-encoded and is only scaled from the range `[0.0, 1.0]`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_srgb(&self, out: &mut Image<u8>) {
+    out.resize(self.w, self.h);
+    self.view().to_srgb(&mut out.view_mut());
+  }
+
+  /// `encode` applies the supplied transfer `curve` to the linear color channels of the image, scaling every encoded
+  /// channel value by `U::SCALE` and storing the saturated result in `out`. The alpha channel is not gamma-encoded and
+  /// is only scaled by `U::SCALE`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut Image<U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    out.resize(self.w, self.h);
+    self.view().encode(curve, &mut out.view_mut());
+  }
+}
+
+impl<'a, T> std::convert::From<&'a Image<T>> for ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a Image<T>) -> Self {
+    img.view()
+  }
+}
+
+impl<'a, T> std::convert::From<&'a mut Image<T>> for ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  fn from(img: &'a mut Image<T>) -> Self {
+    img.view_mut()
+  }
+}
+
+/// `ImageRef` is a borrowed, read-only view of `RGBA` image data that is owned elsewhere, such as by a driver's ring
+/// buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+#[derive(Clone, Copy)]
+pub struct ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  r: &'a [T],
+  g: &'a [T],
+  b: &'a [T],
+  a: &'a [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageRef<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a view over the supplied row-major planes where each row begins `stride` elements after the
+  /// start of the previous one.
+  ///
+  /// Each plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if any of the
+  /// planes are too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  pub fn new(
+    r: &'a [T],
+    g: &'a [T],
+    b: &'a [T],
+    a: &'a [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+  ) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+
+    crate::strided::check_view_plane(r, len)?;
+    crate::strided::check_view_plane(g, len)?;
+    crate::strided::check_view_plane(b, len)?;
+    crate::strided::check_view_plane(a, len)?;
+
+    Ok(Self {
+      r: &r[..len],
+      g: &g[..len],
+      b: &b[..len],
+      a: &a[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `r` returns the view's red channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn r(&self) -> &'a [T] {
+    self.r
+  }
+
+  /// `g` returns the view's green channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn g(&self) -> &'a [T] {
+    self.g
+  }
+
+  /// `b` returns the view's blue channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn b(&self) -> &'a [T] {
+    self.b
+  }
+
+  /// `a` returns the view's alpha channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn a(&self) -> &'a [T] {
+    self.a
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view, equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgba_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and
+  /// yielding each pixel as a `[T; 4]`.
+  ///
+  pub fn rgba_iter(&self) -> impl Iterator<Item = [T; 4]> + 'a {
+    self.rows().flat_map(|[r, g, b, a]| make_iter(r, g, b, a))
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and yielding
+  /// each pixel as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [T; 3]> + 'a {
+    self
+      .rows()
+      .flat_map(|[r, g, b, _]| crate::rgb::make_iter(r, g, b))
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `[&[T]; 4]` in `RGBA` ordering that
+  /// excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'a [T]; 4]> + 'a {
+    let (w, h, stride) = (self.w, self.h, self.stride);
+
+    crate::strided::Rows::new(self.r, w, h, stride)
+      .zip(crate::strided::Rows::new(self.g, w, h, stride))
+      .zip(crate::strided::Rows::new(self.b, w, h, stride))
+      .zip(crate::strided::Rows::new(self.a, w, h, stride))
+      .map(|(((r, g), b), a)| [r, g, b, a])
+  }
+
+  /// `roi` returns a view of the `width * height` rectangle whose top-left corner is at column `x` and row `y` of this
+  /// view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageRef {
+      r: &self.r[range.clone()],
+      g: &self.g[range.clone()],
+      b: &self.b[range.clone()],
+      a: &self.a[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `convert_to` converts the viewed data into a different sample type, computing `saturate(x * scale + offset)` for
+  /// every channel value, including alpha, and storing the result in `out`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn convert_to<U>(&self, out: &mut ImageMut<'_, U>, scale: f64, offset: f64)
+  where
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: T| crate::convert_sample(x, scale, offset);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+    crate::strided::map_plane(self.a, self.stride, out.a, out.stride, dims, f);
+  }
+
+  /// `decode` linearizes the viewed color channels with the supplied transfer `curve`, normalizing every channel value
+  /// by `T::SCALE` first and storing the result in `out`. The alpha channel is only normalized to the range
+  /// `[0.0, 1.0]`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn decode<F>(&self, curve: &F, out: &mut ImageMut<'_, f32>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: T| crate::convert::transfer::decode_sample(curve, x);
+    let alpha = |x: T| crate::convert_sample(x, 1.0 / T::SCALE, 0.0);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+    crate::strided::map_plane(self.a, self.stride, out.a, out.stride, dims, alpha);
+  }
+}
+
+impl ImageRef<'_, u8> {
+  /// `to_linear` will take the viewed 8-bit `sRGBA` data and convert its color channels to their linear floating point
+  /// representation, storing the result in `out`. The alpha channel is only normalized to the range `[0.0, 1.0]`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_linear(&self, out: &mut ImageMut<'_, f32>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = crate::convert::kernels::srgb_to_linear;
+
+    crate::strided::map_rows(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_rows(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_rows(self.b, self.stride, out.b, out.stride, dims, f);
+    crate::strided::map_rows(
+      self.a,
+      self.stride,
+      out.a,
+      out.stride,
+      dims,
+      crate::convert::kernels::u8_to_f32,
+    );
+  }
+}
+
+impl ImageRef<'_, f32> {
+  /// `to_srgb` will take the viewed linear floating point data and convert its color channels to their 8-bit `sRGB`
+  /// representation, storing the result in `out`. The alpha channel is only scaled from the range `[0.0, 1.0]`,
+  /// saturating values that lie outside of it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn to_srgb(&self, out: &mut ImageMut<'_, u8>) {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = crate::convert::kernels::linear_to_srgb;
+
+    crate::strided::map_rows(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_rows(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_rows(self.b, self.stride, out.b, out.stride, dims, f);
+    crate::strided::map_rows(
+      self.a,
+      self.stride,
+      out.a,
+      out.stride,
+      dims,
+      crate::convert::kernels::f32_to_u8,
+    );
+  }
+
+  /// `encode` applies the supplied transfer `curve` to the viewed linear color channels, scaling every encoded channel
+  /// value by `U::SCALE` and storing the saturated result in `out`. The alpha channel is only scaled by `U::SCALE`,
+  /// saturating values that lie outside of the target range.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `out` does not have the same width and height as `self`.
+  ///
+  pub fn encode<F, U>(&self, curve: &F, out: &mut ImageMut<'_, U>)
+  where
+    F: crate::convert::transfer::TransferFunction + ?Sized,
+    U: Numeric,
+  {
+    assert!(
+      self.w == out.w && self.h == out.h,
+      "output dimensions must match the input"
+    );
+
+    let dims = (self.w, self.h);
+    let f = |x: f32| crate::convert::transfer::encode_sample(curve, x);
+    let alpha = |x: f32| crate::convert_sample(x, U::SCALE, 0.0);
+
+    crate::strided::map_plane(self.r, self.stride, out.r, out.stride, dims, f);
+    crate::strided::map_plane(self.g, self.stride, out.g, out.stride, dims, f);
+    crate::strided::map_plane(self.b, self.stride, out.b, out.stride, dims, f);
+    crate::strided::map_plane(self.a, self.stride, out.a, out.stride, dims, alpha);
+  }
+}
+
+impl<T> ImageRef<'_, T>
+where
+  T: Numeric,
+{
+  /// `get` returns the pixel at column `x` and row `y` of the view in `RGBA` ordering, or `None` if the coordinate lies
+  /// outside of the view.
+  ///
+  #[must_use]
+  pub fn get(&self, x: usize, y: usize) -> Option<[T; 4]> {
+    if x >= self.w || y >= self.h {
+      return None;
+    }
+
+    let idx = y * self.stride + x;
+    Some([self.r[idx], self.g[idx], self.b[idx], self.a[idx]])
+  }
+
+  /// `sample` reads the view at the floating point coordinate `(x, y)`, see
+  /// [`sample_plane`](crate::sample::sample_plane) for details.
+  ///
+  #[must_use]
+  pub fn sample(
+    &self,
+    x: f32,
+    y: f32,
+    interpolation: crate::sample::Interpolation,
+    border: crate::sample::Border<T>,
+  ) -> [T; 4] {
+    let dims = (self.w, self.h, self.stride);
+    [self.r, self.g, self.b, self.a]
+      .map(|plane| crate::sample::sample_plane(plane, dims, (x, y), interpolation, border))
+  }
+}
+
+/// `ImageMut` is a borrowed, mutable view of `RGBA` image data that is owned elsewhere, such as by a driver's ring
+/// buffer or by an [`Image`].
+///
+/// Each row of the view begins `stride` elements after the start of the previous one, which enables views of padded
+/// buffers without any copying.
+///
+pub struct ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  r: &'a mut [T],
+  g: &'a mut [T],
+  b: &'a mut [T],
+  a: &'a mut [T],
+  h: usize,
+  w: usize,
+  stride: usize,
+}
+
+impl<'a, T> ImageMut<'a, T>
+where
+  T: Numeric,
+{
+  /// `new` constructs a mutable view over the supplied row-major planes where each row begins `stride` elements after
+  /// the start of the previous one.
+  ///
+  /// Each plane must contain at least `(height - 1) * stride + width` number of elements. Any trailing elements beyond
+  /// that are excluded from the view.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidStride` if `stride` is less than `width`, an `Error::InvalidPlaneLength` if any of the
+  /// planes are too short and an `Error::DimensionOverflow` if the required plane length overflows.
+  ///
+  pub fn new(
+    r: &'a mut [T],
+    g: &'a mut [T],
+    b: &'a mut [T],
+    a: &'a mut [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+  ) -> Result<Self, crate::Error> {
+    let len = crate::strided::view_len(width, height, stride)?;
+
+    crate::strided::check_view_plane(r, len)?;
+    crate::strided::check_view_plane(g, len)?;
+    crate::strided::check_view_plane(b, len)?;
+    crate::strided::check_view_plane(a, len)?;
+
+    Ok(Self {
+      r: &mut r[..len],
+      g: &mut g[..len],
+      b: &mut b[..len],
+      a: &mut a[..len],
+      h: height,
+      w: width,
+      stride,
+    })
+  }
+
+  /// `view` returns a read-only [`ImageRef`] of the same data.
+  ///
+  #[must_use]
+  pub fn view(&self) -> ImageRef<'_, T> {
+    ImageRef {
+      r: self.r,
+      g: self.g,
+      b: self.b,
+      a: self.a,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `view_mut` reborrows the view, returning an `ImageMut` with a shorter lifetime.
+  ///
+  pub fn view_mut(&mut self) -> ImageMut<'_, T> {
+    ImageMut {
+      r: self.r,
+      g: self.g,
+      b: self.b,
+      a: self.a,
+      h: self.h,
+      w: self.w,
+      stride: self.stride,
+    }
+  }
+
+  /// `r` returns the view's red channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn r(&self) -> &[T] {
+    self.r
+  }
+
+  /// `g` returns the view's green channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn g(&self) -> &[T] {
+    self.g
+  }
+
+  /// `b` returns the view's blue channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn b(&self) -> &[T] {
+    self.b
+  }
+
+  /// `a` returns the view's alpha channel as a `&[T]`, including any padding elements between rows.
+  ///
+  #[must_use]
+  pub fn a(&self) -> &[T] {
+    self.a
+  }
+
+  /// `rgba_mut` returns a tuple containing mutable references to the viewed data in `RGBA` ordering, including any
+  /// padding elements between rows.
+  ///
+  pub fn rgba_mut(&mut self) -> (&mut [T], &mut [T], &mut [T], &mut [T]) {
+    (self.r, self.g, self.b, self.a)
+  }
+
+  /// `width` returns the number of columns in the view.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the view.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `stride` returns the number of elements between the start of one row and the start of the next.
+  ///
+  #[must_use]
+  pub fn stride(&self) -> usize {
+    self.stride
+  }
+
+  /// `total` returns the total number of pixels in the view, equivalent to `img.width() * img.height()`.
+  ///
+  #[must_use]
+  pub fn total(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// `rgba_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and
+  /// yielding each pixel as a `[T; 4]`.
+  ///
+  pub fn rgba_iter(&self) -> impl Iterator<Item = [T; 4]> + '_ {
+    self.view().rgba_iter()
+  }
+
+  /// `rgb_iter` returns an iterator that traverses the view in a row-major ordering, skipping any padding and yielding
+  /// each pixel as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> impl Iterator<Item = [T; 3]> + '_ {
+    self.view().rgb_iter()
+  }
+
+  /// `rgba_iter_mut` returns an iterator that traverses the view in a row-major ordering, skipping any padding and
+  /// yielding each pixel as a `[&mut T; 4]` so that the underlying pixel values can be manipulated.
+  ///
+  pub fn rgba_iter_mut(&mut self) -> impl Iterator<Item = [&'_ mut T; 4]> + '_ {
+    self
+      .rows_mut()
+      .flat_map(|[r, g, b, a]| make_iter_mut(r, g, b, a))
+  }
+
+  /// `rows` returns an iterator over the rows of the view, yielding each one as a `[&[T]; 4]` in `RGBA` ordering that
+  /// excludes any padding.
+  ///
+  pub fn rows(&self) -> impl Iterator<Item = [&'_ [T]; 4]> + '_ {
+    self.view().rows()
+  }
+
+  /// `rows_mut` returns an iterator over the rows of the view, yielding each one as a `[&mut [T]; 4]` in `RGBA`
+  /// ordering that excludes any padding.
+  ///
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = [&'_ mut [T]; 4]> + '_ {
+    let (w, h, stride) = (self.w, self.h, self.stride);
+
+    crate::strided::RowsMut::new(self.r, w, h, stride)
+      .zip(crate::strided::RowsMut::new(self.g, w, h, stride))
+      .zip(crate::strided::RowsMut::new(self.b, w, h, stride))
+      .zip(crate::strided::RowsMut::new(self.a, w, h, stride))
+      .map(|(((r, g), b), a)| [r, g, b, a])
+  }
+
+  /// `roi` returns a read-only view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi(
+    &self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageRef<'_, T>, crate::Error> {
+    self.view().roi(x, y, width, height)
+  }
+
+  /// `roi_mut` returns a mutable view of the `width * height` rectangle whose top-left corner is at column `x` and row
+  /// `y` of this view. No image data is copied.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn roi_mut(
+    &mut self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'_, T>, crate::Error> {
+    self.view_mut().into_roi(x, y, width, height)
+  }
+
+  /// `into_roi` consumes the view, narrowing it to the `width * height` rectangle whose top-left corner is at column
+  /// `x` and row `y`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidRoi` if the rectangle does not lie entirely within the view.
+  ///
+  pub fn into_roi(
+    self,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+  ) -> Result<ImageMut<'a, T>, crate::Error> {
+    let range = crate::strided::roi_range((x, y, width, height), (self.w, self.h, self.stride))?;
+
+    Ok(ImageMut {
+      r: &mut self.r[range.clone()],
+      g: &mut self.g[range.clone()],
+      b: &mut self.b[range.clone()],
+      a: &mut self.a[range],
+      h: height,
+      w: width,
+      stride: self.stride,
+    })
+  }
+
+  /// `into_split_rows` consumes the view, dividing it into `n` disjoint horizontal bands of consecutive rows. Band
+  /// heights differ by at most one, with any remainder going to the leading bands.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  #[must_use]
+  pub fn into_split_rows(self, n: usize) -> Vec<ImageMut<'a, T>> {
+    let heights = crate::strided::band_heights(self.h, n);
+    let (w, stride) = (self.w, self.stride);
+
+    let red = crate::strided::split_plane_mut(self.r, w, stride, &heights);
+    let green = crate::strided::split_plane_mut(self.g, w, stride, &heights);
+    let blue = crate::strided::split_plane_mut(self.b, w, stride, &heights);
+    let alpha = crate::strided::split_plane_mut(self.a, w, stride, &heights);
+
+    red
+      .into_iter()
+      .zip(green)
+      .zip(blue)
+      .zip(alpha)
+      .zip(heights)
+      .map(|((((red, green), blue), alpha), h)| ImageMut {
+        r: red,
+        g: green,
+        b: blue,
+        a: alpha,
+        h,
+        w,
+        stride,
+      })
+      .collect()
+  }
+
+  /// `split_rows_mut` divides the view into `n` disjoint horizontal bands of consecutive rows, suitable for handing to
+  /// separate worker threads. Band heights differ by at most one, with any remainder going to the leading bands, so
+  /// bands may be empty when `n` exceeds the number of rows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  ///
+  pub fn split_rows_mut(&mut self, n: usize) -> Vec<ImageMut<'_, T>> {
+    self.view_mut().into_split_rows(n)
+  }
 }
 
 /// `make_iter` returns an iterator that traverses the planar image data in a row-major ordering, yielding each pixel
@@ -116,3 +1128,112 @@ pub fn make_iter_mut<'a, T: Numeric>(
     .zip(a.iter_mut())
     .map(|(((x, y), z), w)| [x, y, z, w])
 }
+
+/// `cvt_rgba_to_rgb` flattens the supplied `RGBA` image onto an opaque `background` color, storing the result in `y`.
+///
+/// Each channel is blended as `a * c + (1 - a) * background` where `a` is the pixel's straight (non-premultiplied)
+/// alpha normalized by `T::SCALE` and clamped to `[0.0, 1.0]`. The blend is carried out on the values as supplied, so
+/// linear data should be used for physically correct results. Integral results are rounded to the nearest value.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+/// # Example
+/// ```
+/// let img =
+///   cvr::rgba::Image::from_vec(vec![1.0_f32, 0.5], vec![0.0, 0.5], vec![0.0, 0.5], vec![0.25, 1.0], 2, 1)
+///     .unwrap();
+///
+/// let mut rgb = cvr::rgb::Image::new();
+/// cvr::rgba::cvt_rgba_to_rgb(&img, &mut rgb, [0.0, 1.0, 0.0]);
+///
+/// assert_eq!(rgb.r(), [0.25, 0.5]);
+/// assert_eq!(rgb.g(), [0.75, 0.5]);
+/// ```
+///
+pub fn cvt_rgba_to_rgb<T>(x: &Image<T>, y: &mut crate::rgb::Image<T>, background: [T; 3])
+where
+  T: Numeric,
+{
+  let [br, bg, bb] = background.map(T::to_f64);
+
+  y.resize(x.width(), x.height());
+  y.rgb_iter_mut()
+    .zip(x.rgba_iter())
+    .for_each(|([out_r, out_g, out_b], [r, g, b, a])| {
+      let a = (a.to_f64() / T::SCALE).clamp(0.0, 1.0);
+      let blend = |c: T, bg: f64| T::saturating_from_f64(a * c.to_f64() + (1.0 - a) * bg);
+
+      *out_r = blend(r, br);
+      *out_g = blend(g, bg);
+      *out_b = blend(b, bb);
+    });
+}
+
+/// `cvt_premultiply` converts the supplied straight alpha image to premultiplied alpha, storing the result in `y`.
+///
+/// Each color channel is scaled by the pixel's alpha normalized by `T::SCALE` and clamped to `[0.0, 1.0]`, while the
+/// alpha channel is copied unchanged. As with blending, linear data should be used for physically correct results.
+/// Integral results are rounded to the nearest value.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+/// # Example
+/// ```
+/// let img = cvr::rgba::Image::from_vec(vec![200_u8], vec![100], vec![0], vec![128], 1, 1).unwrap();
+///
+/// let mut premultiplied = cvr::rgba::Image::new();
+/// cvr::rgba::cvt_premultiply(&img, &mut premultiplied);
+///
+/// assert_eq!(premultiplied.rgba_iter().next(), Some([100, 50, 0, 128]));
+/// ```
+///
+pub fn cvt_premultiply<T>(x: &Image<T>, y: &mut Image<T>)
+where
+  T: Numeric,
+{
+  y.resize(x.width(), x.height());
+  y.rgba_iter_mut()
+    .zip(x.rgba_iter())
+    .for_each(|([out_r, out_g, out_b, out_a], [r, g, b, a])| {
+      let alpha = (a.to_f64() / T::SCALE).clamp(0.0, 1.0);
+      let scale = |c: T| T::saturating_from_f64(c.to_f64() * alpha);
+
+      *out_r = scale(r);
+      *out_g = scale(g);
+      *out_b = scale(b);
+      *out_a = a;
+    });
+}
+
+/// `cvt_unpremultiply` converts the supplied premultiplied alpha image back to straight alpha, storing the result in
+/// `y`.
+///
+/// Each color channel is divided by the pixel's alpha normalized by `T::SCALE` and clamped to `[0.0, 1.0]`, while the
+/// alpha channel is copied unchanged. Fully transparent pixels carry no color and become zero. Integral results are
+/// rounded to the nearest value and saturated.
+///
+/// If `y` is not appropriately sized, it will be resized accordingly.
+///
+pub fn cvt_unpremultiply<T>(x: &Image<T>, y: &mut Image<T>)
+where
+  T: Numeric,
+{
+  y.resize(x.width(), x.height());
+  y.rgba_iter_mut()
+    .zip(x.rgba_iter())
+    .for_each(|([out_r, out_g, out_b, out_a], [r, g, b, a])| {
+      let alpha = (a.to_f64() / T::SCALE).clamp(0.0, 1.0);
+      let scale = |c: T| {
+        if alpha > 0.0 {
+          T::saturating_from_f64(c.to_f64() / alpha)
+        } else {
+          T::saturating_from_f64(0.0)
+        }
+      };
+
+      *out_r = scale(r);
+      *out_g = scale(g);
+      *out_b = scale(b);
+      *out_a = a;
+    });
+}
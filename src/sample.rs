@@ -0,0 +1,176 @@
+//! `sample` contains routines for reading image planes at floating point coordinates, along with the policies that
+//! control how samples that fall outside of the image are handled.
+//!
+//! Integral coordinates refer to pixel centers, so sampling at `(1.0, 2.0)` returns the pixel in column `1` and row
+//! `2` exactly while sampling at `(1.5, 2.0)` blends it with its right-hand neighbor.
+//!
+//! # Example
+//! ```
+//! use cvr::sample::{Border, Interpolation};
+//!
+//! let img = cvr::gray::Image::from_vec(vec![0.0_f32, 1.0, 2.0, 3.0], 2, 2).unwrap();
+//!
+//! assert_eq!(img.sample(0.5, 0.5, Interpolation::Bilinear, Border::Replicate), 1.5);
+//! assert_eq!(img.sample(-1.0, 0.0, Interpolation::Nearest, Border::Constant(9.0)), 9.0);
+//! assert_eq!(img.sample(2.0, 1.0, Interpolation::Nearest, Border::Wrap), 2.0);
+//! ```
+//!
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_possible_wrap,
+  clippy::cast_sign_loss
+)]
+
+use crate::Numeric;
+use std::convert::TryFrom;
+
+/// `Border` determines the value of samples that fall outside of the image.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Border<T> {
+  /// `Constant` treats every pixel outside of the image as the supplied value.
+  ///
+  Constant(T),
+
+  /// `Replicate` repeats the outermost pixels of the image, `aaa|abcd|ddd`.
+  ///
+  Replicate,
+
+  /// `Reflect` mirrors the image about its edges, including the edge pixels themselves, `cba|abcd|dcb`.
+  ///
+  Reflect,
+
+  /// `Wrap` tiles the image, `bcd|abcd|abc`.
+  ///
+  Wrap,
+}
+
+/// `Interpolation` determines how the pixels surrounding a floating point coordinate are combined.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+  /// `Nearest` returns the pixel whose center is closest to the coordinate.
+  ///
+  Nearest,
+
+  /// `Bilinear` linearly blends the 2x2 neighborhood surrounding the coordinate.
+  ///
+  Bilinear,
+
+  /// `Bicubic` blends the 4x4 neighborhood surrounding the coordinate using the Catmull-Rom cubic convolution kernel.
+  /// The result can overshoot the range of its inputs near edges and is saturated for integral sample types.
+  ///
+  Bicubic,
+}
+
+/// `sample_plane` reads the strided plane at the floating point coordinate `(x, y)`, combining the neighboring pixels
+/// according to `interpolation` and handling out-of-bounds pixels according to `border`.
+///
+/// `dims` is the `(width, height, stride)` of the plane, which must contain at least `(height - 1) * stride + width`
+/// number of elements. Empty planes sample as the `Border::Constant` value, or `T::default()` for every other border
+/// mode.
+///
+/// # Panics
+///
+/// Panics if `plane` is too short for the supplied dimensions.
+///
+#[must_use]
+pub fn sample_plane<T>(
+  plane: &[T],
+  dims: (usize, usize, usize),
+  (x, y): (f32, f32),
+  interpolation: Interpolation,
+  border: Border<T>,
+) -> T
+where
+  T: Numeric,
+{
+  let (width, height, stride) = dims;
+
+  let fetch =
+    |col: isize, row: isize| match (resolve(col, width, &border), resolve(row, height, &border)) {
+      (Some(col), Some(row)) => plane[row * stride + col].to_f64(),
+      _ => match border {
+        Border::Constant(value) => value.to_f64(),
+        _ => T::default().to_f64(),
+      },
+    };
+
+  // coordinates beyond the range of `isize` saturate, and the offsets to their neighbors saturate along with them,
+  // while infinite coordinates have no fractional part
+  //
+  let fract = |t: f64| if t.is_finite() { t } else { 0.0 };
+  let at = |base: isize, offset: isize| base.saturating_add(offset);
+
+  let (x, y) = (f64::from(x), f64::from(y));
+  let (x0, y0) = (x.floor(), y.floor());
+  let (fx, fy) = (fract(x - x0), fract(y - y0));
+  let (x0, y0) = (x0 as isize, y0 as isize);
+
+  let value = match interpolation {
+    Interpolation::Nearest => fetch(x.round() as isize, y.round() as isize),
+    Interpolation::Bilinear => {
+      let (x1, y1) = (at(x0, 1), at(y0, 1));
+
+      let top = (1.0 - fx) * fetch(x0, y0) + fx * fetch(x1, y0);
+      let bottom = (1.0 - fx) * fetch(x0, y1) + fx * fetch(x1, y1);
+
+      (1.0 - fy) * top + fy * bottom
+    }
+    Interpolation::Bicubic => {
+      let (wx, wy) = (cubic_weights(fx), cubic_weights(fy));
+
+      (0..4)
+        .map(|j| {
+          let row = (0..4)
+            .map(|i| wx[i] * fetch(at(x0, i as isize - 1), at(y0, j as isize - 1)))
+            .sum::<f64>();
+
+          wy[j] * row
+        })
+        .sum()
+    }
+  };
+
+  T::saturating_from_f64(value)
+}
+
+/// `resolve` maps a possibly out-of-bounds index along an axis of length `len` to an in-bounds one, returning `None`
+/// when the sample should take on the constant border value instead.
+///
+fn resolve<T>(idx: isize, len: usize, border: &Border<T>) -> Option<usize> {
+  let n = isize::try_from(len).ok().filter(|&n| n > 0)?;
+
+  if (0..n).contains(&idx) {
+    return Some(idx as usize);
+  }
+
+  let idx = match border {
+    Border::Constant(_) => return None,
+    Border::Replicate => idx.clamp(0, n - 1),
+    Border::Reflect => {
+      let m = idx.rem_euclid(2 * n);
+      if m < n {
+        m
+      } else {
+        2 * n - 1 - m
+      }
+    }
+    Border::Wrap => idx.rem_euclid(n),
+  };
+
+  Some(idx as usize)
+}
+
+/// `cubic_weights` returns the Catmull-Rom weights of the four pixels at offsets `-1, 0, 1, 2` from the sample whose
+/// fractional offset is `t`.
+///
+fn cubic_weights(t: f64) -> [f64; 4] {
+  const A: f64 = -0.5;
+
+  let near = |d: f64| ((A + 2.0) * d - (A + 3.0)) * d * d + 1.0;
+  let far = |d: f64| ((A * d - 5.0 * A) * d + 8.0 * A) * d - 4.0 * A;
+
+  [far(1.0 + t), near(t), near(1.0 - t), far(2.0 - t)]
+}
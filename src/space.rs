@@ -0,0 +1,621 @@
+//! `space` contains zero-sized markers that tag images and iterators with the color space their data is encoded in.
+//!
+//! Nothing about an `rgb::Image<f32>` says whether its values are linear or still `sRGB`-encoded, which makes it easy
+//! to feed gamma-encoded data to routines like [`linear_to_gray`](crate::convert::linear_to_gray). Wrapping data in a
+//! [`Tagged`] value records its color space in the type system so that conversions such as `to_linear`, `to_srgb` and
+//! the lazy iterator adapters only accept correctly tagged input and always produce correctly tagged output.
+//!
+//! [`Tagged::new`] and [`Tagged::into_inner`] act as untagged escape hatches for interoperating with the rest of the
+//! crate.
+//!
+//! # Example
+//! ```
+//! use cvr::space::{LinearSrgb, Srgb, Tagged};
+//!
+//! let img = cvr::rgb::Image::from_vec(vec![255_u8], vec![128], vec![0], 1, 1).unwrap();
+//! let img = Tagged::<_, Srgb>::new(img);
+//!
+//! let mut linear = Tagged::<cvr::rgb::Image<f32>, LinearSrgb>::default();
+//! img.to_linear(&mut linear);
+//!
+//! let mut srgb = Tagged::default();
+//! linear.to_srgb(&mut srgb);
+//! assert!(srgb == img);
+//!
+//! // `sRGB`-encoded pixels must be linearized before they can be reduced to their luminance
+//! //
+//! let luminance: Vec<f32> = img.rgb_iter().srgb_to_linear().linear_to_gray().into_iter().collect();
+//! assert_eq!(luminance.len(), 1);
+//! ```
+//!
+
+use crate::convert::iter::{
+  HSILinearIterator, HSLLinearIterator, HSVLinearIterator, LabLinearIterator, LinearGrayIterator,
+  LinearHSIIterator, LinearHSLIterator, LinearHSVIterator, LinearLabIterator, LinearLuvIterator,
+  LinearOklabIterator, LinearOklchIterator, LinearSRGBIterator, LinearXYZIterator,
+  LuvLinearIterator, OklabLinearIterator, OklchLinearIterator, SRGBLinearIterator,
+  XYZLinearIterator,
+};
+use crate::convert::CieReference;
+use crate::{gray, rgb, rgba, Numeric};
+
+/// `ColorSpace` is implemented by the marker types that describe how the values of an image or iterator are encoded.
+///
+pub trait ColorSpace: Copy + Default {
+  /// A human-readable name for the color space.
+  const NAME: &'static str;
+}
+
+/// `Srgb` marks data that is encoded with the `sRGB` transfer function, such as the contents of most `.png` files.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Srgb;
+
+/// `LinearSrgb` marks data in the linear `sRGB` color space, which supports linear math.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinearSrgb;
+
+/// `Hsv` marks data in the `HSV` color space, stored in `(H, S, V)` ordering with `H` in the range `[0.0, 360.0]`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hsv;
+
+/// `Hsl` marks data in the `HSL` color space, stored in `(H, S, L)` ordering with `H` in the range `[0.0, 360.0]`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hsl;
+
+/// `Hsi` marks data in the `HSI` color space, stored in `(H, S, I)` ordering with `H` in the range `[0.0, 360.0]`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hsi;
+
+/// `Xyz` marks data in the CIE `XYZ` color space, relative to the `D65` white point.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Xyz;
+
+/// `Lab` marks data in the CIELAB color space, stored in `(L*, a*, b*)` ordering relative to the `D65` white point.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Lab;
+
+/// `Luv` marks data in the CIELUV color space, stored in `(L*, u*, v*)` ordering relative to the `D65` white point.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Luv;
+
+/// `Oklab` marks data in the Oklab color space, stored in `(L, a, b)` ordering.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Oklab;
+
+/// `Oklch` marks data in the `OkLCh` color space, stored in `(L, C, h)` ordering with `h` in the range `[0.0, 360.0)`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Oklch;
+
+impl ColorSpace for Srgb {
+  const NAME: &'static str = "sRGB";
+}
+
+impl ColorSpace for LinearSrgb {
+  const NAME: &'static str = "linear sRGB";
+}
+
+impl ColorSpace for Hsv {
+  const NAME: &'static str = "HSV";
+}
+
+impl ColorSpace for Hsl {
+  const NAME: &'static str = "HSL";
+}
+
+impl ColorSpace for Hsi {
+  const NAME: &'static str = "HSI";
+}
+
+impl ColorSpace for Xyz {
+  const NAME: &'static str = "CIE XYZ";
+}
+
+impl ColorSpace for Lab {
+  const NAME: &'static str = "CIELAB";
+}
+
+impl ColorSpace for Luv {
+  const NAME: &'static str = "CIELUV";
+}
+
+impl ColorSpace for Oklab {
+  const NAME: &'static str = "Oklab";
+}
+
+impl ColorSpace for Oklch {
+  const NAME: &'static str = "OkLCh";
+}
+
+/// `Tagged` wraps an image or iterator, recording the color space `S` its data is encoded in.
+///
+/// `Tagged` intentionally does not dereference to the wrapped value, as doing so would expose the untagged
+/// conversions. Use [`Tagged::inner`], [`Tagged::inner_mut`] or [`Tagged::into_inner`] to opt out of the tagging.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tagged<I, S>
+where
+  S: ColorSpace,
+{
+  inner: I,
+  space: S,
+}
+
+impl<I, S> Tagged<I, S>
+where
+  S: ColorSpace,
+{
+  /// `new` tags the supplied value as being encoded in the color space `S`. The caller is responsible for the tag
+  /// being accurate.
+  ///
+  pub fn new(inner: I) -> Self {
+    Self {
+      inner,
+      space: S::default(),
+    }
+  }
+
+  /// `inner` returns an immutable reference to the untagged value.
+  ///
+  pub fn inner(&self) -> &I {
+    &self.inner
+  }
+
+  /// `inner_mut` returns a mutable reference to the untagged value.
+  ///
+  pub fn inner_mut(&mut self) -> &mut I {
+    &mut self.inner
+  }
+
+  /// `into_inner` discards the tag, returning the untagged value.
+  ///
+  pub fn into_inner(self) -> I {
+    self.inner
+  }
+
+  /// `space` returns the name of the color space the value is tagged with.
+  ///
+  #[must_use]
+  pub fn space(&self) -> &'static str {
+    S::NAME
+  }
+}
+
+impl<I, S> IntoIterator for Tagged<I, S>
+where
+  I: Iterator,
+  S: ColorSpace,
+{
+  type Item = I::Item;
+  type IntoIter = I;
+
+  fn into_iter(self) -> I {
+    self.inner
+  }
+}
+
+impl<I> Tagged<I, Srgb>
+where
+  I: Iterator<Item = [u8; 3]>,
+{
+  /// `srgb_to_linear` lazily converts the tagged `sRGB` pixels to linear floating point.
+  ///
+  pub fn srgb_to_linear(self) -> Tagged<crate::convert::iter::SRGBToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.srgb_to_linear())
+  }
+}
+
+impl<I> Tagged<I, LinearSrgb>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `linear_to_srgb` lazily converts the tagged linear pixels to their 8-bit `sRGB` representation.
+  ///
+  pub fn linear_to_srgb(self) -> Tagged<crate::convert::iter::LinearToSRGB<I>, Srgb> {
+    Tagged::new(self.inner.linear_to_srgb())
+  }
+
+  /// `linear_to_gray` lazily converts the tagged linear pixels to their luminance, which remains linear.
+  ///
+  pub fn linear_to_gray(self) -> Tagged<crate::convert::iter::LinearToGray<I>, LinearSrgb> {
+    Tagged::new(self.inner.linear_to_gray())
+  }
+
+  /// `linear_to_hsv` lazily converts the tagged linear pixels to `HSV`.
+  ///
+  pub fn linear_to_hsv(self) -> Tagged<crate::convert::iter::LinearToHSV<I>, Hsv> {
+    Tagged::new(self.inner.linear_to_hsv())
+  }
+
+  /// `linear_to_hsl` lazily converts the tagged linear pixels to `HSL`.
+  ///
+  pub fn linear_to_hsl(self) -> Tagged<crate::convert::iter::LinearToHSL<I>, Hsl> {
+    Tagged::new(self.inner.linear_to_hsl())
+  }
+
+  /// `linear_to_hsi` lazily converts the tagged linear pixels to `HSI`.
+  ///
+  pub fn linear_to_hsi(self) -> Tagged<crate::convert::iter::LinearToHSI<I>, Hsi> {
+    Tagged::new(self.inner.linear_to_hsi())
+  }
+
+  /// `linear_to_xyz` lazily converts the tagged linear pixels to CIE `XYZ`.
+  ///
+  pub fn linear_to_xyz(self) -> Tagged<crate::convert::iter::LinearToXYZ<I>, Xyz> {
+    Tagged::new(self.inner.linear_to_xyz())
+  }
+
+  /// `linear_to_lab` lazily converts the tagged linear pixels to CIELAB.
+  ///
+  pub fn linear_to_lab(self) -> Tagged<crate::convert::iter::LinearToLab<I>, Lab> {
+    Tagged::new(self.inner.linear_to_lab())
+  }
+
+  /// `linear_to_luv` lazily converts the tagged linear pixels to CIELUV.
+  ///
+  pub fn linear_to_luv(self) -> Tagged<crate::convert::iter::LinearToLuv<I>, Luv> {
+    Tagged::new(self.inner.linear_to_luv())
+  }
+
+  /// `linear_to_oklab` lazily converts the tagged linear pixels to Oklab.
+  ///
+  pub fn linear_to_oklab(self) -> Tagged<crate::convert::iter::LinearToOklab<I>, Oklab> {
+    Tagged::new(self.inner.linear_to_oklab())
+  }
+
+  /// `linear_to_oklch` lazily converts the tagged linear pixels to `OkLCh`.
+  ///
+  pub fn linear_to_oklch(self) -> Tagged<crate::convert::iter::LinearToOklch<I>, Oklch> {
+    Tagged::new(self.inner.linear_to_oklch())
+  }
+}
+
+impl<I> Tagged<I, Hsv>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `hsv_to_linear` lazily converts the tagged `HSV` pixels back to linear `RGB`.
+  ///
+  pub fn hsv_to_linear(self) -> Tagged<crate::convert::iter::HSVToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.hsv_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Hsl>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `hsl_to_linear` lazily converts the tagged `HSL` pixels back to linear `RGB`.
+  ///
+  pub fn hsl_to_linear(self) -> Tagged<crate::convert::iter::HSLToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.hsl_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Hsi>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `hsi_to_linear` lazily converts the tagged `HSI` pixels back to linear `RGB`.
+  ///
+  pub fn hsi_to_linear(self) -> Tagged<crate::convert::iter::HSIToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.hsi_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Xyz>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `xyz_to_linear` lazily converts the tagged CIE `XYZ` pixels back to linear `RGB`.
+  ///
+  pub fn xyz_to_linear(self) -> Tagged<crate::convert::iter::XYZToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.xyz_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Lab>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `lab_to_linear` lazily converts the tagged CIELAB pixels back to linear `RGB`.
+  ///
+  pub fn lab_to_linear(self) -> Tagged<crate::convert::iter::LabToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.lab_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Oklab>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `oklab_to_linear` lazily converts the tagged Oklab pixels back to linear `RGB`.
+  ///
+  pub fn oklab_to_linear(self) -> Tagged<crate::convert::iter::OklabToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.oklab_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Oklch>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `oklch_to_linear` lazily converts the tagged `OkLCh` pixels back to linear `RGB`.
+  ///
+  pub fn oklch_to_linear(self) -> Tagged<crate::convert::iter::OklchToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.oklch_to_linear())
+  }
+}
+
+impl<I> Tagged<I, Luv>
+where
+  I: Iterator<Item = [f32; 3]>,
+{
+  /// `luv_to_linear` lazily converts the tagged CIELUV pixels back to linear `RGB`.
+  ///
+  pub fn luv_to_linear(self) -> Tagged<crate::convert::iter::LuvToLinear<I>, LinearSrgb> {
+    Tagged::new(self.inner.luv_to_linear())
+  }
+}
+
+impl<T, S> Tagged<rgb::Image<T>, S>
+where
+  T: Numeric,
+  S: ColorSpace,
+{
+  /// `rgb_iter` returns a tagged iterator over the pixels of the image, yielding each one as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> Tagged<impl Iterator<Item = [T; 3]> + '_, S> {
+    Tagged::new(self.inner.rgb_iter())
+  }
+}
+
+impl<T, S> Tagged<rgba::Image<T>, S>
+where
+  T: Numeric,
+  S: ColorSpace,
+{
+  /// `rgb_iter` returns a tagged iterator over the color channels of the image, yielding each pixel as a `[T; 3]`.
+  ///
+  pub fn rgb_iter(&self) -> Tagged<impl Iterator<Item = [T; 3]> + '_, S> {
+    Tagged::new(self.inner.rgb_iter())
+  }
+}
+
+impl Tagged<rgb::Image<u8>, Srgb> {
+  /// `to_linear` converts the tagged `sRGB` image to linear floating point, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    self.inner.to_linear(&mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, LinearSrgb> {
+  /// `to_srgb` converts the tagged linear image to its 8-bit `sRGB` representation, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_srgb(&self, out: &mut Tagged<rgb::Image<u8>, Srgb>) {
+    self.inner.to_srgb(&mut out.inner);
+  }
+
+  /// `to_gray` converts the tagged linear image to its luminance, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_gray(&self, out: &mut Tagged<gray::Image<f32>, LinearSrgb>) {
+    rgb::cvt_rgb_to_gray(
+      &self.inner,
+      &mut out.inner,
+      crate::convert::GrayWeights::Luminance,
+    );
+  }
+
+  /// `to_hsv` converts the tagged linear image to `HSV`, storing the hue, saturation and value in the red, green and
+  /// blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_hsv(&self, out: &mut Tagged<rgb::Image<f32>, Hsv>) {
+    rgb::cvt_linear_to_hsv(&self.inner, &mut out.inner);
+  }
+
+  /// `to_hsl` converts the tagged linear image to `HSL`, storing the hue, saturation and lightness in the red, green
+  /// and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_hsl(&self, out: &mut Tagged<rgb::Image<f32>, Hsl>) {
+    rgb::cvt_linear_to_hsl(&self.inner, &mut out.inner);
+  }
+
+  /// `to_hsi` converts the tagged linear image to `HSI`, storing the hue, saturation and intensity in the red, green
+  /// and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_hsi(&self, out: &mut Tagged<rgb::Image<f32>, Hsi>) {
+    rgb::cvt_linear_to_hsi(&self.inner, &mut out.inner);
+  }
+
+  /// `to_xyz` converts the tagged linear image to CIE `XYZ`, storing the `X`, `Y` and `Z` components in the red,
+  /// green and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_xyz(&self, out: &mut Tagged<rgb::Image<f32>, Xyz>) {
+    rgb::cvt_linear_to_xyz(&self.inner, &mut out.inner);
+  }
+
+  /// `to_lab` converts the tagged linear image to CIELAB relative to the `D65` white point, storing the `L*`, `a*`
+  /// and `b*` components in the red, green and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_lab(&self, out: &mut Tagged<rgb::Image<f32>, Lab>) {
+    rgb::cvt_linear_to_lab(&self.inner, &mut out.inner, CieReference::default());
+  }
+
+  /// `to_luv` converts the tagged linear image to CIELUV relative to the `D65` white point, storing the `L*`, `u*`
+  /// and `v*` components in the red, green and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_luv(&self, out: &mut Tagged<rgb::Image<f32>, Luv>) {
+    rgb::cvt_linear_to_luv(&self.inner, &mut out.inner, CieReference::default());
+  }
+
+  /// `to_oklab` converts the tagged linear image to Oklab, storing the `L`, `a` and `b` components in the red, green
+  /// and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_oklab(&self, out: &mut Tagged<rgb::Image<f32>, Oklab>) {
+    rgb::cvt_linear_to_oklab(&self.inner, &mut out.inner);
+  }
+
+  /// `to_oklch` converts the tagged linear image to `OkLCh`, storing the `L`, `C` and `h` components in the red, green
+  /// and blue planes of `out` respectively.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_oklch(&self, out: &mut Tagged<rgb::Image<f32>, Oklch>) {
+    rgb::cvt_linear_to_oklch(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Hsv> {
+  /// `to_linear` converts the tagged `HSV` image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_hsv_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Hsl> {
+  /// `to_linear` converts the tagged `HSL` image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_hsl_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Hsi> {
+  /// `to_linear` converts the tagged `HSI` image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_hsi_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Xyz> {
+  /// `to_linear` converts the tagged CIE `XYZ` image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_xyz_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Lab> {
+  /// `to_linear` converts the tagged CIELAB image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_lab_to_linear(&self.inner, &mut out.inner, CieReference::default());
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Luv> {
+  /// `to_linear` converts the tagged CIELUV image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_luv_to_linear(&self.inner, &mut out.inner, CieReference::default());
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Oklab> {
+  /// `to_linear` converts the tagged Oklab image back to linear `RGB`, storing the result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_oklab_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgb::Image<f32>, Oklch> {
+  /// `to_linear` converts the tagged `OkLCh` image back to linear `RGB`, storing the result in `out`. Colors outside
+  /// of the `sRGB` gamut are not clamped, see [`rgb::cvt_oklch_to_linear_clipped`].
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgb::Image<f32>, LinearSrgb>) {
+    rgb::cvt_oklch_to_linear(&self.inner, &mut out.inner);
+  }
+}
+
+impl Tagged<rgba::Image<u8>, Srgb> {
+  /// `to_linear` converts the color channels of the tagged `sRGBA` image to linear floating point, storing the
+  /// result in `out`. The alpha channel is only normalized.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<rgba::Image<f32>, LinearSrgb>) {
+    self.inner.to_linear(&mut out.inner);
+  }
+}
+
+impl Tagged<rgba::Image<f32>, LinearSrgb> {
+  /// `to_srgb` converts the color channels of the tagged linear image to their 8-bit `sRGB` representation, storing
+  /// the result in `out`. The alpha channel is only scaled.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_srgb(&self, out: &mut Tagged<rgba::Image<u8>, Srgb>) {
+    self.inner.to_srgb(&mut out.inner);
+  }
+}
+
+impl Tagged<gray::Image<u8>, Srgb> {
+  /// `to_linear` converts the tagged `sRGB`-encoded grayscale image to linear floating point, storing the result in
+  /// `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_linear(&self, out: &mut Tagged<gray::Image<f32>, LinearSrgb>) {
+    self.inner.to_linear(&mut out.inner);
+  }
+}
+
+impl Tagged<gray::Image<f32>, LinearSrgb> {
+  /// `to_srgb` converts the tagged linear grayscale image to its 8-bit `sRGB`-encoded representation, storing the
+  /// result in `out`.
+  ///
+  /// If `out` is not appropriately sized, it will be resized accordingly.
+  ///
+  pub fn to_srgb(&self, out: &mut Tagged<gray::Image<u8>, Srgb>) {
+    self.inner.to_srgb(&mut out.inner);
+  }
+}
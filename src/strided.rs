@@ -0,0 +1,231 @@
+//! `strided` contains the internal machinery shared by the borrowed image views for addressing planes whose rows are
+//! separated by a stride.
+//!
+
+/// `view_len` returns the minimum number of elements a plane must contain in order to store `height` rows of `width`
+/// elements where each row begins `stride` elements after the start of the previous one.
+///
+pub(crate) fn view_len(width: usize, height: usize, stride: usize) -> Result<usize, crate::Error> {
+  if stride < width {
+    return Err(crate::Error::InvalidStride { width, stride });
+  }
+
+  if width == 0 || height == 0 {
+    return Ok(0);
+  }
+
+  (height - 1)
+    .checked_mul(stride)
+    .and_then(|n| n.checked_add(width))
+    .ok_or(crate::Error::DimensionOverflow)
+}
+
+/// `check_view_plane` verifies that the supplied plane contains at least `required` number of elements.
+///
+pub(crate) fn check_view_plane<T>(plane: &[T], required: usize) -> Result<(), crate::Error> {
+  if plane.len() >= required {
+    Ok(())
+  } else {
+    Err(crate::Error::InvalidPlaneLength {
+      expected: required,
+      actual: plane.len(),
+    })
+  }
+}
+
+/// `roi_range` returns the range of elements a strided plane must be sliced by in order to view the `width * height`
+/// rectangle whose top-left corner is at column `x` and row `y`.
+///
+pub(crate) fn roi_range(
+  (x, y, width, height): (usize, usize, usize, usize),
+  (full_width, full_height, stride): (usize, usize, usize),
+) -> Result<std::ops::Range<usize>, crate::Error> {
+  let fits =
+    |start: usize, len: usize, max: usize| start.checked_add(len).is_some_and(|end| end <= max);
+
+  if !fits(x, width, full_width) || !fits(y, height, full_height) {
+    return Err(crate::Error::InvalidRoi);
+  }
+
+  let len = view_len(width, height, stride)?;
+  if len == 0 {
+    return Ok(0..0);
+  }
+
+  let start = y * stride + x;
+  Ok(start..start + len)
+}
+
+/// `Rows` traverses a strided plane, yielding each row as a `&[T]` that excludes any padding.
+///
+pub(crate) struct Rows<'a, T> {
+  rest: &'a [T],
+  width: usize,
+  stride: usize,
+  remaining: usize,
+}
+
+impl<'a, T> Rows<'a, T> {
+  pub(crate) fn new(plane: &'a [T], width: usize, height: usize, stride: usize) -> Self {
+    Self {
+      rest: plane,
+      width,
+      stride,
+      remaining: height,
+    }
+  }
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+  type Item = &'a [T];
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+
+    self.remaining -= 1;
+
+    let (row, rest) = self.rest.split_at(self.stride.min(self.rest.len()));
+    self.rest = rest;
+
+    Some(&row[..self.width])
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+/// `RowsMut` traverses a strided plane, yielding each row as a `&mut [T]` that excludes any padding.
+///
+pub(crate) struct RowsMut<'a, T> {
+  rest: &'a mut [T],
+  width: usize,
+  stride: usize,
+  remaining: usize,
+}
+
+impl<'a, T> RowsMut<'a, T> {
+  pub(crate) fn new(plane: &'a mut [T], width: usize, height: usize, stride: usize) -> Self {
+    Self {
+      rest: plane,
+      width,
+      stride,
+      remaining: height,
+    }
+  }
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+  type Item = &'a mut [T];
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+
+    self.remaining -= 1;
+
+    let rest = std::mem::take(&mut self.rest);
+    let (row, rest) = rest.split_at_mut(self.stride.min(rest.len()));
+    self.rest = rest;
+
+    Some(&mut row[..self.width])
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+
+impl<T> ExactSizeIterator for RowsMut<'_, T> {}
+
+/// `band_heights` divides `height` rows into `n` bands of consecutive rows whose heights differ by at most one, with
+/// any remainder distributed to the leading bands.
+///
+pub(crate) fn band_heights(height: usize, n: usize) -> Vec<usize> {
+  assert!(n > 0, "the number of bands must be non-zero");
+
+  (0..n)
+    .map(|idx| height / n + usize::from(idx < height % n))
+    .collect()
+}
+
+/// `split_plane_mut` splits a strided plane into disjoint bands of consecutive rows with the supplied heights. Each
+/// band excludes the padding that follows its final row.
+///
+pub(crate) fn split_plane_mut<'a, T>(
+  mut plane: &'a mut [T],
+  width: usize,
+  stride: usize,
+  heights: &[usize],
+) -> Vec<&'a mut [T]> {
+  heights
+    .iter()
+    .map(|&height| {
+      let rest = std::mem::take(&mut plane);
+      let (band, rest) = rest.split_at_mut((height * stride).min(rest.len()));
+      plane = rest;
+
+      let len = if width == 0 || height == 0 {
+        0
+      } else {
+        (height - 1) * stride + width
+      };
+
+      &mut band[..len]
+    })
+    .collect()
+}
+
+/// `map_plane` applies `f` to each element of the strided `src` plane, storing the result in the corresponding
+/// element of the strided `dst` plane.
+///
+pub(crate) fn map_plane<A, B, F>(
+  src: &[A],
+  src_stride: usize,
+  dst: &mut [B],
+  dst_stride: usize,
+  (width, height): (usize, usize),
+  f: F,
+) where
+  A: Copy,
+  F: Fn(A) -> B,
+{
+  Rows::new(src, width, height, src_stride)
+    .zip(RowsMut::new(dst, width, height, dst_stride))
+    .for_each(|(src, dst)| {
+      src
+        .iter()
+        .copied()
+        .zip(dst.iter_mut())
+        .for_each(|(x, y)| *y = f(x));
+    });
+}
+
+/// `map_rows` applies the slice kernel `f` to each row of the strided `src` plane, storing the result in the
+/// corresponding row of the strided `dst` plane. Planes without any padding are handed to `f` in a single call.
+///
+pub(crate) fn map_rows<A, B, F>(
+  src: &[A],
+  src_stride: usize,
+  dst: &mut [B],
+  dst_stride: usize,
+  (width, height): (usize, usize),
+  f: F,
+) where
+  F: Fn(&[A], &mut [B]),
+{
+  if src_stride == width && dst_stride == width {
+    let n = width * height;
+    f(&src[..n], &mut dst[..n]);
+    return;
+  }
+
+  Rows::new(src, width, height, src_stride)
+    .zip(RowsMut::new(dst, width, height, dst_stride))
+    .for_each(|(src, dst)| f(src, dst));
+}
@@ -0,0 +1,1351 @@
+//! `yuv` contains the `Y'CbCr` image formats produced by cameras and video codecs, along with routines for converting
+//! them to and from 8-bit [`rgb`](crate::rgb) images.
+//!
+//! An [`Encoding`] pairs the luma coefficients of a video standard (`BT.601`, `BT.709` or `BT.2020`) with the range
+//! the samples are quantized to. Chroma subsampled formats additionally need to know where their chroma samples are
+//! located relative to the luma samples, which is described by [`Siting`].
+//!
+//! Decoding the planar formats to `RGB` is vectorized where the target supports it.
+//!
+//! # Example
+//! ```
+//! use cvr::yuv::{Encoding, Siting};
+//!
+//! let img = cvr::rgb::Image::from_vec(vec![255_u8; 4], vec![0_u8; 4], vec![0_u8; 4], 2, 2).unwrap();
+//!
+//! let mut i420 = cvr::yuv::I420::new();
+//! cvr::yuv::rgb_to_i420(img.view(), &mut i420, Encoding::default(), Siting::default());
+//! assert_eq!(i420.y(), [63, 63, 63, 63]);
+//! assert_eq!((i420.u(), i420.v()), (&[102_u8][..], &[240_u8][..]));
+//!
+//! let mut rgb = cvr::rgb::Image::new();
+//! cvr::yuv::i420_to_rgb(&i420, &mut rgb, Encoding::default(), Siting::default());
+//! assert_eq!(rgb.r(), [255, 255, 255, 255]);
+//! ```
+//!
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_possible_wrap,
+  clippy::cast_ptr_alignment,
+  clippy::cast_sign_loss,
+  clippy::many_single_char_names
+)]
+
+extern crate minivec;
+
+use crate::rgb;
+
+/// `Matrix` selects the luma coefficients used to derive `Y'CbCr` from `RGB`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Matrix {
+  /// `Bt601` is the matrix of standard definition video and `JPEG`.
+  ///
+  Bt601,
+
+  /// `Bt709` is the matrix of high definition video.
+  ///
+  #[default]
+  Bt709,
+
+  /// `Bt2020` is the non-constant luminance matrix of ultra high definition video.
+  ///
+  Bt2020,
+}
+
+impl Matrix {
+  /// `coefficients` returns the `(Kr, Kg, Kb)` weights of the red, green and blue channels in the luma signal.
+  ///
+  #[must_use]
+  pub fn coefficients(self) -> [f64; 3] {
+    let (kr, kb) = match self {
+      Matrix::Bt601 => (0.299, 0.114),
+      Matrix::Bt709 => (0.2126, 0.0722),
+      Matrix::Bt2020 => (0.2627, 0.0593),
+    };
+
+    [kr, 1.0 - kr - kb, kb]
+  }
+}
+
+/// `Range` selects how the `Y'CbCr` samples are quantized.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Range {
+  /// `Full` uses every code value, `[0, 255]` for luma and chroma alike, as `JPEG` does.
+  ///
+  Full,
+
+  /// `Limited` reserves headroom and footroom, placing luma within `[16, 235]` and chroma within `[16, 240]`, as
+  /// broadcast video does.
+  ///
+  #[default]
+  Limited,
+}
+
+/// `Encoding` describes how `RGB` values map to `Y'CbCr` samples. The [`Default`] is limited range `BT.709`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Encoding {
+  /// The luma coefficients.
+  pub matrix: Matrix,
+  /// The quantization range.
+  pub range: Range,
+}
+
+impl Encoding {
+  /// `new` constructs an `Encoding` from the supplied matrix and range.
+  ///
+  #[must_use]
+  pub const fn new(matrix: Matrix, range: Range) -> Self {
+    Self { matrix, range }
+  }
+}
+
+/// `Subsampling` describes the resolution of the chroma planes relative to the luma plane.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subsampling {
+  /// `Yuv444` stores chroma at full resolution.
+  ///
+  Yuv444,
+
+  /// `Yuv422` halves the horizontal chroma resolution.
+  ///
+  Yuv422,
+
+  /// `Yuv420` halves both the horizontal and vertical chroma resolution.
+  ///
+  Yuv420,
+}
+
+impl Subsampling {
+  /// `chroma_dims` returns the `(width, height)` of the chroma planes of a `width * height` image, rounding up for odd
+  /// dimensions.
+  ///
+  #[must_use]
+  pub fn chroma_dims(self, width: usize, height: usize) -> (usize, usize) {
+    match self {
+      Subsampling::Yuv444 => (width, height),
+      Subsampling::Yuv422 => (width.div_ceil(2), height),
+      Subsampling::Yuv420 => (width.div_ceil(2), height.div_ceil(2)),
+    }
+  }
+
+  fn factors(self) -> (bool, bool) {
+    match self {
+      Subsampling::Yuv444 => (false, false),
+      Subsampling::Yuv422 => (true, false),
+      Subsampling::Yuv420 => (true, true),
+    }
+  }
+}
+
+/// `Siting` describes where each subsampled chroma sample is located relative to the luma samples it covers.
+///
+/// Siting determines the filters used when resampling chroma, so decoding with a different siting than the one the
+/// image was encoded with produces a slight chroma shift. The [`Default`] is `Left`, used by `MPEG-2`, `H.264` and
+/// `HEVC`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Siting {
+  /// `Center` places chroma samples halfway between the luma samples they cover in both directions, as `JPEG` and
+  /// `MPEG-1` do.
+  ///
+  Center,
+
+  /// `Left` places chroma samples on top of the left luma sample horizontally and halfway between rows vertically.
+  ///
+  #[default]
+  Left,
+
+  /// `TopLeft` places chroma samples on top of the top-left luma sample they cover, as `BT.2020` recommends.
+  ///
+  TopLeft,
+}
+
+impl Siting {
+  fn cosited(self) -> (bool, bool) {
+    match self {
+      Siting::Center => (false, false),
+      Siting::Left => (true, false),
+      Siting::TopLeft => (true, true),
+    }
+  }
+}
+
+/// `Yuv444` represents a planar `Y'CbCr` image without any chroma subsampling.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Yuv444 {
+  pub(super) y: minivec::MiniVec<u8>,
+  pub(super) u: minivec::MiniVec<u8>,
+  pub(super) v: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Yuv444 {
+  /// `new` returns an empty `Yuv444` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_vec` constructs a `Yuv444` from the supplied luma and chroma planes, each of which must contain
+  /// `width * height` number of elements in a row-major ordering.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes are incorrectly sized and an
+  /// `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let size = crate::plane_len(width, height)?;
+    crate::check_plane(&y, size)?;
+    crate::check_plane(&u, size)?;
+    crate::check_plane(&v, size)?;
+
+    Ok(Self {
+      y: y.into_iter().collect(),
+      u: u.into_iter().collect(),
+      v: v.into_iter().collect(),
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `y` returns an immutable reference to the luma plane.
+  ///
+  #[must_use]
+  pub fn y(&self) -> &[u8] {
+    self.y.as_slice()
+  }
+
+  /// `u` returns an immutable reference to the blue-difference chroma plane.
+  ///
+  #[must_use]
+  pub fn u(&self) -> &[u8] {
+    self.u.as_slice()
+  }
+
+  /// `v` returns an immutable reference to the red-difference chroma plane.
+  ///
+  #[must_use]
+  pub fn v(&self) -> &[u8] {
+    self.v.as_slice()
+  }
+
+  /// `yuv_mut` returns mutable references to the luma and both chroma planes.
+  ///
+  pub fn yuv_mut(&mut self) -> (&mut [u8], &mut [u8], &mut [u8]) {
+    (
+      self.y.as_mut_slice(),
+      self.u.as_mut_slice(),
+      self.v.as_mut_slice(),
+    )
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `resize` readjusts the internal planes to match a `width * height` image and resets the internal `width` and
+  /// `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.y.resize(width * height, 0);
+    self.u.resize(width * height, 0);
+    self.v.resize(width * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `I420` represents a planar 4:2:0 `Y'CbCr` image, storing a full resolution luma plane followed by blue-difference
+/// and red-difference chroma planes that are subsampled by two in both directions.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct I420 {
+  pub(super) y: minivec::MiniVec<u8>,
+  pub(super) u: minivec::MiniVec<u8>,
+  pub(super) v: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl I420 {
+  /// `new` returns an empty `I420` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_vec` constructs an `I420` from the supplied planes. The luma plane must contain `width * height` number of
+  /// elements and each chroma plane `((width + 1) / 2) * ((height + 1) / 2)` number of elements.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if any of the planes are incorrectly sized and an
+  /// `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+
+    crate::check_plane(&y, crate::plane_len(width, height)?)?;
+    crate::check_plane(&u, cw * ch)?;
+    crate::check_plane(&v, cw * ch)?;
+
+    Ok(Self {
+      y: y.into_iter().collect(),
+      u: u.into_iter().collect(),
+      v: v.into_iter().collect(),
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `y` returns an immutable reference to the luma plane.
+  ///
+  #[must_use]
+  pub fn y(&self) -> &[u8] {
+    self.y.as_slice()
+  }
+
+  /// `u` returns an immutable reference to the subsampled blue-difference chroma plane.
+  ///
+  #[must_use]
+  pub fn u(&self) -> &[u8] {
+    self.u.as_slice()
+  }
+
+  /// `v` returns an immutable reference to the subsampled red-difference chroma plane.
+  ///
+  #[must_use]
+  pub fn v(&self) -> &[u8] {
+    self.v.as_slice()
+  }
+
+  /// `yuv_mut` returns mutable references to the luma and both chroma planes.
+  ///
+  pub fn yuv_mut(&mut self) -> (&mut [u8], &mut [u8], &mut [u8]) {
+    (
+      self.y.as_mut_slice(),
+      self.u.as_mut_slice(),
+      self.v.as_mut_slice(),
+    )
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `resize` readjusts the internal planes to match a `width * height` image and resets the internal `width` and
+  /// `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+
+    self.y.resize(width * height, 0);
+    self.u.resize(cw * ch, 0);
+    self.v.resize(cw * ch, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `Nv12` represents a semi-planar 4:2:0 `Y'CbCr` image, storing a full resolution luma plane followed by a single
+/// plane of interleaved `[U, V, U, V, ...]` chroma that is subsampled by two in both directions.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Nv12 {
+  pub(super) y: minivec::MiniVec<u8>,
+  pub(super) uv: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Nv12 {
+  /// `new` returns an empty `Nv12` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_vec` constructs an `Nv12` from the supplied planes. The luma plane must contain `width * height` number of
+  /// elements and the interleaved chroma plane `2 * ((width + 1) / 2) * ((height + 1) / 2)` number of elements.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if either of the planes are incorrectly sized and an
+  /// `Error::DimensionOverflow` if `width * height` overflows.
+  ///
+  pub fn from_vec(
+    y: Vec<u8>,
+    uv: Vec<u8>,
+    width: usize,
+    height: usize,
+  ) -> Result<Self, crate::Error> {
+    let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+
+    crate::check_plane(&y, crate::plane_len(width, height)?)?;
+    crate::check_plane(&uv, 2 * cw * ch)?;
+
+    Ok(Self {
+      y: y.into_iter().collect(),
+      uv: uv.into_iter().collect(),
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `y` returns an immutable reference to the luma plane.
+  ///
+  #[must_use]
+  pub fn y(&self) -> &[u8] {
+    self.y.as_slice()
+  }
+
+  /// `uv` returns an immutable reference to the interleaved chroma plane.
+  ///
+  #[must_use]
+  pub fn uv(&self) -> &[u8] {
+    self.uv.as_slice()
+  }
+
+  /// `y_uv_mut` returns mutable references to the luma plane and the interleaved chroma plane.
+  ///
+  pub fn y_uv_mut(&mut self) -> (&mut [u8], &mut [u8]) {
+    (self.y.as_mut_slice(), self.uv.as_mut_slice())
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `resize` readjusts the internal planes to match a `width * height` image and resets the internal `width` and
+  /// `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+
+    self.y.resize(width * height, 0);
+    self.uv.resize(2 * cw * ch, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `Yuyv` represents a packed 4:2:2 `Y'CbCr` image in which every pair of horizontally adjacent pixels is stored as
+/// `[Y0, U, Y1, V]`. Images with an odd width pad each row to a whole pair.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Yuyv {
+  pub(super) data: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Yuyv {
+  /// `new` returns an empty `Yuyv` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_vec` constructs a `Yuyv` from the supplied packed data, which must contain
+  /// `4 * ((width + 1) / 2) * height` number of elements.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` is incorrectly sized and an `Error::DimensionOverflow` if the
+  /// required length overflows.
+  ///
+  pub fn from_vec(data: Vec<u8>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    crate::check_plane(&data, packed_422_len(width, height)?)?;
+
+    Ok(Self {
+      data: data.into_iter().collect(),
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `data` returns an immutable reference to the packed pixel data.
+  ///
+  #[must_use]
+  pub fn data(&self) -> &[u8] {
+    self.data.as_slice()
+  }
+
+  /// `data_mut` returns a mutable reference to the packed pixel data.
+  ///
+  pub fn data_mut(&mut self) -> &mut [u8] {
+    self.data.as_mut_slice()
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `resize` readjusts the internal buffer to match a `width * height` image and resets the internal `width` and
+  /// `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.data.resize(4 * width.div_ceil(2) * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `Uyvy` represents a packed 4:2:2 `Y'CbCr` image in which every pair of horizontally adjacent pixels is stored as
+/// `[U, Y0, V, Y1]`. Images with an odd width pad each row to a whole pair.
+///
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Uyvy {
+  pub(super) data: minivec::MiniVec<u8>,
+  pub(super) h: usize,
+  pub(super) w: usize,
+}
+
+impl Uyvy {
+  /// `new` returns an empty `Uyvy` with no data having been allocated.
+  ///
+  #[must_use]
+  pub fn new() -> Self {
+    <Self as Default>::default()
+  }
+
+  /// `from_vec` constructs a `Uyvy` from the supplied packed data, which must contain
+  /// `4 * ((width + 1) / 2) * height` number of elements.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `Error::InvalidPlaneLength` if `data` is incorrectly sized and an `Error::DimensionOverflow` if the
+  /// required length overflows.
+  ///
+  pub fn from_vec(data: Vec<u8>, width: usize, height: usize) -> Result<Self, crate::Error> {
+    crate::check_plane(&data, packed_422_len(width, height)?)?;
+
+    Ok(Self {
+      data: data.into_iter().collect(),
+      h: height,
+      w: width,
+    })
+  }
+
+  /// `data` returns an immutable reference to the packed pixel data.
+  ///
+  #[must_use]
+  pub fn data(&self) -> &[u8] {
+    self.data.as_slice()
+  }
+
+  /// `data_mut` returns a mutable reference to the packed pixel data.
+  ///
+  pub fn data_mut(&mut self) -> &mut [u8] {
+    self.data.as_mut_slice()
+  }
+
+  /// `width` returns the number of columns in the image.
+  ///
+  #[must_use]
+  pub fn width(&self) -> usize {
+    self.w
+  }
+
+  /// `height` returns the number of rows in the image.
+  ///
+  #[must_use]
+  pub fn height(&self) -> usize {
+    self.h
+  }
+
+  /// `resize` readjusts the internal buffer to match a `width * height` image and resets the internal `width` and
+  /// `height` data members.
+  ///
+  /// This operation should be considered destructive for the image data itself.
+  ///
+  pub fn resize(&mut self, width: usize, height: usize) {
+    self.data.resize(4 * width.div_ceil(2) * height, 0);
+
+    self.h = height;
+    self.w = width;
+  }
+}
+
+/// `rgb_to_ycbcr` encodes a single `RGB` pixel as `[Y, Cb, Cr]`.
+///
+#[must_use]
+pub fn rgb_to_ycbcr(rgb: [u8; 3], encoding: Encoding) -> [u8; 3] {
+  EncodeCoefficients::new(encoding).apply(rgb)
+}
+
+/// `ycbcr_to_rgb` decodes a single `[Y, Cb, Cr]` pixel to `RGB`, saturating values that fall outside of the `RGB`
+/// gamut.
+///
+#[must_use]
+pub fn ycbcr_to_rgb(ycbcr: [u8; 3], encoding: Encoding) -> [u8; 3] {
+  DecodeCoefficients::new(encoding).apply(ycbcr)
+}
+
+/// `rgb_to_yuv444` encodes the supplied image into `out`, resizing it to match.
+///
+pub fn rgb_to_yuv444(img: rgb::ImageRef<'_, u8>, out: &mut Yuv444, encoding: Encoding) {
+  out.resize(img.width(), img.height());
+
+  let (y, u, v) = out.yuv_mut();
+  encode_planes(img, encoding, y, u, v);
+}
+
+/// `yuv444_to_rgb` decodes the supplied image into `out`, resizing it to match.
+///
+pub fn yuv444_to_rgb(img: &Yuv444, out: &mut rgb::Image<u8>, encoding: Encoding) {
+  out.resize(img.width(), img.height());
+  decode_planes(img.y(), img.u(), img.v(), out, encoding);
+}
+
+/// `rgb_to_i420` encodes the supplied image into `out`, downsampling the chroma planes according to `siting` and
+/// resizing `out` to match.
+///
+pub fn rgb_to_i420(img: rgb::ImageRef<'_, u8>, out: &mut I420, encoding: Encoding, siting: Siting) {
+  let (width, height) = (img.width(), img.height());
+  out.resize(width, height);
+
+  let (mut u, mut v) = (vec![0; width * height], vec![0; width * height]);
+
+  let (y, out_u, out_v) = out.yuv_mut();
+  encode_planes(img, encoding, y, &mut u, &mut v);
+
+  let dims = (width, height);
+  downsample_chroma(&u, dims, Subsampling::Yuv420, siting, out_u);
+  downsample_chroma(&v, dims, Subsampling::Yuv420, siting, out_v);
+}
+
+/// `i420_to_rgb` decodes the supplied image into `out`, upsampling the chroma planes according to `siting` and
+/// resizing `out` to match.
+///
+pub fn i420_to_rgb(img: &I420, out: &mut rgb::Image<u8>, encoding: Encoding, siting: Siting) {
+  out.resize(img.width(), img.height());
+
+  let (cw, _) = Subsampling::Yuv420.chroma_dims(img.width(), img.height());
+  decode_420(img.y(), (img.u(), img.v(), cw, 1), out, encoding, siting);
+}
+
+/// `rgb_to_nv12` encodes the supplied image into `out`, downsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn rgb_to_nv12(img: rgb::ImageRef<'_, u8>, out: &mut Nv12, encoding: Encoding, siting: Siting) {
+  let (width, height) = (img.width(), img.height());
+  out.resize(width, height);
+
+  let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+  let (mut u, mut v) = (vec![0; width * height], vec![0; width * height]);
+  let (mut sub_u, mut sub_v) = (vec![0; cw * ch], vec![0; cw * ch]);
+
+  let (y, uv) = out.y_uv_mut();
+  encode_planes(img, encoding, y, &mut u, &mut v);
+
+  let dims = (width, height);
+  downsample_chroma(&u, dims, Subsampling::Yuv420, siting, &mut sub_u);
+  downsample_chroma(&v, dims, Subsampling::Yuv420, siting, &mut sub_v);
+
+  uv.chunks_exact_mut(2)
+    .zip(sub_u.iter().zip(sub_v.iter()))
+    .for_each(|(p, (&u, &v))| {
+      p[0] = u;
+      p[1] = v;
+    });
+}
+
+/// `nv12_to_rgb` decodes the supplied image into `out`, upsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn nv12_to_rgb(img: &Nv12, out: &mut rgb::Image<u8>, encoding: Encoding, siting: Siting) {
+  out.resize(img.width(), img.height());
+
+  // the interleaved chroma is read in place, with `v` starting one byte after `u`
+  //
+  let (cw, _) = Subsampling::Yuv420.chroma_dims(img.width(), img.height());
+  let uv = img.uv();
+  let v = uv.get(1..).unwrap_or_default();
+  decode_420(img.y(), (uv, v, 2 * cw, 2), out, encoding, siting);
+}
+
+/// `rgb_to_yuyv` encodes the supplied image into `out`, downsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn rgb_to_yuyv(img: rgb::ImageRef<'_, u8>, out: &mut Yuyv, encoding: Encoding, siting: Siting) {
+  out.resize(img.width(), img.height());
+  encode_packed_422(img, &mut out.data, [0, 1, 2, 3], encoding, siting);
+}
+
+/// `yuyv_to_rgb` decodes the supplied image into `out`, upsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn yuyv_to_rgb(img: &Yuyv, out: &mut rgb::Image<u8>, encoding: Encoding, siting: Siting) {
+  decode_packed_422(
+    img.data(),
+    (img.width(), img.height()),
+    [0, 1, 2, 3],
+    out,
+    encoding,
+    siting,
+  );
+}
+
+/// `rgb_to_uyvy` encodes the supplied image into `out`, downsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn rgb_to_uyvy(img: rgb::ImageRef<'_, u8>, out: &mut Uyvy, encoding: Encoding, siting: Siting) {
+  out.resize(img.width(), img.height());
+  encode_packed_422(img, &mut out.data, [1, 0, 3, 2], encoding, siting);
+}
+
+/// `uyvy_to_rgb` decodes the supplied image into `out`, upsampling the chroma according to `siting` and resizing
+/// `out` to match.
+///
+pub fn uyvy_to_rgb(img: &Uyvy, out: &mut rgb::Image<u8>, encoding: Encoding, siting: Siting) {
+  decode_packed_422(
+    img.data(),
+    (img.width(), img.height()),
+    [1, 0, 3, 2],
+    out,
+    encoding,
+    siting,
+  );
+}
+
+/// `downsample_chroma` reduces the full resolution chroma `plane` of a `(width, height)` image to the resolution
+/// given by `subsampling`, storing the result in `out`.
+///
+/// Chroma that is sited between luma samples is averaged while co-sited chroma is filtered with a `[1, 2, 1]` kernel
+/// centered on the luma sample it sits on top of.
+///
+/// # Panics
+///
+/// Panics if `plane` does not contain `width * height` number of elements or if `out` is not sized according to
+/// [`Subsampling::chroma_dims`].
+///
+pub fn downsample_chroma(
+  plane: &[u8],
+  (width, height): (usize, usize),
+  subsampling: Subsampling,
+  siting: Siting,
+  out: &mut [u8],
+) {
+  let (cw, ch) = subsampling.chroma_dims(width, height);
+  assert_eq!(plane.len(), width * height, "invalid chroma plane length");
+  assert_eq!(out.len(), cw * ch, "invalid subsampled chroma plane length");
+
+  let (sub_x, sub_y) = subsampling.factors();
+  let (cosited_x, cosited_y) = siting.cosited();
+
+  let xs: Vec<_> = (0..cw)
+    .map(|x| down_taps(x, width, sub_x, cosited_x))
+    .collect();
+  let ys: Vec<_> = (0..ch)
+    .map(|y| down_taps(y, height, sub_y, cosited_y))
+    .collect();
+
+  resample(plane, width, &xs, &ys, out);
+}
+
+/// `upsample_chroma` expands the subsampled chroma `plane` of a `(width, height)` image back to full resolution by
+/// interpolating between the neighboring chroma samples according to their `siting`, storing the result in `out`.
+///
+/// # Panics
+///
+/// Panics if `plane` is not sized according to [`Subsampling::chroma_dims`] or if `out` does not contain
+/// `width * height` number of elements.
+///
+pub fn upsample_chroma(
+  plane: &[u8],
+  (width, height): (usize, usize),
+  subsampling: Subsampling,
+  siting: Siting,
+  out: &mut [u8],
+) {
+  let (cw, ch) = subsampling.chroma_dims(width, height);
+  assert_eq!(
+    plane.len(),
+    cw * ch,
+    "invalid subsampled chroma plane length"
+  );
+  assert_eq!(out.len(), width * height, "invalid chroma plane length");
+
+  let (sub_x, sub_y) = subsampling.factors();
+  let (cosited_x, cosited_y) = siting.cosited();
+
+  let xs: Vec<_> = (0..width)
+    .map(|x| up_taps(x, cw, sub_x, cosited_x))
+    .collect();
+  let ys: Vec<_> = (0..height)
+    .map(|y| up_taps(y, ch, sub_y, cosited_y))
+    .collect();
+
+  resample(plane, cw, &xs, &ys, out);
+}
+
+/// `Taps` are the source indices and integral weights that produce a single resampled value along one axis, along
+/// with the sum of the weights.
+///
+type Taps = ([usize; 3], [u32; 3], u32);
+
+/// `down_taps` returns the filter that produces the `i`-th subsampled value from an axis of length `len`.
+///
+fn down_taps(i: usize, len: usize, subsampled: bool, cosited: bool) -> Taps {
+  if !subsampled {
+    return ([i; 3], [1, 0, 0], 1);
+  }
+
+  let center = 2 * i;
+  let next = (center + 1).min(len - 1);
+
+  if cosited {
+    ([center.saturating_sub(1), center, next], [1, 2, 1], 4)
+  } else {
+    ([center, next, next], [1, 1, 0], 2)
+  }
+}
+
+/// `up_taps` returns the filter that interpolates the value of the `i`-th full resolution sample from a subsampled
+/// axis of length `len`.
+///
+fn up_taps(i: usize, len: usize, subsampled: bool, cosited: bool) -> Taps {
+  if !subsampled {
+    return ([i; 3], [1, 0, 0], 1);
+  }
+
+  // positions are measured in quarters of a chroma sample, sample `k` sits at `4 * k`
+  //
+  let pos = if cosited {
+    2 * i as isize
+  } else {
+    2 * i as isize - 1
+  };
+  let (idx, frac) = (pos.div_euclid(4), pos.rem_euclid(4) as u32);
+
+  let clamp = |k: isize| k.clamp(0, len as isize - 1) as usize;
+
+  ([clamp(idx), clamp(idx + 1), 0], [4 - frac, frac, 0], 4)
+}
+
+/// `resample` applies the separable filters `xs` and `ys` to the row-major `plane`, writing `xs.len() * ys.len()`
+/// values to `out`.
+///
+fn resample(plane: &[u8], stride: usize, xs: &[Taps], ys: &[Taps], out: &mut [u8]) {
+  out
+    .chunks_exact_mut(xs.len().max(1))
+    .zip(ys)
+    .for_each(|(row, ys)| {
+      row
+        .iter_mut()
+        .zip(xs)
+        .for_each(|(p, xs)| *p = interpolate(plane, (stride, 1), xs, ys));
+    });
+}
+
+/// `interpolate` applies the filters `xs` and `ys` to the plane around a single position, where the `(stride, step)`
+/// give the distance between consecutive rows and columns of the plane.
+///
+fn interpolate(plane: &[u8], (stride, step): (usize, usize), xs: &Taps, ys: &Taps) -> u8 {
+  let ((rx, wx, dx), (ry, wy, dy)) = (xs, ys);
+
+  let mut sum = 0;
+  for (&y, &wy) in ry.iter().zip(wy) {
+    for (&x, &wx) in rx.iter().zip(wx) {
+      sum += wy * wx * u32::from(plane[y * stride + x * step]);
+    }
+  }
+
+  let d = dx * dy;
+  ((sum + d / 2) / d) as u8
+}
+
+/// `encode_planes` converts every pixel of `img` into the full resolution `y`, `u` and `v` planes.
+///
+fn encode_planes(
+  img: rgb::ImageRef<'_, u8>,
+  encoding: Encoding,
+  y: &mut [u8],
+  u: &mut [u8],
+  v: &mut [u8],
+) {
+  let width = img.width().max(1);
+  let coefficients = EncodeCoefficients::new(encoding);
+
+  img
+    .rows()
+    .zip(y.chunks_exact_mut(width))
+    .zip(u.chunks_exact_mut(width).zip(v.chunks_exact_mut(width)))
+    .for_each(|(([r, g, b], y), (u, v))| {
+      rgb::make_iter(r, g, b)
+        .zip(crate::rgb::make_iter_mut(y, u, v))
+        .for_each(|(rgb, [y, u, v])| {
+          let [luma, cb, cr] = coefficients.apply(rgb);
+          *y = luma;
+          *u = cb;
+          *v = cr;
+        });
+    });
+}
+
+/// `decode_planes` converts the full resolution `y`, `u` and `v` planes into `out`, which must already be sized to
+/// match.
+///
+fn decode_planes(y: &[u8], u: &[u8], v: &[u8], out: &mut rgb::Image<u8>, encoding: Encoding) {
+  let coefficients = DecodeCoefficients::new(encoding);
+  let (r, g, b) = out.rgb_mut();
+
+  decode_row(y, u, v, [r, g, b], &coefficients);
+}
+
+/// `CHUNK` is the number of pixels of a row whose chroma [`decode_420`] upsamples at a time.
+///
+const CHUNK: usize = 256;
+
+/// `decode_420` converts the full resolution `y` plane along with its 4:2:0 subsampled chroma into `out`, which must
+/// already be sized to match. The chroma is given as `(u, v, stride, step)`, where the `stride` and `step` are the
+/// distances between consecutive chroma rows and columns, so that interleaved chroma can be read in place.
+///
+/// Rather than upsampling whole planes, the chroma of each row is upsampled a chunk at a time into buffers on the
+/// stack, which are converted along with the luma while they're still in cache.
+///
+fn decode_420(
+  y: &[u8],
+  (u, v, stride, step): (&[u8], &[u8], usize, usize),
+  out: &mut rgb::Image<u8>,
+  encoding: Encoding,
+  siting: Siting,
+) {
+  let (width, height) = (out.width(), out.height());
+  if width == 0 || height == 0 {
+    return;
+  }
+
+  let (cw, ch) = Subsampling::Yuv420.chroma_dims(width, height);
+  let (cosited_x, cosited_y) = siting.cosited();
+  let coefficients = DecodeCoefficients::new(encoding);
+
+  let (mut u_chunk, mut v_chunk) = ([0_u8; CHUNK], [0_u8; CHUNK]);
+
+  out
+    .rows_mut()
+    .zip(y.chunks_exact(width))
+    .enumerate()
+    .for_each(|(row, ([r, g, b], luma))| {
+      let ys = up_taps(row, ch, true, cosited_y);
+
+      for start in (0..width).step_by(CHUNK) {
+        let end = (start + CHUNK).min(width);
+        let (u_chunk, v_chunk) = (&mut u_chunk[..end - start], &mut v_chunk[..end - start]);
+
+        u_chunk
+          .iter_mut()
+          .zip(v_chunk.iter_mut())
+          .zip(start..end)
+          .for_each(|((u_out, v_out), x)| {
+            let xs = up_taps(x, cw, true, cosited_x);
+            *u_out = interpolate(u, (stride, step), &xs, &ys);
+            *v_out = interpolate(v, (stride, step), &xs, &ys);
+          });
+
+        decode_row(
+          &luma[start..end],
+          u_chunk,
+          v_chunk,
+          [&mut r[start..end], &mut g[start..end], &mut b[start..end]],
+          &coefficients,
+        );
+      }
+    });
+}
+
+/// `decode_row` converts the full resolution `y`, `u` and `v` samples into the `r`, `g` and `b` samples, all of which
+/// must have the same length.
+///
+fn decode_row(
+  y: &[u8],
+  u: &[u8],
+  v: &[u8],
+  [r, g, b]: [&mut [u8]; 3],
+  coefficients: &DecodeCoefficients,
+) {
+  #[cfg(target_arch = "x86_64")]
+  let done = unsafe { simd::ycbcr_to_rgb(y, u, v, [&mut *r, &mut *g, &mut *b], coefficients) };
+
+  #[cfg(not(target_arch = "x86_64"))]
+  let done = 0;
+
+  rgb::make_iter(&y[done..], &u[done..], &v[done..])
+    .zip(crate::rgb::make_iter_mut(
+      &mut r[done..],
+      &mut g[done..],
+      &mut b[done..],
+    ))
+    .for_each(|(ycbcr, [r, g, b])| {
+      let [red, green, blue] = coefficients.apply(ycbcr);
+      *r = red;
+      *g = green;
+      *b = blue;
+    });
+}
+
+/// `encode_packed_422` encodes `img` into the packed 4:2:2 `data`, where `order` holds the offsets of the
+/// `[Y0, U, Y1, V]` samples within each group of four bytes.
+///
+fn encode_packed_422(
+  img: rgb::ImageRef<'_, u8>,
+  data: &mut [u8],
+  order: [usize; 4],
+  encoding: Encoding,
+  siting: Siting,
+) {
+  let (width, height) = (img.width(), img.height());
+  if width == 0 || height == 0 {
+    return;
+  }
+
+  let (cw, ch) = Subsampling::Yuv422.chroma_dims(width, height);
+
+  let (mut y, mut u, mut v) = (
+    vec![0; width * height],
+    vec![0; width * height],
+    vec![0; width * height],
+  );
+  let (mut sub_u, mut sub_v) = (vec![0; cw * ch], vec![0; cw * ch]);
+
+  encode_planes(img, encoding, &mut y, &mut u, &mut v);
+
+  let dims = (width, height);
+  downsample_chroma(&u, dims, Subsampling::Yuv422, siting, &mut sub_u);
+  downsample_chroma(&v, dims, Subsampling::Yuv422, siting, &mut sub_v);
+
+  for (row, group) in data.chunks_exact_mut(4 * cw).enumerate() {
+    for (col, p) in group.chunks_exact_mut(4).enumerate() {
+      let x = 2 * col;
+      let next = (x + 1).min(width - 1);
+
+      p[order[0]] = y[row * width + x];
+      p[order[1]] = sub_u[row * cw + col];
+      p[order[2]] = y[row * width + next];
+      p[order[3]] = sub_v[row * cw + col];
+    }
+  }
+}
+
+/// `decode_packed_422` decodes the packed 4:2:2 `data` into `out`, where `order` holds the offsets of the
+/// `[Y0, U, Y1, V]` samples within each group of four bytes.
+///
+fn decode_packed_422(
+  data: &[u8],
+  (width, height): (usize, usize),
+  order: [usize; 4],
+  out: &mut rgb::Image<u8>,
+  encoding: Encoding,
+  siting: Siting,
+) {
+  out.resize(width, height);
+  if width == 0 || height == 0 {
+    return;
+  }
+
+  let (cw, ch) = Subsampling::Yuv422.chroma_dims(width, height);
+
+  let mut y = vec![0; width * height];
+  let (mut sub_u, mut sub_v) = (vec![0; cw * ch], vec![0; cw * ch]);
+
+  for (row, group) in data.chunks_exact(4 * cw).enumerate() {
+    for (col, p) in group.chunks_exact(4).enumerate() {
+      let x = 2 * col;
+
+      y[row * width + x] = p[order[0]];
+      if x + 1 < width {
+        y[row * width + x + 1] = p[order[2]];
+      }
+
+      sub_u[row * cw + col] = p[order[1]];
+      sub_v[row * cw + col] = p[order[3]];
+    }
+  }
+
+  let (mut u, mut v) = (vec![0; width * height], vec![0; width * height]);
+
+  let dims = (width, height);
+  upsample_chroma(&sub_u, dims, Subsampling::Yuv422, siting, &mut u);
+  upsample_chroma(&sub_v, dims, Subsampling::Yuv422, siting, &mut v);
+
+  decode_planes(&y, &u, &v, out, encoding);
+}
+
+fn packed_422_len(width: usize, height: usize) -> Result<usize, crate::Error> {
+  crate::plane_len(width.div_ceil(2), height)?
+    .checked_mul(4)
+    .ok_or(crate::Error::DimensionOverflow)
+}
+
+/// `scales` returns the `(offset, luma scale, chroma scale)` that map full range values to the range of `encoding`.
+///
+fn scales(range: Range) -> (i32, f64, f64) {
+  match range {
+    Range::Full => (0, 1.0, 1.0),
+    Range::Limited => (16, 219.0 / 255.0, 224.0 / 255.0),
+  }
+}
+
+/// `EncodeCoefficients` holds the `Q15` fixed point matrix that maps `RGB` to `Y'CbCr`.
+///
+struct EncodeCoefficients {
+  y_offset: i32,
+  y: [i32; 3],
+  cb: [i32; 3],
+  cr: [i32; 3],
+}
+
+impl EncodeCoefficients {
+  const SHIFT: u32 = 15;
+
+  fn new(encoding: Encoding) -> Self {
+    let [kr, kg, kb] = encoding.matrix.coefficients();
+    let (y_offset, ys, cs) = scales(encoding.range);
+
+    let fixed = |x: f64| (x * f64::from(1 << Self::SHIFT)).round() as i32;
+    let (blue_scale, red_scale) = (cs / (2.0 * (1.0 - kb)), cs / (2.0 * (1.0 - kr)));
+
+    Self {
+      y_offset,
+      y: [fixed(ys * kr), fixed(ys * kg), fixed(ys * kb)],
+      cb: [
+        fixed(-blue_scale * kr),
+        fixed(-blue_scale * kg),
+        fixed(blue_scale * (1.0 - kb)),
+      ],
+      cr: [
+        fixed(red_scale * (1.0 - kr)),
+        fixed(-red_scale * kg),
+        fixed(-red_scale * kb),
+      ],
+    }
+  }
+
+  fn apply(&self, rgb: [u8; 3]) -> [u8; 3] {
+    let dot = |k: &[i32; 3]| {
+      let sum: i32 = k.iter().zip(rgb).map(|(k, x)| k * i32::from(x)).sum();
+      (sum + (1 << (Self::SHIFT - 1))) >> Self::SHIFT
+    };
+
+    [
+      saturate(self.y_offset + dot(&self.y)),
+      saturate(128 + dot(&self.cb)),
+      saturate(128 + dot(&self.cr)),
+    ]
+  }
+}
+
+/// `DecodeCoefficients` holds the `Q13` fixed point matrix that maps `Y'CbCr` to `RGB`. The coefficients fit in an
+/// `i16` so that the vectorized path can use `pmaddwd`.
+///
+struct DecodeCoefficients {
+  y_offset: i32,
+  y: i32,
+  r_cr: i32,
+  g_cb: i32,
+  g_cr: i32,
+  b_cb: i32,
+}
+
+impl DecodeCoefficients {
+  const SHIFT: u32 = 13;
+
+  fn new(encoding: Encoding) -> Self {
+    let [kr, kg, kb] = encoding.matrix.coefficients();
+    let (y_offset, ys, cs) = scales(encoding.range);
+
+    let fixed = |x: f64| (x * f64::from(1 << Self::SHIFT)).round() as i32;
+    let (r_cr, b_cb) = (2.0 * (1.0 - kr) / cs, 2.0 * (1.0 - kb) / cs);
+
+    Self {
+      y_offset,
+      y: fixed(1.0 / ys),
+      r_cr: fixed(r_cr),
+      g_cb: fixed(b_cb * kb / kg),
+      g_cr: fixed(r_cr * kr / kg),
+      b_cb: fixed(b_cb),
+    }
+  }
+
+  fn apply(&self, [y, cb, cr]: [u8; 3]) -> [u8; 3] {
+    let y = self.y * (i32::from(y) - self.y_offset) + (1 << (Self::SHIFT - 1));
+    let (cb, cr) = (i32::from(cb) - 128, i32::from(cr) - 128);
+
+    [
+      saturate((y + self.r_cr * cr) >> Self::SHIFT),
+      saturate((y - self.g_cb * cb - self.g_cr * cr) >> Self::SHIFT),
+      saturate((y + self.b_cb * cb) >> Self::SHIFT),
+    ]
+  }
+}
+
+fn saturate(x: i32) -> u8 {
+  x.clamp(0, 255) as u8
+}
+
+#[cfg(target_arch = "x86_64")]
+mod simd {
+  use core::arch::x86_64::{
+    __m128i, _mm_add_epi32, _mm_loadl_epi64, _mm_madd_epi16, _mm_packs_epi32, _mm_packus_epi16,
+    _mm_set1_epi16, _mm_set1_epi32, _mm_setzero_si128, _mm_srai_epi32, _mm_storel_epi64,
+    _mm_sub_epi16, _mm_unpackhi_epi16, _mm_unpacklo_epi16, _mm_unpacklo_epi8,
+  };
+
+  /// `pair` packs two `i16` coefficients into the lanes `pmaddwd` multiplies the low and high halves of each 32-bit
+  /// element by.
+  ///
+  fn pair(lo: i32, hi: i32) -> i32 {
+    (hi << 16) | (lo & 0xffff)
+  }
+
+  /// Converts as many full blocks of 8 pixels as possible, returning the number of pixels written. The arithmetic is
+  /// identical to `DecodeCoefficients::apply` so the scalar remainder matches bit for bit.
+  ///
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn ycbcr_to_rgb(
+    y: &[u8],
+    u: &[u8],
+    v: &[u8],
+    [r, g, b]: [&mut [u8]; 3],
+    k: &super::DecodeCoefficients,
+  ) -> usize {
+    let n = y.len().min(u.len()).min(v.len()).min(r.len()) / 8 * 8;
+
+    let zero = _mm_setzero_si128();
+    let round = _mm_set1_epi32(1 << (super::DecodeCoefficients::SHIFT - 1));
+
+    let ky = _mm_set1_epi32(pair(k.y, 0));
+    let kr = _mm_set1_epi32(pair(k.r_cr, 0));
+    let kb = _mm_set1_epi32(pair(k.b_cb, 0));
+    let kg = _mm_set1_epi32(pair(-k.g_cb, -k.g_cr));
+
+    let load = |p: *const u8, offset: i32| {
+      _mm_sub_epi16(
+        _mm_unpacklo_epi8(_mm_loadl_epi64(p.cast::<__m128i>()), zero),
+        _mm_set1_epi16(offset as i16),
+      )
+    };
+
+    let finish = |lo: __m128i, hi: __m128i, out: *mut u8| {
+      let lo = _mm_srai_epi32(lo, super::DecodeCoefficients::SHIFT as i32);
+      let hi = _mm_srai_epi32(hi, super::DecodeCoefficients::SHIFT as i32);
+      let v = _mm_packs_epi32(lo, hi);
+      _mm_storel_epi64(out.cast::<__m128i>(), _mm_packus_epi16(v, v));
+    };
+
+    let mut i = 0;
+    while i < n {
+      let vy = load(y.as_ptr().add(i), k.y_offset);
+      let vu = load(u.as_ptr().add(i), 128);
+      let vv = load(v.as_ptr().add(i), 128);
+
+      let y_lo = _mm_add_epi32(_mm_madd_epi16(_mm_unpacklo_epi16(vy, zero), ky), round);
+      let y_hi = _mm_add_epi32(_mm_madd_epi16(_mm_unpackhi_epi16(vy, zero), ky), round);
+
+      let r_lo = _mm_madd_epi16(_mm_unpacklo_epi16(vv, zero), kr);
+      let r_hi = _mm_madd_epi16(_mm_unpackhi_epi16(vv, zero), kr);
+      finish(
+        _mm_add_epi32(y_lo, r_lo),
+        _mm_add_epi32(y_hi, r_hi),
+        r.as_mut_ptr().add(i),
+      );
+
+      let g_lo = _mm_madd_epi16(_mm_unpacklo_epi16(vu, vv), kg);
+      let g_hi = _mm_madd_epi16(_mm_unpackhi_epi16(vu, vv), kg);
+      finish(
+        _mm_add_epi32(y_lo, g_lo),
+        _mm_add_epi32(y_hi, g_hi),
+        g.as_mut_ptr().add(i),
+      );
+
+      let b_lo = _mm_madd_epi16(_mm_unpacklo_epi16(vu, zero), kb);
+      let b_hi = _mm_madd_epi16(_mm_unpackhi_epi16(vu, zero), kb);
+      finish(
+        _mm_add_epi32(y_lo, b_lo),
+        _mm_add_epi32(y_hi, b_hi),
+        b.as_mut_ptr().add(i),
+      );
+
+      i += 8;
+    }
+
+    n
+  }
+}
@@ -0,0 +1,122 @@
+extern crate cvr;
+
+use cvr::balance::Gains;
+
+// a neutral scene seen under an illuminant that halves red and quarters blue, so that every estimate is exact
+//
+fn make_tinted() -> cvr::rgb::Image<f32> {
+  cvr::rgb::Image::from_vec(
+    vec![0.125_f32, 0.25, 0.5, 0.0625, 0.375, 0.25, 0.125, 0.25],
+    vec![0.25_f32, 0.5, 1.0, 0.125, 0.75, 0.5, 0.25, 0.5],
+    vec![
+      0.0625_f32, 0.125, 0.25, 0.031_25, 0.1875, 0.125, 0.0625, 0.125,
+    ],
+    4,
+    2,
+  )
+  .unwrap()
+}
+
+#[test]
+fn white_balance_gains() {
+  assert_eq!(Gains::default(), Gains::IDENTITY);
+  assert_eq!(
+    Gains::new(2.0, 1.0, 0.5).apply([0.25, 0.5, 1.0]),
+    [0.5, 0.5, 0.5]
+  );
+
+  assert_eq!(
+    Gains::from_neutral([0.25, 0.5, 1.0]),
+    Gains::new(2.0, 1.0, 0.5)
+  );
+  assert_eq!(Gains::from_neutral([0.0, 0.5, f32::NAN]), Gains::IDENTITY);
+  assert_eq!(Gains::from_neutral([0.5, 0.0, 0.5]), Gains::IDENTITY);
+
+  let img = make_tinted();
+
+  let mut balanced = cvr::rgb::Image::new();
+  cvr::balance::apply(&img, &mut balanced, Gains::new(2.0, 1.0, 4.0));
+
+  assert_eq!(
+    (balanced.width(), balanced.height()),
+    (img.width(), img.height())
+  );
+  assert_eq!(balanced.r(), img.g());
+  assert_eq!(balanced.g(), img.g());
+  assert_eq!(balanced.b(), img.g());
+}
+
+#[test]
+fn white_balance_estimators() {
+  let expected = Gains::new(2.0, 1.0, 4.0);
+
+  let img = make_tinted();
+  assert_eq!(cvr::balance::gray_world(&img), expected);
+  assert_eq!(cvr::balance::white_patch(&img, 100.0), expected);
+  assert_eq!(cvr::balance::white_patch(&img, 50.0), expected);
+  assert_eq!(
+    cvr::balance::reference_patch(&img, 1, 0, 2, 2),
+    Ok(expected)
+  );
+
+  assert_eq!(
+    cvr::balance::reference_patch(&img, 3, 0, 2, 2),
+    Err(cvr::Error::InvalidRoi)
+  );
+
+  // `NaN` values are ignored
+  //
+  let mut nan = make_tinted();
+  let (r, g, b) = nan.rgb_mut();
+  r[0] = f32::NAN;
+  g[0] = f32::NAN;
+  b[0] = f32::NAN;
+  assert_eq!(cvr::balance::gray_world(&nan), expected);
+
+  // a single hot pixel dominates the maximum but not the lower percentiles
+  //
+  let mut hot = make_tinted();
+  hot.rgb_mut().2[2] = 100.0;
+
+  assert_eq!(cvr::balance::white_patch(&hot, 100.0).b, 0.01);
+  assert_eq!(cvr::balance::white_patch(&hot, 75.0), expected);
+}
+
+#[test]
+fn white_balance_temperature() {
+  let daylight = Gains::from_temperature(6504.0);
+  assert!((daylight.r - 1.0).abs() < 2e-3 && (daylight.b - 1.0).abs() < 2e-3);
+  assert_eq!(daylight.g, 1.0);
+  assert_eq!(
+    Gains::from_temperature(30000.0),
+    Gains::from_temperature(25000.0)
+  );
+
+  // warm light is corrected by attenuating red and amplifying blue, cool light the reverse
+  //
+  let mut previous = Gains::from_temperature(1000.0);
+  assert_eq!(previous, Gains::from_temperature(2000.0));
+
+  for kelvin in [
+    2856.0, 3999.0, 4000.0, 4500.0, 5000.0, 6504.0, 8000.0, 12000.0, 25000.0,
+  ] {
+    let gains = Gains::from_temperature(kelvin);
+    assert!(gains.r > previous.r && gains.b < previous.b, "{}", kelvin);
+    assert_eq!(gains.g, 1.0);
+    previous = gains;
+  }
+
+  let tungsten = Gains::from_temperature(2856.0);
+  assert!(tungsten.r < 1.0 && tungsten.b > 1.0);
+
+  let shade = Gains::from_temperature(12000.0);
+  assert!(shade.r > 1.0 && shade.b < 1.0);
+
+  // the gains turn the illuminant itself back into a neutral white
+  //
+  let illuminant = cvr::convert::xyz_to_linear(
+    cvr::convert::WhitePoint::from_chromaticity(0.447_58, 0.407_45).xyz(),
+  );
+  let [r, g, b] = tungsten.apply(illuminant);
+  assert!((r - g).abs() < 5e-3 && (b - g).abs() < 5e-3);
+}
@@ -19,23 +19,17 @@ fn debayer_parrot() {
     *b = (cvr::convert::srgb_to_linear(*b) * 255.0) as u8;
   });
 
-  let mut bayered_data = minivec::mini_vec![0_u8; img_rgb8.total()];
-
-  for row_idx in 0..img_rgb8.height() {
-    for col_idx in 0..img_rgb8.width() {
-      fn is_even(x: usize) -> bool {
-        x % 2 == 0
-      }
-
-      let idx = row_idx * img_rgb8.width() + col_idx;
-
-      bayered_data[idx] = match (is_even(row_idx), is_even(col_idx)) {
-        (true, true) => img_rgb8.r()[idx],
-        (true, false) | (false, true) => img_rgb8.g()[idx],
-        (false, false) => img_rgb8.b()[idx],
-      };
-    }
-  }
+  #[allow(clippy::manual_is_multiple_of)]
+  let is_even = |x: usize| x % 2 == 0;
+
+  let bayered_data: minivec::MiniVec<u8> = img_rgb8
+    .enumerate_pixels()
+    .map(|(x, y, [r, g, b])| match (is_even(y), is_even(x)) {
+      (true, true) => r,
+      (true, false) | (false, true) => g,
+      (false, false) => b,
+    })
+    .collect();
 
   cvr::png::write_gray8(
     std::fs::File::create("tests/images/output/bayered-parrot.png").unwrap(),
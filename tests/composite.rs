@@ -0,0 +1,270 @@
+extern crate cvr;
+
+use cvr::composite::Operator;
+
+const OPERATORS: [Operator; 5] = [
+  Operator::Over,
+  Operator::In,
+  Operator::Out,
+  Operator::Atop,
+  Operator::Xor,
+];
+
+// a straight alpha source whose values are all multiples of powers of two, so that compositing is exact
+//
+fn make_src() -> cvr::rgba::Image<f32> {
+  cvr::rgba::Image::from_vec(
+    vec![1.0_f32, 0.5, 0.25, 0.0, 0.75, 0.5],
+    vec![0.0_f32, 0.25, 0.5, 1.0, 0.25, 0.125],
+    vec![0.5_f32, 0.75, 0.0, 0.25, 1.0, 0.375],
+    vec![1.0_f32, 0.5, 0.25, 0.0, 0.75, 0.5],
+    3,
+    2,
+  )
+  .unwrap()
+}
+
+fn make_dst() -> cvr::rgba::Image<f32> {
+  cvr::rgba::Image::from_vec(
+    vec![
+      0.0_f32, 0.25, 0.5, 0.75, 1.0, 0.125, 0.375, 0.625, 0.875, 0.5, 0.25, 0.0, 1.0, 0.75, 0.5,
+      0.5, 0.125, 0.0, 0.25, 1.0,
+    ],
+    vec![
+      1.0_f32, 0.75, 0.5, 0.25, 0.0, 0.5, 0.125, 0.875, 0.25, 0.625, 0.0, 0.5, 0.375, 1.0, 0.25,
+      0.75, 0.5, 0.125, 0.0, 0.25,
+    ],
+    vec![
+      0.5_f32, 0.5, 0.0, 1.0, 0.25, 0.75, 0.625, 0.0, 0.125, 0.375, 1.0, 0.25, 0.5, 0.0, 0.875,
+      0.125, 0.75, 0.5, 0.25, 0.0,
+    ],
+    vec![
+      1.0_f32, 0.75, 0.5, 0.25, 0.0, 1.0, 0.875, 0.625, 0.375, 0.125, 0.5, 1.0, 0.25, 0.75, 0.0,
+      0.5, 1.0, 0.25, 0.125, 0.75,
+    ],
+    5,
+    4,
+  )
+  .unwrap()
+}
+
+#[test]
+fn premultiplied_alpha() {
+  let img = make_src();
+
+  let mut premultiplied = cvr::rgba::Image::new();
+  cvr::rgba::cvt_premultiply(&img, &mut premultiplied);
+  assert_eq!(
+    premultiplied.rgba_iter().collect::<Vec<_>>(),
+    [
+      [1.0, 0.0, 0.5, 1.0],
+      [0.25, 0.125, 0.375, 0.5],
+      [0.0625, 0.125, 0.0, 0.25],
+      [0.0, 0.0, 0.0, 0.0],
+      [0.5625, 0.1875, 0.75, 0.75],
+      [0.25, 0.0625, 0.1875, 0.5],
+    ]
+  );
+
+  // transparent pixels lose their color
+  //
+  let mut straight = cvr::rgba::Image::new();
+  cvr::rgba::cvt_unpremultiply(&premultiplied, &mut straight);
+  assert_eq!(
+    straight.rgba_iter().collect::<Vec<_>>(),
+    [
+      [1.0, 0.0, 0.5, 1.0],
+      [0.5, 0.25, 0.75, 0.5],
+      [0.25, 0.5, 0.0, 0.25],
+      [0.0, 0.0, 0.0, 0.0],
+      [0.75, 0.25, 1.0, 0.75],
+      [0.5, 0.125, 0.375, 0.5],
+    ]
+  );
+
+  assert_eq!(
+    cvr::composite::unpremultiply([0.5, 0.5, 0.5, 0.0]),
+    [0.0; 4]
+  );
+
+  // 8-bit values round and saturate
+  //
+  let img = cvr::rgba::Image::from_vec(
+    vec![255_u8, 100, 9],
+    vec![0_u8, 50, 9],
+    vec![128_u8, 25, 9],
+    vec![255_u8, 128, 0],
+    3,
+    1,
+  )
+  .unwrap();
+
+  let mut premultiplied = cvr::rgba::Image::new();
+  cvr::rgba::cvt_premultiply(&img, &mut premultiplied);
+  assert_eq!(
+    premultiplied.rgba_iter().collect::<Vec<_>>(),
+    [[255, 0, 128, 255], [50, 25, 13, 128], [0, 0, 0, 0]]
+  );
+
+  let mut straight = cvr::rgba::Image::new();
+  cvr::rgba::cvt_unpremultiply(&premultiplied, &mut straight);
+  assert_eq!(
+    straight.rgba_iter().collect::<Vec<_>>(),
+    [[255, 0, 128, 255], [100, 50, 26, 128], [0, 0, 0, 0]]
+  );
+
+  let overflow =
+    cvr::rgba::Image::from_vec(vec![200_u8], vec![0], vec![0], vec![100], 1, 1).unwrap();
+  cvr::rgba::cvt_unpremultiply(&overflow, &mut straight);
+  assert_eq!(straight.get(0, 0), Some([255, 0, 0, 100]));
+}
+
+#[test]
+fn porter_duff_operators() {
+  let src = [0.5, 0.25, 0.0, 0.5];
+  let dst = [0.0, 0.0, 0.75, 0.75];
+
+  let expected = [
+    (Operator::Over, [0.5, 0.25, 0.375, 0.875]),
+    (Operator::In, [0.375, 0.1875, 0.0, 0.375]),
+    (Operator::Out, [0.125, 0.0625, 0.0, 0.125]),
+    (Operator::Atop, [0.375, 0.1875, 0.375, 0.75]),
+    (Operator::Xor, [0.125, 0.0625, 0.375, 0.5]),
+  ];
+
+  for (op, expected) in &expected {
+    assert_eq!(op.apply(src, dst), *expected, "{:?}", op);
+  }
+
+  assert_eq!(Operator::default(), Operator::Over);
+
+  // an opaque source covers the destination entirely while a transparent one leaves it untouched
+  //
+  let opaque = [0.2, 0.4, 0.6, 1.0];
+  assert_eq!(Operator::Over.apply(opaque, dst), opaque);
+  assert_eq!(Operator::Over.apply([0.0; 4], dst), dst);
+  assert_eq!(Operator::Atop.apply([0.0; 4], dst), dst);
+  assert_eq!(Operator::Xor.apply([0.0; 4], dst), dst);
+}
+
+#[test]
+fn composite_images() {
+  use cvr::composite::{premultiply, unpremultiply};
+
+  let (src, dst) = (make_src(), make_dst());
+
+  let (mut src_pre, mut dst_pre) = (cvr::rgba::Image::new(), cvr::rgba::Image::new());
+  cvr::rgba::cvt_premultiply(&src, &mut src_pre);
+  cvr::rgba::cvt_premultiply(&dst, &mut dst_pre);
+
+  for op in OPERATORS {
+    for (x, y) in [
+      (0, 0),
+      (1, 2),
+      (-1, -1),
+      (4, 3),
+      (-3, 0),
+      (5, 0),
+      (isize::MIN, 0),
+      (0, isize::MAX),
+      (isize::MAX, isize::MIN),
+    ] {
+      let mut out = dst.clone();
+      cvr::composite::composite(&src, &mut out, x, y, op);
+
+      // compositing premultiplied images skips the conversions but blends the same values
+      //
+      let mut out_pre = dst_pre.clone();
+      cvr::composite::composite_premultiplied(&src_pre, &mut out_pre, x, y, op);
+
+      for (col, row, pixel) in out.enumerate_pixels() {
+        let offset = |idx: usize, offset: isize| (idx as isize).checked_sub(offset);
+        let (sx, sy) = (offset(col, x), offset(row, y));
+        let inside =
+          sx.is_some_and(|sx| (0..3).contains(&sx)) && sy.is_some_and(|sy| (0..2).contains(&sy));
+
+        let d = premultiply(dst.get(col, row).unwrap());
+        let s = if inside {
+          premultiply(src.get(sx.unwrap() as usize, sy.unwrap() as usize).unwrap())
+        } else {
+          [0.0; 4]
+        };
+
+        let covered = inside || op == Operator::In || op == Operator::Out;
+        let (expected, straight) = if covered {
+          let blended = op.apply(s, d);
+          (blended, unpremultiply(blended))
+        } else {
+          (d, dst.get(col, row).unwrap())
+        };
+
+        assert_eq!(pixel, straight, "{:?} {:?}", op, (x, y));
+        assert_eq!(
+          out_pre.get(col, row),
+          Some(expected),
+          "{:?} {:?}",
+          op,
+          (x, y)
+        );
+      }
+    }
+  }
+
+  // the source's top-left pixel is opaque and replaces the destination's while its transparent one leaves it be
+  //
+  let mut out = dst.clone();
+  cvr::composite::composite(&src, &mut out, 1, 1, Operator::Over);
+  assert_eq!(out.get(1, 1), Some([1.0, 0.0, 0.5, 1.0]));
+  assert_eq!(out.get(1, 2), dst.get(1, 2));
+}
+
+#[test]
+fn composite_srgb_images() {
+  let src = cvr::rgba::Image::from_vec(
+    vec![255_u8, 0, 30, 200],
+    vec![0_u8, 255, 60, 100],
+    vec![0_u8, 0, 90, 50],
+    vec![128_u8, 255, 0, 64],
+    2,
+    2,
+  )
+  .unwrap();
+
+  let dst = cvr::rgba::Image::from_vec(
+    vec![0_u8, 10, 20, 30, 40, 50],
+    vec![0_u8, 60, 70, 80, 90, 100],
+    vec![255_u8, 110, 120, 130, 140, 150],
+    vec![255_u8, 255, 200, 100, 50, 0],
+    3,
+    2,
+  )
+  .unwrap();
+
+  for op in OPERATORS {
+    let mut out = dst.clone();
+    cvr::composite::composite_srgb(&src, &mut out, 1, 0, op);
+
+    let (mut linear_src, mut linear_dst) = (cvr::rgba::Image::new(), cvr::rgba::Image::new());
+    src.to_linear(&mut linear_src);
+    dst.to_linear(&mut linear_dst);
+    cvr::composite::composite(&linear_src, &mut linear_dst, 1, 0, op);
+
+    let mut expected = cvr::rgba::Image::new();
+    linear_dst.to_srgb(&mut expected);
+
+    assert!(out == expected);
+  }
+
+  // uncovered pixels survive the trip through linear light unchanged
+  //
+  let mut out = dst.clone();
+  cvr::composite::composite_srgb(&src, &mut out, 2, 0, Operator::Over);
+  assert_eq!(out.get(0, 0), dst.get(0, 0));
+  assert_eq!(out.get(1, 1), dst.get(1, 1));
+
+  // half transparent red over opaque blue mixes their light rather than their encoded values
+  //
+  let mut out = dst.clone();
+  cvr::composite::composite_srgb(&src, &mut out, 0, 0, Operator::Over);
+  assert_eq!(out.get(0, 0), Some([188, 0, 187, 255]));
+}
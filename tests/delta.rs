@@ -0,0 +1,114 @@
+extern crate cvr;
+
+use cvr::delta::Metric;
+
+// sample pairs and their expected CIEDE2000 values taken from the test data published alongside
+// "The CIEDE2000 Color-Difference Formula: Implementation Notes, Supplementary Test Data, and Mathematical
+// Observations" by Sharma, Wu and Dalal
+//
+const CIEDE2000_PAIRS: [([f32; 3], [f32; 3], f32); 8] = [
+  ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+  ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+  ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+  ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0011], 7.2195),
+  ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+  ([50.0, 2.5, 0.0], [50.0, 3.1736, 0.5854], 1.0),
+  (
+    [60.2574, -34.0099, 36.2677],
+    [60.4626, -34.1751, 39.4387],
+    1.2644,
+  ),
+  ([2.0776, 0.0795, -1.135], [0.9033, -0.0636, -0.5514], 0.9082),
+];
+
+#[test]
+fn delta_e_metrics() {
+  for (x, y, expected) in &CIEDE2000_PAIRS {
+    let d = cvr::delta::ciede2000(*x, *y);
+    assert!(
+      (d - expected).abs() < 1e-4,
+      "{:?} {:?}: {} != {}",
+      x,
+      y,
+      d,
+      expected
+    );
+
+    // CIEDE2000 is symmetric
+    //
+    assert!((cvr::delta::ciede2000(*y, *x) - d).abs() < 1e-4);
+  }
+
+  let (x, y) = ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0]);
+  assert!((cvr::delta::cie76(x, y) - 36.8680).abs() < 1e-4);
+  assert!((cvr::delta::cie94(x, y) - 34.6892).abs() < 1e-4);
+  assert!(
+    (cvr::delta::cie94([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485]) - 1.3950).abs() < 1e-4
+  );
+
+  for metric in [Metric::Cie76, Metric::Cie94, Metric::Ciede2000] {
+    assert!(metric.distance(x, x).abs() < 1e-6);
+  }
+
+  assert_eq!(Metric::default(), Metric::Ciede2000);
+}
+
+#[test]
+fn delta_e_map() {
+  let reference = cvr::rgb::Image::from_vec(
+    vec![10_u8, 128, 250, 0],
+    vec![200_u8, 128, 40, 0],
+    vec![90_u8, 128, 60, 255],
+    2,
+    2,
+  )
+  .unwrap();
+
+  let mut sample = reference.clone();
+  sample.rgb_mut().0[1] = 138;
+  sample.rgb_mut().2[3] = 235;
+
+  let map = cvr::delta::map(&reference, &sample, Metric::Cie76);
+  assert_eq!((map.width(), map.height()), (2, 2));
+  assert_eq!(map.v()[0], 0.0);
+  assert_eq!(map.v()[2], 0.0);
+  assert!(map.v()[1] > 1.0 && map.v()[3] > 1.0);
+
+  let mut linear = cvr::rgb::Image::new();
+  let (mut x, mut y) = (cvr::rgb::Image::new(), cvr::rgb::Image::new());
+
+  reference.to_linear(&mut linear);
+  cvr::rgb::cvt_linear_to_lab(&linear, &mut x, cvr::convert::CieReference::default());
+  sample.to_linear(&mut linear);
+  cvr::rgb::cvt_linear_to_lab(&linear, &mut y, cvr::convert::CieReference::default());
+
+  assert!(cvr::delta::map_lab(&x, &y, Metric::Cie76) == map);
+
+  let stats = cvr::delta::Stats::from_map(&map);
+  let max = map.v()[1].max(map.v()[3]);
+  let min = map.v()[1].min(map.v()[3]);
+
+  assert_eq!(stats.max, max);
+  assert!((stats.mean - (max + min) / 4.0).abs() < 1e-5);
+  assert_eq!(stats.median, 0.0);
+  assert_eq!(stats.p95, max);
+  assert_eq!(stats.p99, max);
+
+  assert_eq!(cvr::delta::percentile(&map, 0.0), 0.0);
+  assert_eq!(cvr::delta::percentile(&map, 75.0), min);
+  assert_eq!(cvr::delta::percentile(&map, 100.0), max);
+
+  assert_eq!(
+    cvr::delta::Stats::from_map(&cvr::gray::Image::new()),
+    cvr::delta::Stats::default()
+  );
+}
+
+#[test]
+#[should_panic(expected = "image dimensions must match")]
+fn delta_e_map_dimensions() {
+  let x = cvr::rgb::Image::from_vec(vec![0_u8; 2], vec![0; 2], vec![0; 2], 2, 1).unwrap();
+  let y = cvr::rgb::Image::from_vec(vec![0_u8; 2], vec![0; 2], vec![0; 2], 1, 2).unwrap();
+
+  let _ = cvr::delta::map(&x, &y, Metric::default());
+}
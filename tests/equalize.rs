@@ -0,0 +1,178 @@
+extern crate cvr;
+
+use cvr::equalize::Clahe;
+
+fn range(img: &cvr::gray::Image<u8>) -> u8 {
+  img.iter().max().unwrap() - img.iter().min().unwrap()
+}
+
+// a faint diagonal gradient with a brighter square in its center
+//
+fn make_faint(width: usize, height: usize) -> cvr::gray::Image<u8> {
+  let v = (0..width * height)
+    .map(|i| {
+      let (x, y) = (i % width, i / width);
+      let bright =
+        (width / 4..3 * width / 4).contains(&x) && (height / 4..3 * height / 4).contains(&y);
+      90 + ((x + y) % 32) as u8 + if bright { 20 } else { 0 }
+    })
+    .collect();
+
+  cvr::gray::Image::from_vec(v, width, height).unwrap()
+}
+
+#[test]
+fn equalize_global() {
+  let img = cvr::gray::Image::from_vec(vec![50_u8, 50, 51, 52, 60, 60], 3, 2).unwrap();
+
+  let mut out = cvr::gray::Image::new();
+  cvr::equalize::global(&img, &mut out);
+  assert_eq!(out.v(), [0, 0, 64, 128, 255, 255]);
+
+  let img = make_faint(40, 30);
+  cvr::equalize::global(&img, &mut out);
+  assert_eq!((out.width(), out.height()), (40, 30));
+  assert!(range(&img) < 64 && range(&out) == 255);
+
+  // the mapping preserves the ordering of values
+  //
+  let pairs: Vec<_> = img.iter().zip(out.iter()).collect();
+  assert!(pairs
+    .iter()
+    .all(|(a, b)| pairs.iter().all(|(c, d)| a <= c || b >= d)));
+
+  // constant and empty images are left unchanged
+  //
+  let constant = cvr::gray::Image::from_vec(vec![77_u8; 12], 4, 3).unwrap();
+  cvr::equalize::global(&constant, &mut out);
+  assert!(out == constant);
+
+  cvr::equalize::global(&cvr::gray::Image::new(), &mut out);
+  assert_eq!(out.total(), 0);
+}
+
+#[test]
+fn equalize_clahe() {
+  let img = cvr::gray::Image::from_vec(vec![50_u8, 50, 51, 52, 60, 60], 3, 2).unwrap();
+
+  // a single unclipped tile maps every value through the cumulative histogram
+  //
+  let mut out = cvr::gray::Image::new();
+  cvr::equalize::clahe(&img, &mut out, Clahe::new(1, 1, 0.0));
+  assert_eq!(out.v(), [85, 85, 128, 170, 255, 255]);
+
+  let mut unclipped = cvr::gray::Image::new();
+  cvr::equalize::clahe(&img, &mut unclipped, Clahe::new(1, 1, 1000.0));
+  assert!(out == unclipped);
+
+  // grids larger than the image are reduced instead of rejected
+  //
+  cvr::equalize::clahe(&img, &mut out, Clahe::new(16, 16, 2.0));
+  assert_eq!((out.width(), out.height()), (3, 2));
+
+  // identical tiles interpolate to the same result as a single tile
+  //
+  let tile = [
+    10_u8, 20, 20, 30, 40, 40, 40, 200, 10, 20, 30, 30, 50, 60, 70, 80,
+  ];
+  let v = (0..12 * 8)
+    .map(|i| tile[(i / 12 % 4) * 4 + i % 4])
+    .collect();
+  let img = cvr::gray::Image::from_vec(v, 12, 8).unwrap();
+
+  let mut single = cvr::gray::Image::new();
+  cvr::equalize::clahe(&img, &mut single, Clahe::new(1, 1, 0.0));
+  cvr::equalize::clahe(&img, &mut out, Clahe::new(3, 2, 0.0));
+  assert!(out == single);
+
+  // clipping limits how far the contrast is stretched
+  //
+  let img = make_faint(64, 48);
+  let mut clipped = cvr::gray::Image::new();
+  cvr::equalize::clahe(&img, &mut clipped, Clahe::new(4, 4, 1.5));
+  cvr::equalize::clahe(&img, &mut unclipped, Clahe::new(4, 4, 0.0));
+
+  assert!(range(&img) < range(&clipped) && range(&clipped) < range(&unclipped));
+
+  let mut default = cvr::gray::Image::new();
+  cvr::equalize::clahe(&img, &mut default, Clahe::default());
+  assert_eq!((default.width(), default.height()), (64, 48));
+
+  assert!(std::panic::catch_unwind(|| {
+    cvr::equalize::clahe(&img, &mut cvr::gray::Image::new(), Clahe::new(0, 4, 2.0))
+  })
+  .is_err());
+}
+
+#[test]
+fn equalize_rgb() {
+  let faint = make_faint(32, 24);
+  let img = cvr::rgb::Image::from_vec(
+    faint.iter().map(|v| v + 30).collect(),
+    faint.v().to_vec(),
+    faint.iter().map(|v| v - 40).collect(),
+    32,
+    24,
+  )
+  .unwrap();
+
+  let hue = |[r, g, b]: [u8; 3]| {
+    let linear = [r, g, b].map(cvr::convert::srgb_to_linear);
+    cvr::convert::linear_to_hsv(linear)[0]
+  };
+
+  for (name, f) in [
+    (
+      "global",
+      Box::new(cvr::equalize::global_rgb) as Box<dyn Fn(&_, &mut _)>,
+    ),
+    (
+      "clahe",
+      Box::new(|x: &_, y: &mut _| cvr::equalize::clahe_rgb(x, y, Clahe::new(2, 2, 4.0))),
+    ),
+  ] {
+    let mut out = cvr::rgb::Image::new();
+    f(&img, &mut out);
+    assert_eq!((out.width(), out.height()), (32, 24));
+
+    // contrast is stretched while the hue of every pixel is kept
+    //
+    let (mut before, mut after) = (cvr::gray::Image::new(), cvr::gray::Image::new());
+    let weights = cvr::convert::GrayWeights::Bt601;
+    cvr::rgb::cvt_rgb_to_gray(&img, &mut before, weights);
+    cvr::rgb::cvt_rgb_to_gray(&out, &mut after, weights);
+    assert!(range(&after) > 2 * range(&before), "{}", name);
+
+    assert!(
+      img
+        .rgb_iter()
+        .zip(out.rgb_iter())
+        .filter(|(_, y)| y.iter().min().unwrap() > &16)
+        .all(|(x, y)| (hue(x) - hue(y)).abs() < 2.0),
+      "{}",
+      name
+    );
+  }
+
+  // gray pixels stay gray, with the same luminance as equalizing a gray image
+  //
+  let gray = cvr::rgb::Image::from_vec(
+    faint.v().to_vec(),
+    faint.v().to_vec(),
+    faint.v().to_vec(),
+    32,
+    24,
+  )
+  .unwrap();
+
+  let mut out = cvr::rgb::Image::new();
+  cvr::equalize::global_rgb(&gray, &mut out);
+
+  let mut expected = cvr::gray::Image::new();
+  cvr::equalize::global(&faint, &mut expected);
+
+  assert!(out
+    .rgb_iter()
+    .zip(expected.iter())
+    .all(|([r, g, b], v)| r == g && g == b && (i16::from(r) - i16::from(*v)).abs() <= 1));
+}
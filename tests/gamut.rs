@@ -0,0 +1,168 @@
+extern crate cvr;
+
+use cvr::convert::gamut::{adaptation_matrix, Adaptation, Conversion, GamutMapping, RgbSpace};
+use cvr::convert::WhitePoint;
+
+fn apply(m: [[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+  m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+#[test]
+fn rgb_space_matrices() {
+  // the matrix derived from the primaries agrees with the constants of `linear_to_xyz` to 7 digits
+  //
+  assert_eq!(
+    RgbSpace::SRGB.to_xyz_matrix(),
+    [
+      [0.412_390_74, 0.357_584_36, 0.180_480_82],
+      [0.212_638_99, 0.715_168_7, 0.072_192_32],
+      [0.019_330_818, 0.119_194_73, 0.950_532_26],
+    ]
+  );
+
+  // white maps onto the reference white of every space
+  //
+  for space in [
+    RgbSpace::SRGB,
+    RgbSpace::DISPLAY_P3,
+    RgbSpace::ADOBE_RGB,
+    RgbSpace::REC2020,
+  ] {
+    assert_eq!(
+      apply(space.to_xyz_matrix(), [1.0, 1.0, 1.0]),
+      WhitePoint::D65.xyz()
+    );
+  }
+  assert_eq!(
+    apply(RgbSpace::PRO_PHOTO.to_xyz_matrix(), [1.0, 1.0, 1.0]),
+    [0.964_295_7, 0.999_999_94, 0.825_104_6]
+  );
+
+  // reference values published by Bruce Lindbloom, whose white points differ slightly from those derived from the
+  // chromaticity coordinates
+  //
+  let bradford = adaptation_matrix(WhitePoint::D65, WhitePoint::D50, Adaptation::Bradford);
+  let expected = [
+    [1.047_811_2, 0.022_886_6, -0.050_127],
+    [0.029_542_4, 0.990_484_4, -0.017_049_1],
+    [-0.009_234_5, 0.015_043_6, 0.752_131_6],
+  ];
+
+  for (x, y) in bradford.iter().flatten().zip(expected.iter().flatten()) {
+    assert!((x - y).abs() < 5e-4, "{} != {}", x, y);
+  }
+
+  for (method, adapted) in [
+    (Adaptation::Bradford, [0.950_455_84, 1.0, 1.089_057_8]),
+    (Adaptation::VonKries, [0.950_455_9, 1.0, 1.089_057_8]),
+    (Adaptation::Cat02, [0.950_455_96, 1.0, 1.089_057_8]),
+  ] {
+    let m = adaptation_matrix(WhitePoint::D50, WhitePoint::D65, method);
+    assert_eq!(apply(m, WhitePoint::D50.xyz()), adapted, "{:?}", method);
+
+    // adapting to the same white point leaves values unchanged
+    //
+    let identity = adaptation_matrix(WhitePoint::D65, WhitePoint::D65, method);
+    for (i, row) in identity.iter().enumerate() {
+      for (j, x) in row.iter().enumerate() {
+        assert!(if i == j { *x == 1.0 } else { x.abs() < 1e-16 });
+      }
+    }
+  }
+}
+
+#[test]
+fn rgb_space_conversions() {
+  let spaces = [
+    RgbSpace::DISPLAY_P3,
+    RgbSpace::ADOBE_RGB,
+    RgbSpace::REC2020,
+    RgbSpace::PRO_PHOTO,
+  ];
+
+  // `sRGB` fits within every one of the wider gamuts, so roundtrips are lossless at 8-bit precision
+  //
+  let to_u8 = |rgb: [f32; 3]| rgb.map(|x| (x * 255.0).round());
+
+  for space in spaces {
+    for method in [
+      Adaptation::Bradford,
+      Adaptation::VonKries,
+      Adaptation::Cat02,
+    ] {
+      let there = Conversion::new(RgbSpace::SRGB, space, method, GamutMapping::Clip);
+      let back = Conversion::new(space, RgbSpace::SRGB, method, GamutMapping::Clip);
+
+      for rgb in [
+        [1.0, 0.0, 0.0],
+        [0.2, 0.4, 0.6],
+        [1.0, 1.0, 1.0],
+        [0.0, 0.0, 0.0],
+      ] {
+        assert_eq!(to_u8(back.convert(there.convert(rgb))), to_u8(rgb));
+      }
+    }
+  }
+
+  // the `Rec.2020` green primary lies far outside of `sRGB`
+  //
+  let green = [0.0, 1.0, 0.0];
+  let luminance = RgbSpace::REC2020.to_xyz_matrix()[1][1];
+  let srgb_luminance = RgbSpace::SRGB.to_xyz_matrix()[1];
+
+  let clip = Conversion::new(
+    RgbSpace::REC2020,
+    RgbSpace::SRGB,
+    Adaptation::default(),
+    GamutMapping::Clip,
+  );
+
+  let unclipped = apply(clip.matrix(), green);
+  assert!(unclipped[0] < 0.0 && unclipped[1] > 1.0 && unclipped[2] < 0.0);
+  assert_eq!(clip.convert_linear(green), [0.0, 1.0, 0.0]);
+
+  let preserve = Conversion::new(
+    RgbSpace::REC2020,
+    RgbSpace::SRGB,
+    Adaptation::default(),
+    GamutMapping::PreserveLuminance,
+  );
+
+  let rgb = preserve.convert_linear(green);
+  assert_eq!(rgb, [0.0, 0.921_687_25, 0.260_917_45]);
+  assert!((apply([srgb_luminance; 3], rgb)[0] - luminance).abs() < 1e-5);
+
+  // in gamut colors are only subject to the rounding of the matrix by both
+  //
+  let gray = [0.5, 0.5, 0.5];
+  assert_eq!(preserve.convert_linear(gray), [0.500_000_06, 0.5, 0.5]);
+  assert_eq!(clip.convert_linear(gray), [0.500_000_06, 0.5, 0.5]);
+}
+
+#[test]
+fn rgb_space_images() {
+  let img = cvr::rgb::Image::from_vec(
+    vec![1.0_f32, 0.0, 0.5, 0.9],
+    vec![0.0_f32, 1.0, 0.5, 0.1],
+    vec![0.0_f32, 0.0, 0.5, 0.3],
+    2,
+    2,
+  )
+  .unwrap();
+
+  let conversion = Conversion::new(
+    RgbSpace::DISPLAY_P3,
+    RgbSpace::SRGB,
+    Adaptation::Bradford,
+    GamutMapping::PreserveLuminance,
+  );
+
+  let mut out = cvr::rgb::Image::new();
+  cvr::rgb::cvt_rgb_space(&img, &mut out, &conversion);
+
+  assert_eq!((out.width(), out.height()), (2, 2));
+  assert!(out
+    .rgb_iter()
+    .eq(img.rgb_iter().map(|rgb| conversion.convert(rgb))));
+  assert!(out.rgb_iter().flatten().all(|x| (0.0..=1.0).contains(&x)));
+}
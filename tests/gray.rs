@@ -0,0 +1,121 @@
+extern crate cvr;
+extern crate minivec;
+
+#[test]
+fn gray_from_plane() {
+  let img = cvr::gray::Image::from_plane(minivec::mini_vec![1_u8, 2, 3, 4], 2, 2).unwrap();
+
+  assert_eq!(img.total(), 4);
+  assert_eq!(img.v(), [1, 2, 3, 4]);
+
+  let img = cvr::gray::Image::from_vec(vec![0_u8; 4], 3, 2);
+  assert_eq!(
+    img.err(),
+    Some(cvr::Error::InvalidPlaneLength {
+      expected: 6,
+      actual: 4
+    })
+  );
+
+  // errors can be propagated as boxed standard errors
+  //
+  let boxed = || -> Result<cvr::gray::Image<u8>, Box<dyn std::error::Error>> {
+    Ok(cvr::gray::Image::from_vec(vec![0_u8; 4], 3, 2)?)
+  };
+  assert_eq!(
+    boxed().err().map(|err| err.to_string()).as_deref(),
+    Some("invalid plane length: expected 6 elements, got 4")
+  );
+}
+
+#[test]
+fn gray_view() {
+  let mut data = [1_u8, 2, 0, 3, 4, 0, 5, 6];
+
+  let view = cvr::gray::ImageRef::new(&data, 2, 3, 3).unwrap();
+  assert_eq!(view.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+  assert_eq!(view.v().len(), 8);
+
+  let mut view = cvr::gray::ImageMut::new(&mut data, 2, 3, 3).unwrap();
+  view.iter_mut().for_each(|x| *x *= 2);
+
+  assert_eq!(data, [2, 4, 0, 6, 8, 0, 10, 12]);
+
+  assert_eq!(
+    cvr::gray::ImageRef::new(&data, 2, 4, 3).err(),
+    Some(cvr::Error::InvalidPlaneLength {
+      expected: 11,
+      actual: 8
+    })
+  );
+}
+
+#[test]
+fn gray_roi() {
+  let img = cvr::gray::Image::from_vec((0_u8..20).collect(), 5, 4).unwrap();
+
+  let roi = img.roi(1, 2, 3, 2).unwrap();
+  let rows: Vec<_> = roi.rows().collect();
+  assert_eq!(rows, [&[11_u8, 12, 13][..], &[16, 17, 18][..]]);
+
+  let inner = roi.roi(2, 1, 1, 1).unwrap();
+  assert_eq!(inner.iter().copied().collect::<Vec<_>>(), [18]);
+}
+
+#[test]
+fn gray_mutation() {
+  let mut img = cvr::gray::Image::<u8>::new();
+  img.resize(3, 2);
+  assert_eq!(img.v(), [0; 6]);
+
+  img.v_mut()[0] = 255;
+  for (idx, x) in img.iter_mut().enumerate().skip(1) {
+    *x = 50 * idx as u8;
+  }
+
+  let mut linear = cvr::gray::Image::new();
+  img.to_linear(&mut linear);
+  assert_eq!(linear.total(), 6);
+  assert_eq!(linear.v()[1], cvr::convert::srgb_to_linear(50));
+
+  let mut srgb = cvr::gray::Image::new();
+  linear.to_srgb(&mut srgb);
+  assert!(srgb == img);
+
+  let copy = img.clone();
+  img.resize(1, 1);
+  assert_eq!(img.v(), [255]);
+  assert_eq!(copy.v(), [255, 50, 100, 150, 200, 250]);
+}
+
+#[test]
+fn gray_split_rows() {
+  let mut img = cvr::gray::Image::from_vec((0_u16..12).collect(), 3, 4).unwrap();
+
+  let rows: Vec<_> = img.rows().collect();
+  assert_eq!(rows[1], [3, 4, 5]);
+
+  img.rows_mut().for_each(|row| row.reverse());
+  assert_eq!(img.v()[..6], [2, 1, 0, 5, 4, 3]);
+
+  let bands = img.split_rows_mut(2);
+  assert_eq!(bands[1].v(), [8, 7, 6, 11, 10, 9]);
+  assert_eq!(bands[1].stride(), 3);
+}
+
+#[test]
+fn gray_pixel_access() {
+  let mut img = cvr::gray::Image::from_vec((0_u8..6).collect(), 3, 2).unwrap();
+
+  assert_eq!(img[(2, 1)], 5);
+  assert_eq!(img.get(0, 1), Some(3));
+  assert_eq!(img.get(3, 0), None);
+  assert_eq!(unsafe { img.get_unchecked(1, 1) }, 4);
+
+  img[(0, 0)] = 7;
+  *img.get_mut(1, 0).unwrap() = 8;
+  assert!(img.get_mut(0, 2).is_none());
+
+  let pixels: Vec<_> = img.enumerate_pixels().skip(1).take(3).collect();
+  assert_eq!(pixels, [(1, 0, 8), (2, 0, 2), (0, 1, 3)]);
+}
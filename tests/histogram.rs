@@ -0,0 +1,155 @@
+extern crate cvr;
+
+use cvr::histogram::{Bins, Histogram, JointHistogram};
+
+#[test]
+fn histogram_bins() {
+  let bins = Bins::new(4, 0.0, 1.0);
+  assert_eq!(bins.count(), 4);
+  assert_eq!(bins.range(), [0.0, 1.0]);
+
+  assert_eq!(bins.index(0.0), Some(0));
+  assert_eq!(bins.index(0.249), Some(0));
+  assert_eq!(bins.index(0.25), Some(1));
+  assert_eq!(bins.index(0.999), Some(3));
+  assert_eq!(bins.index(1.0), Some(3));
+  assert_eq!(bins.index(-0.001), None);
+  assert_eq!(bins.index(1.001), None);
+  assert_eq!(bins.index(f32::NAN), None);
+
+  assert!((0..=255_u8).all(|x| Bins::U8.index(f32::from(x)) == Some(usize::from(x))));
+
+  assert!(std::panic::catch_unwind(|| Bins::new(0, 0.0, 1.0)).is_err());
+  assert!(std::panic::catch_unwind(|| Bins::new(4, 1.0, 1.0)).is_err());
+  assert!(std::panic::catch_unwind(|| Bins::new(4, 0.0, f32::INFINITY)).is_err());
+}
+
+#[test]
+fn histogram_u8() {
+  let img = cvr::gray::Image::from_vec(vec![0_u8, 1, 1, 255, 128, 1], 3, 2).unwrap();
+
+  let hist = cvr::histogram::gray(&img, None);
+  assert_eq!(hist.bins(), Bins::U8);
+  assert_eq!(hist.counts().len(), 256);
+  assert_eq!(hist.total(), 6);
+  assert_eq!(
+    [0, 1, 2, 128, 255].map(|x| hist.counts()[x]),
+    [1, 3, 0, 1, 1]
+  );
+
+  let cumulative = hist.cumulative();
+  assert_eq!(cumulative.len(), 256);
+  assert_eq!([cumulative[0], cumulative[1], cumulative[127]], [1, 4, 4]);
+  assert_eq!([cumulative[128], cumulative[255]], [5, 6]);
+
+  // only pixels with a non-zero mask value are counted
+  //
+  let mask = cvr::gray::Image::from_vec(vec![0_u8, 255, 1, 0, 9, 0], 3, 2).unwrap();
+
+  let masked = cvr::histogram::gray(&img, Some(&mask));
+  assert_eq!(masked.total(), 3);
+  assert_eq!([1, 128].map(|x| masked.counts()[x]), [2, 1]);
+
+  let rgb = cvr::rgb::Image::from_vec(
+    vec![0_u8, 1, 1, 255, 128, 1],
+    vec![7_u8; 6],
+    vec![0_u8, 0, 0, 9, 9, 9],
+    3,
+    2,
+  )
+  .unwrap();
+
+  let [r, g, b] = cvr::histogram::rgb(&rgb, Some(&mask));
+  assert_eq!(r, masked);
+  assert_eq!((g.counts()[7], g.total()), (3, 3));
+  assert_eq!((b.counts()[0], b.counts()[9]), (2, 1));
+
+  let wrong_size = cvr::gray::Image::from_vec(vec![1_u8; 6], 2, 3).unwrap();
+  assert!(std::panic::catch_unwind(|| cvr::histogram::gray(&img, Some(&wrong_size))).is_err());
+}
+
+#[test]
+fn histogram_f32() {
+  let img = cvr::gray::Image::from_vec(
+    vec![0.1_f32, 0.3, 0.35, 0.9, 1.0, -0.5, f32::NAN, 2.0],
+    4,
+    2,
+  )
+  .unwrap();
+
+  let bins = Bins::new(4, 0.0, 1.0);
+  let hist = cvr::histogram::gray_f32(&img, bins, None);
+  assert_eq!(hist.counts(), [1, 2, 0, 2]);
+  assert_eq!(hist.cumulative(), [1, 3, 3, 5]);
+
+  let mask = cvr::gray::Image::from_vec(vec![1_u8, 0, 1, 0, 1, 1, 1, 1], 4, 2).unwrap();
+  let hist = cvr::histogram::gray_f32(&img, bins, Some(&mask));
+  assert_eq!(hist.counts(), [1, 1, 0, 1]);
+
+  let rgb = cvr::rgb::Image::from_vec(
+    vec![0.1_f32, 0.6],
+    vec![0.6_f32, 0.6],
+    vec![0.1_f32, 0.1],
+    2,
+    1,
+  )
+  .unwrap();
+
+  let [r, g, b] = cvr::histogram::rgb_f32(&rgb, Bins::new(2, 0.0, 1.0), None);
+  assert_eq!(
+    [r.counts(), g.counts(), b.counts()],
+    [[1, 1], [0, 2], [2, 0]]
+  );
+
+  let mut manual = Histogram::new(bins);
+  manual.extend(img.iter().copied());
+  assert_eq!(manual, cvr::histogram::gray_f32(&img, bins, None));
+}
+
+#[test]
+fn histogram_joint() {
+  let img = cvr::rgb::Image::from_vec(
+    vec![0.1_f32, 0.9, 0.9, 0.1, 0.5],
+    vec![0.1_f32, 0.1, 0.9, 0.9, 0.5],
+    vec![0.1_f32, 0.1, 0.1, 0.1, 1.5],
+    5,
+    1,
+  )
+  .unwrap();
+
+  let bins = Bins::new(2, 0.0, 1.0);
+
+  let hist = cvr::histogram::joint(&img, [0, 1], [bins; 2], None);
+  assert_eq!(hist.counts(), [1, 1, 1, 2]);
+  assert_eq!(hist.get([1, 0]), Some(1));
+  assert_eq!(hist.get([1, 1]), Some(2));
+  assert_eq!(hist.get([2, 0]), None);
+
+  // pixels with any channel outside of its range are skipped
+  //
+  let hist = cvr::histogram::joint(&img, [0, 1, 2], [bins; 3], None);
+  assert_eq!(hist.total(), 4);
+  assert_eq!(hist.counts().len(), 8);
+  assert_eq!(hist.get([1, 0, 0]), Some(1));
+  assert_eq!(hist.get([1, 1, 1]), Some(0));
+
+  // channels can be selected in any order, including repeatedly
+  //
+  let hist = cvr::histogram::joint(&img, [2, 0], [Bins::new(3, 0.0, 1.5), bins], None);
+  assert_eq!(hist.get([0, 0]), Some(2));
+  assert_eq!(hist.get([0, 1]), Some(2));
+  assert_eq!(hist.get([2, 1]), Some(1));
+
+  let diagonal = cvr::histogram::joint(&img, [0, 0], [bins; 2], None);
+  assert_eq!(diagonal.counts(), [2, 0, 0, 3]);
+
+  let mask = cvr::gray::Image::from_vec(vec![0_u8, 1, 1, 0, 1], 5, 1).unwrap();
+  let hist = cvr::histogram::joint(&img, [0, 1], [bins; 2], Some(&mask));
+  assert_eq!(hist.counts(), [0, 0, 1, 2]);
+
+  let mut manual = JointHistogram::new([bins; 2]);
+  manual.extend(img.rgb_iter().map(|[r, g, _]| [r, g]));
+  assert_eq!(manual, cvr::histogram::joint(&img, [0, 1], [bins; 2], None));
+
+  assert!(std::panic::catch_unwind(|| cvr::histogram::joint(&img, [3], [bins], None)).is_err());
+}
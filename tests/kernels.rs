@@ -0,0 +1,213 @@
+extern crate cvr;
+
+use cvr::convert::kernels;
+
+// lengths that aren't a multiple of any vector width exercise the scalar remainders
+//
+const LEN: usize = 1000 + 13;
+
+fn samples() -> Vec<f32> {
+  let mut x: Vec<f32> = (0..LEN).map(|i| (i as f32 * 0.618_034).fract()).collect();
+  x[..8].copy_from_slice(&[0.0, 1.0, -0.5, 1.5, f32::NAN, f32::INFINITY, -0.0, 0.000_1]);
+  x
+}
+
+#[test]
+fn srgb_kernels() {
+  let srgb: Vec<u8> = (0..LEN).map(|i| (i * 7 % 256) as u8).collect();
+
+  let mut linear = vec![0_f32; LEN];
+  kernels::srgb_to_linear(&srgb, &mut linear);
+  assert!(linear
+    .iter()
+    .zip(srgb.iter())
+    .all(|(x, y)| x.to_bits() == cvr::convert::srgb_to_linear(*y).to_bits()));
+
+  let mut roundtrip = vec![0_u8; LEN];
+  kernels::linear_to_srgb(&linear, &mut roundtrip);
+  assert_eq!(roundtrip, srgb);
+
+  let x = samples();
+  kernels::linear_to_srgb(&x, &mut roundtrip);
+  assert!(roundtrip
+    .iter()
+    .zip(x.iter())
+    .all(|(y, x)| *y == cvr::convert::linear_to_srgb(*x)));
+
+  // every threshold and its neighbors
+  //
+  let x: Vec<f32> = (0..=255_u8)
+    .flat_map(|u| {
+      let bits = cvr::convert::srgb_to_linear(u).to_bits();
+      [bits.saturating_sub(1), bits, bits + 1].map(f32::from_bits)
+    })
+    .collect();
+
+  let mut y = vec![0_u8; x.len()];
+  kernels::linear_to_srgb(&x, &mut y);
+  assert!(y
+    .iter()
+    .zip(x.iter())
+    .all(|(y, x)| *y == cvr::convert::linear_to_srgb(*x)));
+
+  // only the shortest slice's worth of samples is converted
+  //
+  let mut short = [0_u8; 3];
+  kernels::linear_to_srgb(&[1.0; 16], &mut short);
+  assert_eq!(short, [255; 3]);
+}
+
+#[test]
+fn normalization_kernels() {
+  let x: Vec<u8> = (0..LEN).map(|i| (i % 256) as u8).collect();
+
+  let mut y = vec![0_f32; LEN];
+  kernels::u8_to_f32(&x, &mut y);
+  assert!(y
+    .iter()
+    .zip(x.iter())
+    .all(|(y, x)| y.to_bits() == (f32::from(*x) / 255.0).to_bits()));
+
+  let mut z = vec![0_u8; LEN];
+  kernels::f32_to_u8(&y, &mut z);
+  assert_eq!(z, x);
+
+  let mut x = samples();
+  x.extend_from_slice(&[0.5 / 255.0, 1.5 / 255.0, 254.5 / 255.0, -1.0 / 255.0]);
+
+  let mut y = vec![0_u8; x.len()];
+  kernels::f32_to_u8(&x, &mut y);
+
+  let img = cvr::gray::Image::from_vec(x.clone(), x.len(), 1).unwrap();
+  let mut expected = cvr::gray::Image::<u8>::new();
+  img.convert_to(&mut expected, 255.0, 0.0);
+
+  assert_eq!(y, expected.v());
+}
+
+#[test]
+fn color_kernels() {
+  // `NaN`, infinite and out of range samples must produce the same results as the scalar conversions
+  //
+  let r = samples();
+  let g: Vec<f32> = r.iter().rev().copied().collect();
+  let mut b: Vec<f32> = r.iter().map(|x| (x * 3.0).fract()).collect();
+
+  // equal channels exercise the achromatic and tie-breaking paths
+  //
+  b[20..30].copy_from_slice(&r[20..30]);
+  b[30..40].copy_from_slice(&g[30..40]);
+
+  let mut gray = vec![0_f32; LEN];
+  kernels::linear_to_gray(&r, &g, &b, &mut gray);
+  assert!(cvr::rgb::make_iter(&r, &g, &b)
+    .zip(gray.iter())
+    .all(|(rgb, y)| y.to_bits() == cvr::convert::linear_to_gray(rgb).to_bits()));
+
+  let (mut h, mut s, mut v) = (vec![0_f32; LEN], vec![0_f32; LEN], vec![0_f32; LEN]);
+  kernels::linear_to_hsv(&r, &g, &b, &mut h, &mut s, &mut v);
+  assert!(cvr::rgb::make_iter(&r, &g, &b)
+    .zip(cvr::rgb::make_iter(&h, &s, &v))
+    .all(|(rgb, hsv)| hsv.map(f32::to_bits) == cvr::convert::linear_to_hsv(rgb).map(f32::to_bits)));
+
+  let (mut x, mut y, mut z) = (vec![0_f32; LEN], vec![0_f32; LEN], vec![0_f32; LEN]);
+  kernels::hsv_to_linear(&h, &s, &v, &mut x, &mut y, &mut z);
+  assert!(cvr::rgb::make_iter(&h, &s, &v)
+    .zip(cvr::rgb::make_iter(&x, &y, &z))
+    .all(|(hsv, rgb)| rgb.map(f32::to_bits) == cvr::convert::hsv_to_linear(hsv).map(f32::to_bits)));
+
+  // channels whose differences overflow
+  //
+  let extremes = [f32::MAX, -f32::MAX, f32::MAX / 2.0, 0.0];
+  let r: Vec<f32> = (0..64).map(|i| extremes[i % 4]).collect();
+  let g: Vec<f32> = (0..64).map(|i| extremes[i / 4 % 4]).collect();
+  let b: Vec<f32> = (0..64).map(|i| extremes[i / 16]).collect();
+
+  let (mut h, mut s, mut v) = (vec![0_f32; 64], vec![0_f32; 64], vec![0_f32; 64]);
+  kernels::linear_to_hsv(&r, &g, &b, &mut h, &mut s, &mut v);
+  assert!(h.iter().all(|h| (0.0..=360.0).contains(h)));
+  assert!(cvr::rgb::make_iter(&r, &g, &b)
+    .zip(cvr::rgb::make_iter(&h, &s, &v))
+    .all(|(rgb, hsv)| hsv.map(f32::to_bits) == cvr::convert::linear_to_hsv(rgb).map(f32::to_bits)));
+
+  // every sextant boundary of the hue as well as hues that wrap around the color wheel
+  //
+  let mut h: Vec<f32> = (0..=360).map(|h| h as f32).collect();
+  h.extend_from_slice(&[-30.0, 390.0, 720.0, -1e-7, f32::NAN, f32::NEG_INFINITY, 1e9]);
+  let s = vec![0.75; h.len()];
+  let v = vec![0.5; h.len()];
+
+  let (mut x, mut y, mut z) = (
+    vec![0_f32; h.len()],
+    vec![0_f32; h.len()],
+    vec![0_f32; h.len()],
+  );
+  kernels::hsv_to_linear(&h, &s, &v, &mut x, &mut y, &mut z);
+  assert!(cvr::rgb::make_iter(&h, &s, &v)
+    .zip(cvr::rgb::make_iter(&x, &y, &z))
+    .all(|(hsv, rgb)| rgb.map(f32::to_bits) == cvr::convert::hsv_to_linear(hsv).map(f32::to_bits)));
+}
+
+#[test]
+fn image_kernels() {
+  let (width, height) = (37, 5);
+  let n = width * height;
+
+  let img = cvr::rgb::Image::from_vec(
+    (0..n).map(|i| (i * 3 % 256) as u8).collect(),
+    (0..n).map(|i| (i * 5 % 256) as u8).collect(),
+    (0..n).map(|i| (i * 11 % 256) as u8).collect(),
+    width,
+    height,
+  )
+  .unwrap();
+
+  let mut linear = cvr::rgb::Image::new();
+  img.to_linear(&mut linear);
+  assert!(linear
+    .rgb_iter()
+    .eq(img.rgb_iter().map(|p| p.map(cvr::convert::srgb_to_linear))));
+
+  let mut srgb = cvr::rgb::Image::new();
+  linear.to_srgb(&mut srgb);
+  assert!(srgb == img);
+
+  // the vectorized normalization matches the generic conversion
+  //
+  let mut normalized = cvr::rgb::Image::new();
+  cvr::rgb::cvt_u8_to_f32(&img, &mut normalized);
+
+  let mut converted = cvr::rgb::Image::new();
+  img.convert_to(&mut converted, 1.0 / 255.0, 0.0);
+  assert!(normalized == converted);
+
+  let mut gray = cvr::gray::Image::new();
+  cvr::rgb::cvt_linear_to_gray(&linear, &mut gray);
+  assert!(gray
+    .iter()
+    .copied()
+    .eq(linear.rgb_iter().map(cvr::convert::linear_to_gray)));
+
+  let mut hsv = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_hsv(&linear, &mut hsv);
+  assert!(hsv
+    .rgb_iter()
+    .eq(linear.rgb_iter().map(cvr::convert::linear_to_hsv)));
+
+  let mut rgb = cvr::rgb::Image::new();
+  cvr::rgb::cvt_hsv_to_linear(&hsv, &mut rgb);
+  assert!(rgb
+    .rgb_iter()
+    .eq(hsv.rgb_iter().map(cvr::convert::hsv_to_linear)));
+
+  // strided regions of interest are converted row by row
+  //
+  let roi = img.view().roi(3, 1, 30, 3).unwrap();
+
+  let mut out = cvr::rgb::Image::new();
+  out.resize(30, 3);
+  roi.to_linear(&mut out.view_mut());
+  assert!(out
+    .rgb_iter()
+    .eq(roi.rgb_iter().map(|p| p.map(cvr::convert::srgb_to_linear))));
+}
@@ -0,0 +1,89 @@
+extern crate cvr;
+
+#[allow(clippy::cast_possible_truncation)]
+fn plane(len: usize, seed: usize) -> Vec<u8> {
+  (0..len).map(|i| (i * 7 + seed) as u8).collect()
+}
+
+#[test]
+fn interleave_roundtrip() {
+  for n in 0..100 {
+    let (x, y, z, w) = (plane(n, 0), plane(n, 1), plane(n, 2), plane(n, 3));
+
+    let mut packed3 = vec![0_u8; 3 * n];
+    cvr::packed::interleave3(&x, &y, &z, &mut packed3);
+
+    let expected: Vec<u8> = (0..n).flat_map(|i| vec![x[i], y[i], z[i]]).collect();
+    assert_eq!(packed3, expected);
+
+    let mut packed4 = vec![0_u8; 4 * n];
+    cvr::packed::interleave4(&x, &y, &z, &w, &mut packed4);
+
+    let expected: Vec<u8> = (0..n).flat_map(|i| vec![x[i], y[i], z[i], w[i]]).collect();
+    assert_eq!(packed4, expected);
+
+    let (mut a, mut b, mut c, mut d) = (vec![0; n], vec![0; n], vec![0; n], vec![0; n]);
+
+    cvr::packed::deinterleave3(&packed3, &mut a, &mut b, &mut c);
+    assert_eq!((&a, &b, &c), (&x, &y, &z));
+
+    a.iter_mut().for_each(|v| *v = 0);
+    cvr::packed::deinterleave4(&packed4, &mut a, &mut b, &mut c, &mut d);
+    assert_eq!((&a, &b, &c, &d), (&x, &y, &z, &w));
+  }
+}
+
+#[test]
+fn pack_planar_images() {
+  let (width, height) = (37, 5);
+  let n = width * height;
+
+  let img = cvr::rgba::Image::from_vec(
+    plane(n, 0),
+    plane(n, 1),
+    plane(n, 2),
+    plane(n, 3),
+    width,
+    height,
+  )
+  .unwrap();
+
+  let mut rgba8 = cvr::packed::Rgba8::new();
+  cvr::packed::pack_rgba(img.view(), &mut rgba8);
+  assert!(rgba8.rgba_iter().eq(img.rgba_iter()));
+
+  let mut planar = cvr::rgba::Image::default();
+  cvr::packed::unpack_rgba(&rgba8, &mut planar);
+  assert!(planar.rgba_iter().eq(img.rgba_iter()));
+
+  let rgb =
+    cvr::rgb::Image::from_vec(plane(n, 4), plane(n, 5), plane(n, 6), width, height).unwrap();
+  let roi = rgb.roi(3, 1, 20, 3).unwrap();
+
+  let mut rgb8 = cvr::packed::Rgb8::new();
+  cvr::packed::pack_rgb(roi, &mut rgb8);
+  assert_eq!((rgb8.width(), rgb8.height()), (20, 3));
+  assert!(rgb8.rgb_iter().eq(roi.rgb_iter()));
+
+  let mut bgr8 = cvr::packed::Bgr8::new();
+  cvr::packed::pack_bgr(rgb.view(), &mut bgr8);
+  assert!(bgr8.rgb_iter().eq(rgb.rgb_iter()));
+  assert!(bgr8
+    .bgr_iter()
+    .eq(rgb.rgb_iter().map(|[r, g, b]| [b, g, r])));
+
+  let mut planar = cvr::rgb::Image::new();
+  cvr::packed::unpack_bgr(&bgr8, &mut planar);
+  assert!(planar == rgb);
+
+  cvr::packed::unpack_rgb(&rgb8, &mut planar);
+  assert!(planar.rgb_iter().eq(roi.rgb_iter()));
+
+  assert_eq!(
+    cvr::packed::Rgb8::from_vec(vec![0; 10], 2, 2).err(),
+    Some(cvr::Error::InvalidPlaneLength {
+      expected: 12,
+      actual: 10
+    })
+  );
+}
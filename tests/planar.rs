@@ -0,0 +1,59 @@
+extern crate cvr;
+
+#[test]
+fn planar_gray_alpha() {
+  let mut img =
+    cvr::planar::Image::<f32, 2>::from_vec([vec![0.25; 6], vec![1.0; 6]], 3, 2).unwrap();
+
+  assert_eq!(img.channels(), 2);
+  assert_eq!(img.total(), 6);
+
+  img.pixel_iter_mut().enumerate().for_each(|(idx, [v, a])| {
+    *v += 0.25;
+    if idx % 2 == 0 {
+      *a = 0.5;
+    }
+  });
+
+  assert_eq!(img[0], [0.5; 6]);
+  assert_eq!(img.channel(1), [0.5, 1.0, 0.5, 1.0, 0.5, 1.0]);
+  assert_eq!(img.pixel_iter().nth(1), Some([0.5, 1.0]));
+
+  let [v, a] = img.planes_mut();
+  v.copy_from_slice(a);
+  assert_eq!(img[0], img[1]);
+}
+
+#[test]
+fn planar_conversions() {
+  let rgba =
+    cvr::rgba::Image::from_vec(vec![1_u8, 2], vec![3, 4], vec![5, 6], vec![7, 8], 1, 2).unwrap();
+
+  let img = cvr::planar::Image::<u8, 4>::from(rgba);
+  assert_eq!((img.width(), img.height()), (1, 2));
+  assert_eq!(
+    img.pixel_iter().collect::<Vec<_>>(),
+    [[1, 3, 5, 7], [2, 4, 6, 8]]
+  );
+
+  let rgba = cvr::rgba::Image::from(img);
+  assert_eq!(rgba.a(), [7, 8]);
+
+  let gray = cvr::gray::Image::from_vec(vec![9_u8, 10], 2, 1).unwrap();
+  let img = cvr::planar::Image::<u8, 1>::from(gray);
+  assert_eq!(img[0], [9, 10]);
+  assert_eq!(cvr::gray::Image::from(img).v(), [9, 10]);
+
+  let mut multispectral = cvr::planar::Image::<f32, 6>::new();
+  multispectral.resize(4, 4);
+  assert!(multispectral.planes().iter().all(|plane| plane.len() == 16));
+
+  let img = cvr::planar::Image::<u8, 3>::from_vec([vec![0; 4], vec![0; 4], vec![0; 3]], 2, 2);
+  assert_eq!(
+    img.err(),
+    Some(cvr::Error::InvalidPlaneLength {
+      expected: 4,
+      actual: 3
+    })
+  );
+}
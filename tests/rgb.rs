@@ -1,4 +1,5 @@
 extern crate cvr;
+extern crate minivec;
 
 #[test]
 fn rgb_resize() {
@@ -63,3 +64,260 @@ fn rgb_to_linear() {
 
   assert!(parrot == parrot_copy);
 }
+
+#[test]
+fn rgb_from_planes() {
+  let r = minivec::mini_vec![1_u8, 2, 3, 4, 5, 6];
+  let g = minivec::mini_vec![7_u8, 8, 9, 10, 11, 12];
+  let b = minivec::mini_vec![13_u8, 14, 15, 16, 17, 18];
+
+  let img = cvr::rgb::Image::from_planes(r, g, b, 3, 2).unwrap();
+
+  assert_eq!(img.width(), 3);
+  assert_eq!(img.height(), 2);
+  assert_eq!(img.rgb_iter().nth(4), Some([5, 11, 17]));
+
+  let img = cvr::rgb::Image::from_vec(vec![0_f32; 6], vec![0_f32; 6], vec![0_f32; 5], 3, 2);
+  assert_eq!(
+    img.err(),
+    Some(cvr::Error::InvalidPlaneLength {
+      expected: 6,
+      actual: 5
+    })
+  );
+
+  let img = cvr::rgb::Image::<u8>::from_vec(vec![], vec![], vec![], usize::MAX, 2);
+  assert_eq!(img.err(), Some(cvr::Error::DimensionOverflow));
+}
+
+#[test]
+fn rgb_view_to_linear() {
+  // a 2x2 image where each row is followed by two padding elements
+  //
+  let r = [1_u8, 2, 255, 255, 3, 4];
+  let g = [5_u8, 6, 255, 255, 7, 8];
+  let b = [9_u8, 10, 255, 255, 11, 12];
+
+  let view = cvr::rgb::ImageRef::new(&r, &g, &b, 2, 2, 4).unwrap();
+  assert_eq!(view.total(), 4);
+
+  let owned = cvr::rgb::Image::from_vec(
+    vec![1_u8, 2, 3, 4],
+    vec![5_u8, 6, 7, 8],
+    vec![9_u8, 10, 11, 12],
+    2,
+    2,
+  )
+  .unwrap();
+
+  assert!(view.rgb_iter().eq(owned.rgb_iter()));
+
+  let mut expected = cvr::rgb::Image::<f32>::new();
+  owned.to_linear(&mut expected);
+
+  let mut lr = [-1_f32; 5];
+  let mut lg = [-1_f32; 5];
+  let mut lb = [-1_f32; 5];
+
+  let mut linear = cvr::rgb::ImageMut::new(&mut lr, &mut lg, &mut lb, 2, 2, 3).unwrap();
+  view.to_linear(&mut linear);
+
+  assert!(linear.rgb_iter().eq(expected.rgb_iter()));
+
+  let mut srgb = cvr::rgb::Image::<u8>::new();
+  srgb.resize(2, 2);
+  linear.view().to_srgb(&mut srgb.view_mut());
+
+  assert!(srgb == owned);
+
+  linear.rgb_iter_mut().for_each(|[r, g, b]| {
+    *r = 0.0;
+    *g = 0.0;
+    *b = 0.0;
+  });
+
+  assert_eq!(lr, [0.0, 0.0, -1.0, 0.0, 0.0]);
+  assert_eq!(
+    cvr::rgb::ImageRef::new(&r, &g, &b, 2, 2, 1).err(),
+    Some(cvr::Error::InvalidStride {
+      width: 2,
+      stride: 1
+    })
+  );
+}
+
+#[test]
+fn rgb_roi() {
+  let mut img = cvr::rgb::Image::<u8>::new();
+  img.resize(8, 6);
+
+  {
+    let mut roi = img.roi_mut(2, 1, 4, 3).unwrap();
+    assert_eq!(roi.stride(), 8);
+
+    roi.rgb_iter_mut().for_each(|[r, _, b]| {
+      *r = 255;
+      *b = 128;
+    });
+
+    let mut inner = roi.roi_mut(1, 1, 2, 1).unwrap();
+    inner.rows_mut().for_each(|[_, g, _]| g.fill(64));
+  }
+
+  let reds: Vec<_> = img.r().chunks(8).map(|row| row.to_vec()).collect();
+  assert_eq!(reds[0], [0; 8]);
+  assert_eq!(reds[1], [0, 0, 255, 255, 255, 255, 0, 0]);
+  assert_eq!(reds[3], [0, 0, 255, 255, 255, 255, 0, 0]);
+  assert_eq!(reds[4], [0; 8]);
+
+  assert_eq!(&img.g()[2 * 8..3 * 8], [0, 0, 0, 64, 64, 0, 0, 0]);
+
+  let roi = img.roi(2, 1, 4, 3).unwrap();
+  assert_eq!(roi.rows().count(), 3);
+  assert!(roi
+    .rows()
+    .all(|[r, g, b]| r.len() == 4 && g.len() == 4 && b.len() == 4));
+
+  let mut linear = cvr::rgb::Image::<f32>::new();
+  linear.resize(8, 6);
+
+  roi.to_linear(&mut linear.roi_mut(4, 3, 4, 3).unwrap());
+  assert_eq!(linear.r()[0], 0.0);
+  assert_eq!(linear.r()[3 * 8 + 4], cvr::convert::srgb_to_linear(255));
+
+  let mut normalized = cvr::rgb::Image::<f32>::new();
+  normalized.resize(4, 3);
+  roi.to_f32(&mut normalized.view_mut());
+  assert!(normalized
+    .rgb_iter()
+    .all(|[r, _, b]| r == 1.0 && b == 128.0 / 255.0));
+
+  assert_eq!(img.roi(6, 0, 3, 1).err(), Some(cvr::Error::InvalidRoi));
+  assert_eq!(img.roi(0, 5, 1, 2).err(), Some(cvr::Error::InvalidRoi));
+  assert_eq!(img.roi(8, 6, 0, 0).unwrap().rgb_iter().count(), 0);
+}
+
+#[test]
+fn rgb_convert_to() {
+  use cvr::Numeric;
+
+  let img = cvr::rgb::Image::from_vec(
+    vec![0_u16, 1024, 65535],
+    vec![4095, 2, 3],
+    vec![7, 8, 9],
+    3,
+    1,
+  )
+  .unwrap();
+
+  let mut gradient = cvr::rgb::Image::<i16>::new();
+  img.convert_to(&mut gradient, 1.0, -2048.0);
+  assert_eq!(gradient.r(), [-2048, -1024, 32767]);
+  assert_eq!(gradient.g(), [2047, -2046, -2045]);
+
+  let mut normalized = cvr::rgb::Image::<f64>::new();
+  img.convert_to(&mut normalized, f64::SCALE / u16::SCALE, 0.0);
+  assert_eq!(normalized.r(), [0.0, 1024.0 / 65535.0, 1.0]);
+
+  let mut narrowed = cvr::rgb::Image::<u8>::new();
+  normalized.convert_to(&mut narrowed, u8::SCALE, 0.0);
+  assert_eq!(narrowed.r(), [0, 4, 255]);
+  assert_eq!(narrowed.g(), [16, 0, 0]);
+
+  let mut accumulated = cvr::rgb::Image::<i32>::new();
+  narrowed.convert_to(&mut accumulated, 1000.0, 0.5);
+  assert_eq!(accumulated.r(), [1, 4001, 255_001]);
+  assert_eq!(accumulated.width(), 3);
+}
+
+#[test]
+fn rgb_cvt_color() {
+  use cvr::convert::GrayWeights;
+
+  let mut img = cvr::rgb::Image::from_vec(
+    vec![0.5_f32, 1.0, 0.0],
+    vec![0.5, 0.0, 0.0],
+    vec![0.5, 0.0, 1.0],
+    3,
+    1,
+  )
+  .unwrap();
+
+  let mut gray = cvr::gray::Image::new();
+  cvr::rgb::cvt_rgb_to_gray(&img, &mut gray, GrayWeights::Luminance);
+  assert!((gray.v()[0] - 0.5).abs() < 1e-6);
+  assert!((gray.v()[1] - cvr::convert::linear_to_gray([1.0, 0.0, 0.0])).abs() < 1e-7);
+
+  cvr::rgb::cvt_rgb_to_gray(&img, &mut gray, GrayWeights::Bt601);
+  assert!((gray.v()[2] - 0.114).abs() < 1e-7);
+
+  let mut rgb = cvr::rgb::Image::new();
+  cvr::gray::cvt_gray_to_rgb(&gray, &mut rgb);
+  assert_eq!(rgb.r(), gray.v());
+  assert_eq!(rgb.b(), gray.v());
+
+  let mut bgr = cvr::rgb::Image::new();
+  cvr::rgb::cvt_rgb_to_bgr(&img, &mut bgr);
+  assert_eq!(bgr.r(), img.b());
+  assert_eq!(bgr.b(), img.r());
+
+  img.swap_rb();
+  assert!(img == bgr);
+
+  let alpha = cvr::gray::Image::from_vec(vec![1.0, 0.5, 0.0], 3, 1).unwrap();
+  let mut rgba = cvr::rgba::Image::new();
+  cvr::rgb::cvt_rgb_to_rgba(&img, &alpha, &mut rgba);
+  assert_eq!(
+    rgba.rgba_iter().collect::<Vec<_>>(),
+    [
+      [0.5, 0.5, 0.5, 1.0],
+      [0.0, 0.0, 1.0, 0.5],
+      [1.0, 0.0, 0.0, 0.0]
+    ]
+  );
+
+  let mut flat = cvr::rgb::Image::new();
+  cvr::rgba::cvt_rgba_to_rgb(&rgba, &mut flat, [0.0, 1.0, 0.0]);
+  assert_eq!(
+    flat.rgb_iter().collect::<Vec<_>>(),
+    [[0.5, 0.5, 0.5], [0.0, 0.5, 0.5], [0.0, 1.0, 0.0]]
+  );
+}
+
+#[test]
+fn rgb_split_rows() {
+  let mut img = cvr::rgb::Image::<u8>::new();
+  img.resize(4, 7);
+
+  img.rows_mut().enumerate().for_each(|(y, [r, _, b])| {
+    r.fill(y as u8);
+    b.fill(2 * y as u8);
+  });
+
+  let mut bands = img.split_rows_mut(3);
+  assert_eq!(
+    bands
+      .iter()
+      .map(cvr::rgb::ImageMut::height)
+      .collect::<Vec<_>>(),
+    [3, 2, 2]
+  );
+
+  std::thread::scope(|scope| {
+    for band in &mut bands {
+      scope.spawn(move || band.rgb_iter_mut().for_each(|[_, g, b]| *g = *b + 1));
+    }
+  });
+
+  assert!(img
+    .rows()
+    .enumerate()
+    .all(|(y, [r, g, b])| r == [y as u8; 4] && g == [2 * y as u8 + 1; 4] && b == [2 * y as u8; 4]));
+
+  let mut roi = img.roi_mut(1, 1, 2, 5).unwrap();
+  let bands = roi.split_rows_mut(8);
+  assert_eq!(bands.len(), 8);
+  assert_eq!(bands[0].view().rows().next().unwrap()[0], [1, 1]);
+  assert_eq!(bands[4].view().rows().next().unwrap()[0], [5, 5]);
+  assert!(bands[5..].iter().all(|band| band.total() == 0));
+}
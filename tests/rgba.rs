@@ -0,0 +1,132 @@
+extern crate cvr;
+extern crate minivec;
+
+#[test]
+fn rgba_from_planes() {
+  let img = cvr::rgba::Image::from_vec(
+    vec![1_u8, 2],
+    vec![3_u8, 4],
+    vec![5_u8, 6],
+    vec![7_u8, 8],
+    1,
+    2,
+  )
+  .unwrap();
+
+  assert_eq!(img.width(), 1);
+  assert_eq!(img.height(), 2);
+  assert_eq!(
+    img.rgba_iter().collect::<Vec<_>>(),
+    [[1, 3, 5, 7], [2, 4, 6, 8]]
+  );
+
+  let img = cvr::rgba::Image::from_planes(
+    minivec::mini_vec![0_u8; 2],
+    minivec::mini_vec![0_u8; 2],
+    minivec::mini_vec![0_u8; 2],
+    minivec::mini_vec![0_u8; 3],
+    1,
+    2,
+  );
+
+  assert!(img.is_err());
+}
+
+#[test]
+fn rgba_view() {
+  let mut img = cvr::rgba::Image::from_vec(
+    vec![1_u8, 2],
+    vec![3_u8, 4],
+    vec![5_u8, 6],
+    vec![7_u8, 8],
+    2,
+    1,
+  )
+  .unwrap();
+
+  img
+    .view_mut()
+    .rgba_iter_mut()
+    .for_each(|[_, _, _, a]| *a = 255);
+
+  let view = cvr::rgba::ImageRef::from(&img);
+  assert_eq!(view.stride(), 2);
+  assert_eq!(
+    view.rgba_iter().collect::<Vec<_>>(),
+    [[1, 3, 5, 255], [2, 4, 6, 255]]
+  );
+  assert!(view.rgb_iter().eq(img.rgb_iter()));
+}
+
+#[test]
+fn rgba_to_linear() {
+  let mut img = cvr::rgba::Image::<u8>::new();
+  img.resize(2, 1);
+  img.rgba_iter_mut().for_each(|[r, g, b, a]| {
+    *r = 10;
+    *g = 128;
+    *b = 255;
+    *a = 51;
+  });
+
+  let mut linear = cvr::rgba::Image::new();
+  img.to_linear(&mut linear);
+  assert!(linear.a().iter().all(|a| (a - 0.2).abs() < f32::EPSILON));
+  assert_eq!(linear.r()[1], cvr::convert::srgb_to_linear(10));
+
+  let mut srgb = cvr::rgba::Image::new();
+  linear.to_srgb(&mut srgb);
+  assert!(srgb == img);
+
+  let (_, _, _, a) = linear.rgba_mut();
+  a.copy_from_slice(&[-0.5, 1.5]);
+  linear.to_srgb(&mut srgb);
+  assert_eq!(srgb.a(), [0, 255]);
+  assert_eq!(
+    srgb.rgb_iter().collect::<Vec<_>>(),
+    img.rgb_iter().collect::<Vec<_>>()
+  );
+}
+
+#[test]
+fn rgba_flatten_u8() {
+  let img = cvr::rgba::Image::from_vec(
+    vec![255_u8, 200],
+    vec![0, 200],
+    vec![0, 200],
+    vec![51, 0],
+    2,
+    1,
+  )
+  .unwrap();
+
+  let mut rgb = cvr::rgb::Image::new();
+  cvr::rgba::cvt_rgba_to_rgb(&img, &mut rgb, [0, 0, 255]);
+
+  assert_eq!(
+    rgb.rgb_iter().collect::<Vec<_>>(),
+    [[51, 0, 204], [0, 0, 255]]
+  );
+}
+
+#[test]
+fn rgba_pixel_access() {
+  let mut img = cvr::rgba::Image::from_vec(
+    vec![1_u8, 2, 3, 4],
+    vec![5, 6, 7, 8],
+    vec![9, 10, 11, 12],
+    vec![13, 14, 15, 16],
+    2,
+    2,
+  )
+  .unwrap();
+
+  assert_eq!(img.get(1, 1), Some([4, 8, 12, 16]));
+  assert_eq!(img.get(2, 1), None);
+
+  let [_, _, _, a] = img.get_mut(0, 1).unwrap();
+  *a = 0;
+
+  assert_eq!(img.roi(0, 1, 2, 1).unwrap().get(0, 0), Some([3, 7, 11, 0]));
+  assert_eq!(img.enumerate_pixels().last(), Some((1, 1, [4, 8, 12, 16])));
+}
@@ -0,0 +1,102 @@
+#![allow(clippy::float_cmp)]
+
+extern crate cvr;
+
+use cvr::sample::{Border, Interpolation};
+
+#[test]
+fn sample_borders() {
+  let img = cvr::gray::Image::from_vec(vec![1_u8, 2, 3, 4], 4, 1).unwrap();
+  let at = |x, border| img.sample(x, 0.0, Interpolation::Nearest, border);
+
+  assert_eq!(at(-2.0, Border::Replicate), 1);
+  assert_eq!(at(5.0, Border::Replicate), 4);
+  assert_eq!(at(-2.0, Border::Reflect), 2);
+  assert_eq!(at(5.0, Border::Reflect), 3);
+  assert_eq!(at(-2.0, Border::Wrap), 3);
+  assert_eq!(at(5.0, Border::Wrap), 2);
+  assert_eq!(at(4.0, Border::Constant(9)), 9);
+  assert_eq!(at(2.4, Border::Constant(9)), 3);
+
+  let empty = cvr::gray::Image::<u8>::new();
+  assert_eq!(
+    empty.sample(0.0, 0.0, Interpolation::Bicubic, Border::Replicate),
+    0
+  );
+}
+
+#[test]
+fn sample_interpolation() {
+  let img = cvr::gray::Image::from_vec((0..16).map(|x| x as f32).collect(), 4, 4).unwrap();
+
+  for interpolation in [
+    Interpolation::Nearest,
+    Interpolation::Bilinear,
+    Interpolation::Bicubic,
+  ] {
+    assert_eq!(img.sample(2.0, 1.0, interpolation, Border::Replicate), 6.0);
+  }
+
+  assert_eq!(
+    img.sample(1.25, 1.5, Interpolation::Bilinear, Border::Replicate),
+    7.25
+  );
+
+  // cubic convolution reproduces linear ramps exactly away from the borders
+  //
+  let v = img.sample(1.25, 1.5, Interpolation::Bicubic, Border::Replicate);
+  assert!((v - 7.25).abs() < 1e-5);
+
+  let rgb = cvr::rgb::Image::from_vec(vec![0_u8, 255], vec![10, 20], vec![255, 255], 2, 1).unwrap();
+
+  assert_eq!(
+    rgb.sample(0.5, 0.0, Interpolation::Bilinear, Border::Replicate),
+    [128, 15, 255]
+  );
+  // sampling a region of interest never reads the pixels surrounding it
+  //
+  assert_eq!(
+    rgb
+      .roi(1, 0, 1, 1)
+      .unwrap()
+      .sample(-0.75, 0.0, Interpolation::Bilinear, Border::Constant(0)),
+    [64, 5, 64]
+  );
+}
+
+#[test]
+fn sample_extreme_coordinates() {
+  let img = cvr::gray::Image::from_vec(vec![1_u8, 2, 3, 4], 4, 1).unwrap();
+
+  for interpolation in [
+    Interpolation::Nearest,
+    Interpolation::Bilinear,
+    Interpolation::Bicubic,
+  ] {
+    for border in [
+      Border::Constant(9),
+      Border::Replicate,
+      Border::Reflect,
+      Border::Wrap,
+    ] {
+      for x in [f32::MAX, -f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        let v = img.sample(x, 0.0, interpolation, border);
+        let expected = match border {
+          Border::Constant(value) => Some(value),
+          Border::Replicate => Some(if x > 0.0 { 4 } else { 1 }),
+          _ => None,
+        };
+
+        assert!(expected.map_or((1..=4).contains(&v), |e| e == v));
+
+        // every row of a single row image resolves to that row unless the border is constant
+        //
+        let expected = match border {
+          Border::Constant(value) => value,
+          _ => 1,
+        };
+        assert_eq!(img.sample(0.0, x, interpolation, border), expected);
+      }
+    }
+  }
+}
@@ -0,0 +1,62 @@
+extern crate cvr;
+
+use cvr::space::{Hsv, LinearSrgb, Srgb, Tagged};
+
+#[test]
+fn tagged_image_conversions() {
+  let img = cvr::rgb::Image::from_vec(
+    vec![255_u8, 128, 0],
+    vec![0, 128, 64],
+    vec![32, 128, 255],
+    3,
+    1,
+  )
+  .unwrap();
+  let img = Tagged::<_, Srgb>::new(img);
+  assert_eq!(img.space(), "sRGB");
+
+  let mut linear = Tagged::<cvr::rgb::Image<f32>, LinearSrgb>::default();
+  img.to_linear(&mut linear);
+
+  let mut hsv = Tagged::<cvr::rgb::Image<f32>, Hsv>::default();
+  linear.to_hsv(&mut hsv);
+  assert!(hsv
+    .rgb_iter()
+    .into_iter()
+    .eq(linear.rgb_iter().linear_to_hsv().into_iter()));
+
+  let mut roundtrip = Tagged::default();
+  hsv.to_linear(&mut roundtrip);
+
+  let mut srgb = Tagged::default();
+  roundtrip.to_srgb(&mut srgb);
+  assert!(srgb == img);
+
+  let mut gray = Tagged::default();
+  linear.to_gray(&mut gray);
+
+  let mut gray_srgb = Tagged::<cvr::gray::Image<u8>, Srgb>::default();
+  gray.to_srgb(&mut gray_srgb);
+  assert_eq!(gray_srgb.inner().v()[1], 128);
+}
+
+#[test]
+fn tagged_iterators() {
+  let pixels = [[1_u8, 2, 3], [200, 100, 50]];
+
+  let srgb: Vec<_> = Tagged::<_, Srgb>::new(pixels.iter().copied())
+    .srgb_to_linear()
+    .linear_to_hsv()
+    .hsv_to_linear()
+    .linear_to_srgb()
+    .into_iter()
+    .collect();
+
+  assert_eq!(srgb, pixels);
+
+  let gray = Tagged::<_, Srgb>::new(pixels.iter().copied())
+    .srgb_to_linear()
+    .linear_to_gray();
+  assert_eq!(gray.space(), "linear sRGB");
+  assert_eq!(gray.into_inner().count(), 2);
+}
@@ -2,8 +2,12 @@
 
 extern crate cvr;
 
-use cvr::convert::iter::{LinearGrayIterator, LinearSRGBIterator, SRGBLinearIterator};
+use cvr::convert::iter::{
+  HSILinearIterator, HSLLinearIterator, LinearGrayIterator, LinearHSIIterator, LinearHSLIterator,
+  LinearSRGBIterator, SRGBLinearIterator,
+};
 
+#[allow(clippy::legacy_numeric_constants)]
 fn float_eq(a: f32, b: f32) -> bool {
   (a - b).abs() <= std::f32::EPSILON * (a.max(b))
 }
@@ -76,6 +80,50 @@ fn srgb_to_linear_to_srgb() {
   assert_eq!(blue_srgb, b);
 }
 
+#[test]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn srgb_lut() {
+  use cvr::convert::transfer::{Srgb, TransferFunction};
+
+  // the table driven conversions must match evaluating the curve directly
+  //
+  let encode = |x: f32| {
+    let u = Srgb.encode(x);
+    if u >= 1.0 {
+      255
+    } else if u < 0.0 {
+      0
+    } else {
+      (255.0 * u).round() as u8
+    }
+  };
+
+  for u in 0..=255_u8 {
+    assert_eq!(
+      cvr::convert::srgb_to_linear(u).to_bits(),
+      Srgb.decode(f32::from(u) * 0.003_921_569).to_bits()
+    );
+  }
+
+  // every value a decoded 8-bit sample can take, along with its neighbors, sits right next to a decision threshold
+  //
+  for u in 0..=255_u8 {
+    let bits = cvr::convert::srgb_to_linear(u).to_bits();
+    for x in [bits.saturating_sub(1), bits, bits + 1].map(f32::from_bits) {
+      assert_eq!(cvr::convert::linear_to_srgb(x), encode(x), "{x}");
+    }
+  }
+
+  for bits in (0..=1.0_f32.to_bits()).step_by(251) {
+    let x = f32::from_bits(bits);
+    assert_eq!(cvr::convert::linear_to_srgb(x), encode(x), "{x}");
+  }
+
+  for x in [-0.0, -1.0, 1.5, f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+    assert_eq!(cvr::convert::linear_to_srgb(x), encode(x), "{x}");
+  }
+}
+
 #[test]
 fn srgb_to_gray() {
   let r = [1_u8, 2, 3];
@@ -188,3 +236,536 @@ fn rgb_to_hsv() {
     assert_eq!(&converted_rgb[..], &round_tripped_rgb[..]);
   }
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn hsv_out_of_range() {
+  use cvr::convert::iter::{HSVLinearIterator, LinearHSVIterator};
+  use cvr::convert::{
+    hsv_to_linear, hsv_to_linear_clamped, linear_to_hsv, linear_to_hsv_clamped, try_hsv_to_linear,
+    try_linear_to_hsv, RangeError,
+  };
+
+  // `NaN` channels are treated as `0.0`
+  //
+  assert_eq!(
+    linear_to_hsv([f32::NAN, 0.5, 0.25]),
+    linear_to_hsv([0.0, 0.5, 0.25])
+  );
+  assert_eq!(linear_to_hsv([f32::NAN; 3]), [0.0, 0.0, 0.0]);
+  assert_eq!(hsv_to_linear([f32::NAN, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+  assert_eq!(hsv_to_linear([120.0, f32::NAN, 0.5]), [0.5, 0.5, 0.5]);
+
+  // values slightly outside of the unit range pass through the equation
+  //
+  let [h, s, v] = linear_to_hsv([1.02, 0.5, -0.01]);
+  assert!((0.0..=360.0).contains(&h));
+  assert!(s > 1.0);
+  assert_eq!(v, 1.02);
+
+  // the hue stays within its range even when the differences of the channels overflow
+  //
+  assert_eq!(linear_to_hsv([f32::MAX, f32::MAX, -f32::MAX])[0], 60.0);
+
+  // hues wrap around the color wheel
+  //
+  for (wrapped, hue) in [(-120.0, 240.0), (480.0, 120.0), (720.0, 0.0)] {
+    assert_eq!(
+      hsv_to_linear([wrapped, 0.5, 0.5]),
+      hsv_to_linear([hue, 0.5, 0.5])
+    );
+  }
+  assert_eq!(hsv_to_linear([f32::INFINITY, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+
+  // the clamped variants always produce results within range
+  //
+  assert_eq!(
+    linear_to_hsv_clamped([1.02, 0.5, -0.01]),
+    linear_to_hsv([1.0, 0.5, 0.0])
+  );
+  assert_eq!(
+    linear_to_hsv_clamped([f32::NAN, f32::INFINITY, -1.0]),
+    [120.0, 1.0, 1.0]
+  );
+  assert_eq!(
+    hsv_to_linear_clamped([480.0, 2.0, f32::NAN]),
+    [0.0, 0.0, 0.0]
+  );
+  assert_eq!(hsv_to_linear_clamped([0.0, 1.5, 1.5]), [1.0, 0.0, 0.0]);
+
+  // the fallible variants name the first offending channel
+  //
+  assert_eq!(
+    try_linear_to_hsv([0.25, 0.5, 0.75]),
+    Ok(linear_to_hsv([0.25, 0.5, 0.75]))
+  );
+  assert_eq!(
+    try_linear_to_hsv([0.25, 1.5, f32::NAN]),
+    Err(RangeError {
+      channel: 1,
+      value: 1.5
+    })
+  );
+  assert!(matches!(
+    try_linear_to_hsv([0.25, 0.5, f32::NAN]),
+    Err(RangeError { channel: 2, value }) if value.is_nan()
+  ));
+  assert_eq!(
+    try_hsv_to_linear([360.0, 1.0, 1.0]),
+    Ok(hsv_to_linear([360.0, 1.0, 1.0]))
+  );
+  assert_eq!(
+    try_hsv_to_linear([361.0, 1.0, 1.0]),
+    Err(RangeError {
+      channel: 0,
+      value: 361.0
+    })
+  );
+  assert_eq!(
+    try_hsv_to_linear([90.0, 1.0, -0.5]),
+    Err(RangeError {
+      channel: 2,
+      value: -0.5
+    })
+  );
+
+  // as do the iterator adapters
+  //
+  let pixels = [[0.25, 0.5, 0.75], [1.5, 0.5, 0.0]];
+
+  assert!(pixels
+    .iter()
+    .copied()
+    .linear_to_hsv_clamped()
+    .eq(pixels.iter().copied().map(linear_to_hsv_clamped)));
+
+  assert!(pixels
+    .iter()
+    .copied()
+    .try_linear_to_hsv()
+    .collect::<Result<Vec<_>, _>>()
+    .is_err());
+
+  let hsv = [[400.0, 0.5, 0.5], [90.0, 1.5, 1.0]];
+
+  assert!(hsv
+    .iter()
+    .copied()
+    .hsv_to_linear_clamped()
+    .flatten()
+    .all(|x| (0.0..=1.0).contains(&x)));
+
+  assert_eq!(
+    hsv
+      .iter()
+      .copied()
+      .try_hsv_to_linear()
+      .find_map(Result::err),
+    Some(RangeError {
+      channel: 0,
+      value: 400.0
+    })
+  );
+}
+
+fn approx_eq(actual: [f32; 3], expected: [f32; 3], tolerances: [f32; 3]) -> bool {
+  let matches = actual
+    .iter()
+    .zip(expected.iter())
+    .zip(tolerances.iter())
+    .all(|((a, b), tol)| (a - b).abs() <= *tol);
+
+  if !matches {
+    dbg!(actual);
+    dbg!(expected);
+  }
+
+  matches
+}
+
+// sample colors and their expected values taken from the table in
+// https://en.wikipedia.org/wiki/HSL_and_HSV#Examples, in `(rgb, hsl, hsi)` ordering
+//
+const HSL_HSI_EXAMPLES: [([f32; 3], [f32; 3], [f32; 3]); 10] = [
+  ([1.0, 1.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]),
+  ([0.5, 0.5, 0.5], [0.0, 0.0, 0.5], [0.0, 0.0, 0.5]),
+  ([1.0, 0.0, 0.0], [0.0, 1.0, 0.5], [0.0, 1.0, 0.333]),
+  ([0.75, 0.75, 0.0], [60.0, 1.0, 0.375], [60.0, 1.0, 0.5]),
+  ([0.0, 0.5, 0.0], [120.0, 1.0, 0.25], [120.0, 1.0, 0.167]),
+  ([0.5, 1.0, 1.0], [180.0, 1.0, 0.75], [180.0, 0.4, 0.833]),
+  ([0.5, 0.5, 1.0], [240.0, 1.0, 0.75], [240.0, 0.25, 0.667]),
+  ([0.75, 0.25, 0.75], [300.0, 0.5, 0.5], [300.0, 0.571, 0.583]),
+  (
+    [0.628, 0.643, 0.142],
+    [61.8, 0.638, 0.393],
+    [61.5, 0.699, 0.471],
+  ),
+  (
+    [0.255, 0.104, 0.918],
+    [251.1, 0.832, 0.511],
+    [250.0, 0.756, 0.426],
+  ),
+];
+
+#[test]
+fn rgb_to_hsl() {
+  let tolerances = [0.1, 1e-3, 1e-3];
+
+  for (rgb, expected_hsl, _) in &HSL_HSI_EXAMPLES {
+    let hsl = cvr::convert::linear_to_hsl(*rgb);
+    assert!(approx_eq(hsl, *expected_hsl, tolerances));
+
+    let converted_rgb = cvr::convert::hsl_to_linear(hsl);
+    assert!(approx_eq(converted_rgb, *rgb, [1e-6; 3]));
+  }
+}
+
+#[test]
+fn rgb_to_hsi() {
+  let tolerances = [0.1, 1e-3, 1e-3];
+
+  for (rgb, _, expected_hsi) in &HSL_HSI_EXAMPLES {
+    let hsi = cvr::convert::linear_to_hsi(*rgb);
+    assert!(approx_eq(hsi, *expected_hsi, tolerances));
+
+    let converted_rgb = cvr::convert::hsi_to_linear(hsi);
+    assert!(approx_eq(converted_rgb, *rgb, [1e-5; 3]));
+  }
+}
+
+#[test]
+fn hsl_hsi_images() {
+  let r = [10_u8, 128, 250, 0];
+  let g = [200_u8, 128, 40, 0];
+  let b = [90_u8, 128, 60, 255];
+
+  let img = cvr::rgb::Image::from_vec(r.to_vec(), g.to_vec(), b.to_vec(), 2, 2).unwrap();
+  let mut linear = cvr::rgb::Image::new();
+  img.to_linear(&mut linear);
+
+  let mut hsl = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_hsl(&linear, &mut hsl);
+  assert!(hsl.rgb_iter().eq(
+    cvr::rgb::make_iter(&r, &g, &b)
+      .srgb_to_linear()
+      .linear_to_hsl()
+  ));
+
+  let mut hsi = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_hsi(&linear, &mut hsi);
+
+  let mut roundtrip = cvr::rgb::Image::new();
+  cvr::rgb::cvt_hsi_to_linear(&hsi, &mut roundtrip);
+
+  let mut srgb = cvr::rgb::Image::new();
+  roundtrip.to_srgb(&mut srgb);
+  assert!(srgb == img);
+
+  let srgb: Vec<_> = hsl.rgb_iter().hsl_to_linear().linear_to_srgb().collect();
+  assert!(srgb.into_iter().eq(img.rgb_iter()));
+
+  let srgb: Vec<_> = hsi.rgb_iter().hsi_to_linear().linear_to_srgb().collect();
+  assert!(srgb.into_iter().eq(
+    linear
+      .rgb_iter()
+      .linear_to_hsi()
+      .hsi_to_linear()
+      .linear_to_srgb()
+  ));
+}
+
+#[test]
+fn rgb_to_cie() {
+  use cvr::convert::WhitePoint;
+
+  // reference values computed directly from the CIE definitions using the same `sRGB` to `XYZ` matrix
+  //
+  let red = [1.0, 0.0, 0.0];
+  assert!(approx_eq(
+    cvr::convert::linear_to_xyz(red),
+    [0.412_390_8, 0.212_639, 0.019_330_8],
+    [1e-7; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_lab(red),
+    [53.2371, 80.0901, 67.2033],
+    [1e-3; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_luv(red),
+    [53.2371, 175.0098, 37.7651],
+    [1e-3; 3]
+  ));
+
+  let white = [1.0, 1.0, 1.0];
+  assert!(approx_eq(
+    cvr::convert::linear_to_lab(white),
+    [100.0, 0.0, 0.0],
+    [1e-4; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_luv(white),
+    [100.0, 0.0, 0.0],
+    [1e-4; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_luv([0.0; 3]),
+    [0.0; 3],
+    [0.0; 3]
+  ));
+
+  let d50 = WhitePoint::D50;
+  assert!(approx_eq(
+    cvr::convert::xyz_to_lab(d50.xyz(), d50),
+    [100.0, 0.0, 0.0],
+    [1e-4; 3]
+  ));
+  assert!(approx_eq(
+    WhitePoint::from_chromaticity(0.3127, 0.3290).xyz(),
+    WhitePoint::default().xyz(),
+    [1e-4; 3]
+  ));
+
+  let colors = [
+    [0.19, 0.38, 0.38],
+    [0.75, 0.19, 0.19],
+    [0.001, 0.002, 0.0005],
+    [0.0, 0.0, 0.0],
+  ];
+
+  for rgb in &colors {
+    for white in [WhitePoint::D65, d50] {
+      let xyz = cvr::convert::linear_to_xyz(*rgb);
+
+      let lab = cvr::convert::xyz_to_lab(xyz, white);
+      assert!(approx_eq(
+        cvr::convert::lab_to_xyz(lab, white),
+        xyz,
+        [1e-6; 3]
+      ));
+
+      let luv = cvr::convert::xyz_to_luv(xyz, white);
+      assert!(approx_eq(
+        cvr::convert::luv_to_xyz(luv, white),
+        xyz,
+        [1e-6; 3]
+      ));
+    }
+
+    assert!(approx_eq(
+      cvr::convert::lab_to_linear(cvr::convert::linear_to_lab(*rgb)),
+      *rgb,
+      [1e-6; 3]
+    ));
+    assert!(approx_eq(
+      cvr::convert::luv_to_linear(cvr::convert::linear_to_luv(*rgb)),
+      *rgb,
+      [1e-6; 3]
+    ));
+  }
+}
+
+#[test]
+fn cie_images() {
+  use cvr::convert::iter::{
+    CieRelativeIterator, LabLinearIterator, LinearLabIterator, LinearXYZIterator,
+  };
+  use cvr::convert::{CieReference, WhitePoint};
+
+  let img = cvr::rgb::Image::from_vec(
+    vec![10_u8, 128, 250, 0],
+    vec![200_u8, 128, 40, 0],
+    vec![90_u8, 128, 60, 255],
+    2,
+    2,
+  )
+  .unwrap();
+
+  let mut linear = cvr::rgb::Image::new();
+  img.to_linear(&mut linear);
+
+  let mut xyz = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_xyz(&linear, &mut xyz);
+  assert!(xyz.rgb_iter().eq(linear.rgb_iter().linear_to_xyz()));
+
+  let mut lab = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_lab(&linear, &mut lab, CieReference::default());
+  assert!(lab.rgb_iter().eq(linear.rgb_iter().linear_to_lab()));
+
+  let srgb: Vec<_> = lab.rgb_iter().lab_to_linear().linear_to_srgb().collect();
+  assert!(srgb.into_iter().eq(img.rgb_iter()));
+
+  // the linear values are adapted to `D50`, so white and gray remain neutral
+  //
+  let d50 = CieReference::from(WhitePoint::D50);
+
+  let mut luv = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_luv(&linear, &mut luv, d50);
+  assert!(luv.rgb_iter().eq(linear.rgb_iter().linear_to_luv_with(d50)));
+
+  let [_, u, v] = luv.rgb_iter().nth(1).unwrap();
+  assert!(u.abs() < 1e-3 && v.abs() < 1e-3);
+
+  let mut roundtrip = cvr::rgb::Image::new();
+  cvr::rgb::cvt_luv_to_linear(&luv, &mut roundtrip, d50);
+
+  let mut srgb = cvr::rgb::Image::new();
+  roundtrip.to_srgb(&mut srgb);
+  assert!(srgb == img);
+}
+
+#[test]
+fn rgb_to_oklab() {
+  use cvr::convert::iter::{LinearOklchIterator, OklabLinearIterator};
+
+  // reference values from the Oklab definition and the CSS Color 4 specification
+  //
+  assert!(approx_eq(
+    cvr::convert::linear_to_oklab([1.0, 0.0, 0.0]),
+    [0.627_955, 0.224_863, 0.125_846],
+    [1e-5; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_oklch([1.0, 0.0, 0.0]),
+    [0.627_955, 0.257_683, 29.2339],
+    [1e-4; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_oklab([1.0, 1.0, 1.0]),
+    [1.0, 0.0, 0.0],
+    [1e-5; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::linear_to_oklch([0.5, 0.5, 0.5]),
+    [0.793_701, 0.0, 0.0],
+    [1e-4; 3]
+  ));
+
+  let colors = [
+    [0.19, 0.38, 0.38],
+    [0.75, 0.19, 0.19],
+    [0.0, 0.0, 1.0],
+    [0.001, 0.002, 0.0005],
+  ];
+
+  let roundtrip: Vec<_> = colors
+    .iter()
+    .copied()
+    .linear_to_oklch()
+    .map(cvr::convert::oklch_to_oklab)
+    .oklab_to_linear()
+    .collect();
+
+  for (actual, expected) in roundtrip.into_iter().zip(colors.iter()) {
+    assert!(approx_eq(actual, *expected, [1e-5; 3]));
+  }
+}
+
+#[test]
+fn oklch_gamut_clip() {
+  // in gamut colors are left untouched
+  //
+  let teal = cvr::convert::linear_to_oklch([0.19, 0.38, 0.38]);
+  assert!(cvr::convert::oklch_in_gamut(teal));
+  assert!(approx_eq(
+    cvr::convert::gamut_clip_oklch(teal),
+    teal,
+    [0.0; 3]
+  ));
+
+  // boosting the chroma pushes the color out of the `sRGB` gamut, clipping must bring it back without touching the
+  // lightness or the hue
+  //
+  let [l, c, h] = teal;
+  let vivid = [l, 4.0 * c, h];
+  assert!(!cvr::convert::oklch_in_gamut(vivid));
+
+  let clipped = cvr::convert::gamut_clip_oklch(vivid);
+  assert!(cvr::convert::oklch_in_gamut(clipped));
+  assert!(clipped[1] >= c && clipped[1] < 4.0 * c);
+  assert!(approx_eq(clipped, [l, clipped[1], h], [0.0; 3]));
+
+  let rgb = cvr::convert::oklch_to_linear_clipped(vivid);
+  assert!(rgb.iter().all(|x| (0.0..=1.0).contains(x)));
+  assert!(approx_eq(
+    cvr::convert::linear_to_oklch(rgb),
+    clipped,
+    [1e-3, 1e-3, 1e-1]
+  ));
+
+  // lightness outside of `[0, 1]` clamps to black and white
+  //
+  assert!(approx_eq(
+    cvr::convert::oklch_to_linear_clipped([1.5, 0.2, 90.0]),
+    [1.0; 3],
+    [1e-4; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::oklch_to_linear_clipped([-0.5, 0.2, 90.0]),
+    [0.0; 3],
+    [1e-4; 3]
+  ));
+
+  let img =
+    cvr::rgb::Image::from_vec(vec![0.19_f32, 1.0], vec![0.38, 0.0], vec![0.38, 0.0], 2, 1).unwrap();
+
+  let mut lch = cvr::rgb::Image::new();
+  cvr::rgb::cvt_linear_to_oklch(&img, &mut lch);
+  lch.rgb_mut().1.iter_mut().for_each(|c| *c *= 4.0);
+
+  let mut linear = cvr::rgb::Image::new();
+  cvr::rgb::cvt_oklch_to_linear(&lch, &mut linear);
+  assert!(linear
+    .rgb_iter()
+    .eq(lch.rgb_iter().map(cvr::convert::oklch_to_linear)));
+  assert!(!linear
+    .rgb_iter()
+    .all(|rgb| rgb.iter().all(|x| (0.0..=1.0).contains(x))));
+
+  cvr::rgb::cvt_oklch_to_linear_clipped(&lch, &mut linear);
+  assert!(linear
+    .rgb_iter()
+    .all(|rgb| rgb.iter().all(|x| (0.0..=1.0).contains(x))));
+}
+
+#[test]
+fn oklch_gamut_clip_nan() {
+  // `NaN` components are treated as `0.0` instead of poisoning every channel
+  //
+  let nan = f32::NAN;
+
+  assert!(approx_eq(
+    cvr::convert::gamut_clip_oklch([nan, nan, nan]),
+    [0.0; 3],
+    [0.0; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::gamut_clip_oklch([0.7, 0.1, nan]),
+    cvr::convert::gamut_clip_oklch([0.7, 0.1, 0.0]),
+    [0.0; 3]
+  ));
+  assert!(approx_eq(
+    cvr::convert::oklch_to_linear_clipped([0.7, nan, 150.0]),
+    cvr::convert::oklch_to_linear_clipped([0.7, 0.0, 150.0]),
+    [0.0; 3]
+  ));
+
+  for lch in [
+    [nan, 0.1, 150.0],
+    [0.7, nan, 150.0],
+    [0.7, 0.4, nan],
+    [nan; 3],
+  ] {
+    let rgb = cvr::convert::oklch_to_linear_clipped(lch);
+    assert!(rgb.iter().all(|x| (0.0..=1.0).contains(x)), "{:?}", lch);
+  }
+}
+
+#[test]
+fn range_error_display() {
+  let err: Box<dyn std::error::Error> = Box::new(cvr::convert::RangeError {
+    channel: 2,
+    value: -0.5,
+  });
+  assert_eq!(err.to_string(), "channel 2 is out of range: -0.5");
+}
@@ -0,0 +1,153 @@
+extern crate cvr;
+
+use cvr::convert::iter::{DecodeIterator, EncodeIterator};
+use cvr::convert::transfer::{AdobeRgb, Gamma, Hlg, Linear, Pq, Rec709, Srgb, TransferFunction};
+
+#[test]
+fn srgb_wrappers_unchanged() {
+  // the original, hard-coded implementations of the `sRGB` curve
+  //
+  let decode = |u: u8| {
+    let u = f32::from(u) * 0.003_921_569;
+    if u <= 0.04045 {
+      u * 0.077_399_38
+    } else {
+      ((u + 0.055) * 0.947_867_3).powf(2.4)
+    }
+  };
+
+  let encode = |u: f32| {
+    let u = if u <= 0.003_130_8 {
+      12.92 * u
+    } else {
+      1.055 * u.powf(0.416_666_66) - 0.055
+    };
+
+    (255.0 * u.clamp(0.0, 1.0)).round() as u8
+  };
+
+  for x in 0..=255_u8 {
+    assert_eq!(
+      cvr::convert::srgb_to_linear(x).to_bits(),
+      decode(x).to_bits()
+    );
+  }
+
+  for i in -10..=1010 {
+    let x = i as f32 / 1000.0;
+    assert_eq!(cvr::convert::linear_to_srgb(x), encode(x));
+  }
+}
+
+#[test]
+fn transfer_curves() {
+  let curves: [&dyn TransferFunction; 8] = [
+    &Linear,
+    &Srgb,
+    &Rec709,
+    &Gamma(2.2),
+    &Gamma(2.6),
+    &AdobeRgb,
+    &Pq,
+    &Hlg,
+  ];
+
+  for curve in curves.iter() {
+    assert!(curve.encode(0.0).abs() < 1e-6);
+    assert!((curve.encode(1.0) - 1.0).abs() < 1e-5);
+
+    for i in 0..=100 {
+      let x = i as f32 / 100.0;
+      assert!((curve.decode(curve.encode(x)) - x).abs() < 1e-4);
+    }
+  }
+
+  assert!((Rec709.encode(0.01) - 0.045).abs() < 1e-6);
+  assert!((Gamma(2.2).decode(0.5) - 0.217_637_6).abs() < 1e-6);
+  assert!((AdobeRgb.decode(0.5) - 0.217_755_5).abs() < 1e-6);
+
+  assert!((Hlg.encode(1.0 / 12.0) - 0.5).abs() < 1e-6);
+  assert!((Hlg.decode(0.75) - 0.264_962_6).abs() < 1e-5);
+
+  // reference values from the `SMPTE ST 2084` specification: 100 and 1,000 nits
+  //
+  assert!((Pq.encode(0.01) - 0.508_078).abs() < 1e-4);
+  assert!((Pq.encode(0.1) - 0.751_827).abs() < 1e-4);
+}
+
+#[test]
+fn transfer_adapters() {
+  let pixels = [[0_u16, 512, 1023], [65535, 40000, 100]];
+
+  let linear: Vec<[f32; 3]> = pixels.iter().copied().decode(Pq).collect();
+  assert!((linear[1][0] - 1.0).abs() < 1e-6);
+
+  let roundtrip: Vec<[u16; 3]> = linear.iter().copied().encode(Pq).collect();
+  assert_eq!(roundtrip, pixels);
+
+  let srgb: Vec<[u8; 3]> = [[0.5_f32, 0.0, 2.0]]
+    .iter()
+    .copied()
+    .encode::<_, u8>(&Srgb as &dyn TransferFunction)
+    .collect();
+  assert_eq!(srgb, [[cvr::convert::linear_to_srgb(0.5), 0, 255]]);
+
+  let img = cvr::rgb::Image::from_vec(
+    vec![0_u8, 64, 128, 255],
+    vec![1_u8, 2, 3, 4],
+    vec![250_u8, 200, 150, 100],
+    2,
+    2,
+  )
+  .unwrap();
+
+  let mut linear = cvr::rgb::Image::new();
+  img.decode(&Rec709, &mut linear);
+  assert!(linear.rgb_iter().eq(img.rgb_iter().decode(Rec709)));
+
+  let mut encoded = cvr::rgb::Image::<u8>::new();
+  linear.encode(&Rec709, &mut encoded);
+  assert!(encoded == img);
+
+  let mut wide = cvr::rgb::Image::<u16>::new();
+  linear.encode(&Rec709, &mut wide);
+  assert_eq!(wide.r()[3], 65535);
+
+  let gray = cvr::gray::Image::from_vec(vec![0_u8, 64, 128, 255], 2, 2).unwrap();
+
+  let mut linear = cvr::gray::Image::new();
+  gray.decode(&Gamma(2.2), &mut linear);
+  assert!((linear.v()[2] - (128.0_f32 / 255.0).powf(2.2)).abs() < 1e-6);
+
+  let mut encoded = cvr::gray::Image::<u8>::new();
+  linear.encode(&Gamma(2.2), &mut encoded);
+  assert!(encoded == gray);
+}
+
+#[test]
+fn transfer_rgba() {
+  let img = cvr::rgba::Image::from_vec(
+    vec![0_u8, 64, 128, 255],
+    vec![1_u8, 2, 3, 4],
+    vec![250_u8, 200, 150, 100],
+    vec![0_u8, 51, 102, 255],
+    2,
+    2,
+  )
+  .unwrap();
+
+  // the alpha channel is only normalized, never run through the curve
+  //
+  let mut linear = cvr::rgba::Image::new();
+  img.decode(&Pq, &mut linear);
+  assert!(linear.rgb_iter().eq(img.rgb_iter().decode(Pq)));
+  assert_eq!(linear.a(), [0.0, 0.2, 0.4, 1.0]);
+
+  let mut encoded = cvr::rgba::Image::<u8>::new();
+  linear.encode(&Pq, &mut encoded);
+  assert!(encoded == img);
+
+  let mut wide = cvr::rgba::Image::<u16>::new();
+  linear.encode(&Pq, &mut wide);
+  assert_eq!(wide.a(), [0, 13107, 26214, 65535]);
+}
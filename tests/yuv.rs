@@ -0,0 +1,303 @@
+extern crate cvr;
+
+use cvr::yuv::{Encoding, Matrix, Range, Siting, Subsampling};
+
+fn gradient(width: usize, height: usize) -> cvr::rgb::Image<u8> {
+  let n = width * height;
+  cvr::rgb::Image::from_vec(
+    (0..n).map(|i| (i * 7 % 256) as u8).collect(),
+    (0..n).map(|i| (i * 13 % 256) as u8).collect(),
+    (0..n).map(|i| (255 - i * 3 % 256) as u8).collect(),
+    width,
+    height,
+  )
+  .unwrap()
+}
+
+#[test]
+fn ycbcr_pixels() {
+  let bt601 = Encoding::new(Matrix::Bt601, Range::Limited);
+  assert_eq!(cvr::yuv::rgb_to_ycbcr([255, 0, 0], bt601), [81, 90, 240]);
+  assert_eq!(
+    cvr::yuv::rgb_to_ycbcr([255, 255, 255], bt601),
+    [235, 128, 128]
+  );
+  assert_eq!(cvr::yuv::rgb_to_ycbcr([0, 0, 0], bt601), [16, 128, 128]);
+
+  assert_eq!(
+    cvr::yuv::rgb_to_ycbcr([255, 0, 0], Encoding::new(Matrix::Bt601, Range::Full)),
+    [76, 85, 255]
+  );
+  assert_eq!(
+    cvr::yuv::rgb_to_ycbcr([255, 0, 0], Encoding::default()),
+    [63, 102, 240]
+  );
+  assert_eq!(
+    cvr::yuv::rgb_to_ycbcr([255, 255, 255], Encoding::new(Matrix::Bt2020, Range::Full)),
+    [255, 128, 128]
+  );
+
+  for matrix in [Matrix::Bt601, Matrix::Bt709, Matrix::Bt2020] {
+    for range in [Range::Full, Range::Limited] {
+      let encoding = Encoding::new(matrix, range);
+
+      for rgb in gradient(64, 4).rgb_iter() {
+        let actual = cvr::yuv::ycbcr_to_rgb(cvr::yuv::rgb_to_ycbcr(rgb, encoding), encoding);
+        assert!(
+          actual
+            .iter()
+            .zip(rgb.iter())
+            .all(|(a, b)| (i32::from(*a) - i32::from(*b)).abs() <= 2),
+          "{:?} {:?}: {:?} != {:?}",
+          matrix,
+          range,
+          actual,
+          rgb
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn yuv444_vectorized_decode() {
+  // an odd width exercises both the vectorized blocks and the scalar remainder while the extreme values check that
+  // both paths saturate identically
+  //
+  let (width, height) = (37, 3);
+  let n = width * height;
+
+  let y: Vec<u8> = (0..n).map(|i| (i * 29 % 256) as u8).collect();
+  let u: Vec<u8> = (0..n).map(|i| (i * 47 % 256) as u8).collect();
+  let v: Vec<u8> = (0..n).map(|i| (255 - i * 11 % 256) as u8).collect();
+
+  let img = cvr::yuv::Yuv444::from_vec(y.clone(), u.clone(), v.clone(), width, height).unwrap();
+
+  for encoding in [
+    Encoding::default(),
+    Encoding::new(Matrix::Bt601, Range::Full),
+    Encoding::new(Matrix::Bt2020, Range::Limited),
+  ] {
+    let mut rgb = cvr::rgb::Image::new();
+    cvr::yuv::yuv444_to_rgb(&img, &mut rgb, encoding);
+
+    let expected = cvr::rgb::make_iter(&y, &u, &v).map(|p| cvr::yuv::ycbcr_to_rgb(p, encoding));
+    assert!(rgb.rgb_iter().eq(expected));
+
+    let mut yuv = cvr::yuv::Yuv444::new();
+    cvr::yuv::rgb_to_yuv444(rgb.view(), &mut yuv, encoding);
+    assert_eq!((yuv.width(), yuv.height()), (width, height));
+  }
+}
+
+#[test]
+fn chroma_resampling() {
+  let mut out = [0_u8; 4];
+
+  cvr::yuv::upsample_chroma(
+    &[0, 100],
+    (4, 1),
+    Subsampling::Yuv422,
+    Siting::Center,
+    &mut out,
+  );
+  assert_eq!(out, [0, 25, 75, 100]);
+
+  cvr::yuv::upsample_chroma(
+    &[0, 100],
+    (4, 1),
+    Subsampling::Yuv422,
+    Siting::Left,
+    &mut out,
+  );
+  assert_eq!(out, [0, 50, 100, 100]);
+
+  let mut out = [0_u8; 2];
+
+  cvr::yuv::downsample_chroma(
+    &[0, 100, 200, 100],
+    (4, 1),
+    Subsampling::Yuv422,
+    Siting::Center,
+    &mut out,
+  );
+  assert_eq!(out, [50, 150]);
+
+  cvr::yuv::downsample_chroma(
+    &[0, 100, 200, 100],
+    (4, 1),
+    Subsampling::Yuv422,
+    Siting::Left,
+    &mut out,
+  );
+  assert_eq!(out, [25, 150]);
+
+  // vertical siting only matters for 4:2:0
+  //
+  let plane = [0, 0, 100, 100];
+  let mut out = [0_u8; 4];
+
+  cvr::yuv::upsample_chroma(
+    &[0, 100],
+    (1, 4),
+    Subsampling::Yuv420,
+    Siting::Left,
+    &mut out,
+  );
+  assert_eq!(out, [0, 25, 75, 100]);
+
+  cvr::yuv::upsample_chroma(
+    &[0, 100],
+    (1, 4),
+    Subsampling::Yuv420,
+    Siting::TopLeft,
+    &mut out,
+  );
+  assert_eq!(out, [0, 50, 100, 100]);
+
+  let mut out = [0_u8; 2];
+  cvr::yuv::downsample_chroma(&plane, (1, 4), Subsampling::Yuv420, Siting::Left, &mut out);
+  assert_eq!(out, [0, 100]);
+
+  assert_eq!(Subsampling::Yuv420.chroma_dims(5, 3), (3, 2));
+  assert_eq!(Subsampling::Yuv422.chroma_dims(5, 3), (3, 3));
+  assert_eq!(Subsampling::Yuv444.chroma_dims(5, 3), (5, 3));
+}
+
+#[test]
+fn yuv420_formats() {
+  let img = gradient(9, 5);
+  let encoding = Encoding::new(Matrix::Bt601, Range::Full);
+
+  for siting in [Siting::Center, Siting::Left, Siting::TopLeft] {
+    let mut i420 = cvr::yuv::I420::new();
+    cvr::yuv::rgb_to_i420(img.view(), &mut i420, encoding, siting);
+    assert_eq!((i420.u().len(), i420.v().len()), (15, 15));
+
+    let mut nv12 = cvr::yuv::Nv12::new();
+    cvr::yuv::rgb_to_nv12(img.view(), &mut nv12, encoding, siting);
+
+    assert_eq!(nv12.y(), i420.y());
+    assert!(nv12
+      .uv()
+      .chunks_exact(2)
+      .eq(i420.u().iter().zip(i420.v()).map(|(u, v)| [*u, *v])));
+
+    let (mut x, mut y) = (cvr::rgb::Image::new(), cvr::rgb::Image::new());
+    cvr::yuv::i420_to_rgb(&i420, &mut x, encoding, siting);
+    cvr::yuv::nv12_to_rgb(&nv12, &mut y, encoding, siting);
+
+    assert!(x == y);
+    assert_eq!((x.width(), x.height()), (9, 5));
+  }
+
+  // decoding matches upsampling the chroma planes in full before converting them, including rows that span several
+  // of the chunks the chroma is upsampled in
+  //
+  for (width, height) in [(1, 1), (3, 2), (300, 3), (513, 5)] {
+    let img = gradient(width, height);
+
+    for siting in [Siting::Center, Siting::Left, Siting::TopLeft] {
+      let mut i420 = cvr::yuv::I420::new();
+      cvr::yuv::rgb_to_i420(img.view(), &mut i420, encoding, siting);
+
+      let mut nv12 = cvr::yuv::Nv12::new();
+      cvr::yuv::rgb_to_nv12(img.view(), &mut nv12, encoding, siting);
+
+      let (mut u, mut v) = (vec![0; width * height], vec![0; width * height]);
+      let dims = (width, height);
+      cvr::yuv::upsample_chroma(i420.u(), dims, Subsampling::Yuv420, siting, &mut u);
+      cvr::yuv::upsample_chroma(i420.v(), dims, Subsampling::Yuv420, siting, &mut v);
+
+      let yuv = cvr::yuv::Yuv444::from_vec(i420.y().to_vec(), u, v, width, height).unwrap();
+      let mut expected = cvr::rgb::Image::new();
+      cvr::yuv::yuv444_to_rgb(&yuv, &mut expected, encoding);
+
+      let (mut x, mut y) = (cvr::rgb::Image::new(), cvr::rgb::Image::new());
+      cvr::yuv::i420_to_rgb(&i420, &mut x, encoding, siting);
+      cvr::yuv::nv12_to_rgb(&nv12, &mut y, encoding, siting);
+
+      assert!(x == expected && y == expected);
+    }
+  }
+
+  let mut empty = cvr::rgb::Image::new();
+  cvr::yuv::i420_to_rgb(&cvr::yuv::I420::new(), &mut empty, encoding, Siting::Center);
+  cvr::yuv::nv12_to_rgb(&cvr::yuv::Nv12::new(), &mut empty, encoding, Siting::Center);
+  assert_eq!(empty.total(), 0);
+
+  // flat images survive chroma subsampling
+  //
+  let flat = cvr::rgb::Image::from_vec(vec![200_u8; 15], vec![40; 15], vec![90; 15], 5, 3).unwrap();
+
+  let mut i420 = cvr::yuv::I420::new();
+  cvr::yuv::rgb_to_i420(flat.view(), &mut i420, encoding, Siting::Center);
+
+  let mut rgb = cvr::rgb::Image::new();
+  cvr::yuv::i420_to_rgb(&i420, &mut rgb, encoding, Siting::Center);
+  assert!(rgb.rgb_iter().all(|p| p
+    .iter()
+    .zip([200, 40, 90].iter())
+    .all(|(a, b)| (i32::from(*a) - b).abs() <= 1)));
+
+  assert!(cvr::yuv::I420::from_vec(vec![0; 15], vec![0; 6], vec![0; 5], 5, 3).is_err());
+  assert!(cvr::yuv::Nv12::from_vec(vec![0; 15], vec![0; 12], 5, 3).is_ok());
+}
+
+#[test]
+fn yuv422_packed_formats() {
+  let encoding = Encoding::new(Matrix::Bt601, Range::Full);
+  let img = cvr::rgb::Image::from_vec(vec![255_u8, 255], vec![0, 0], vec![0, 0], 2, 1).unwrap();
+
+  let mut yuyv = cvr::yuv::Yuyv::new();
+  cvr::yuv::rgb_to_yuyv(img.view(), &mut yuyv, encoding, Siting::Center);
+  assert_eq!(yuyv.data(), [76, 85, 76, 255]);
+
+  let mut uyvy = cvr::yuv::Uyvy::new();
+  cvr::yuv::rgb_to_uyvy(img.view(), &mut uyvy, encoding, Siting::Center);
+  assert_eq!(uyvy.data(), [85, 76, 255, 76]);
+
+  // odd widths pad each row to a whole pair of pixels
+  //
+  let img = gradient(7, 3);
+
+  cvr::yuv::rgb_to_yuyv(img.view(), &mut yuyv, encoding, Siting::Left);
+  cvr::yuv::rgb_to_uyvy(img.view(), &mut uyvy, encoding, Siting::Left);
+  assert_eq!(yuyv.data().len(), 4 * 4 * 3);
+
+  let (mut x, mut y) = (cvr::rgb::Image::new(), cvr::rgb::Image::new());
+  cvr::yuv::yuyv_to_rgb(&yuyv, &mut x, encoding, Siting::Left);
+  cvr::yuv::uyvy_to_rgb(&uyvy, &mut y, encoding, Siting::Left);
+
+  assert!(x == y);
+  assert_eq!((x.width(), x.height()), (7, 3));
+
+  let mut yuv = cvr::yuv::Yuv444::new();
+  cvr::yuv::rgb_to_yuv444(img.view(), &mut yuv, encoding);
+  assert!(yuv
+    .y()
+    .chunks_exact(7)
+    .zip(yuyv.data().chunks_exact(16))
+    .all(|(y, row)| y.iter().eq(row.iter().step_by(2).take(7))));
+
+  // empty images have no pairs of pixels to pack
+  //
+  for (width, height) in [(0, 0), (0, 3), (4, 0)] {
+    let empty = cvr::rgb::Image::<u8>::from_vec(vec![], vec![], vec![], width, height).unwrap();
+
+    cvr::yuv::rgb_to_yuyv(empty.view(), &mut yuyv, encoding, Siting::Center);
+    cvr::yuv::rgb_to_uyvy(empty.view(), &mut uyvy, encoding, Siting::Center);
+    assert!(yuyv.data().is_empty() && uyvy.data().is_empty());
+
+    cvr::yuv::yuyv_to_rgb(&yuyv, &mut x, encoding, Siting::Center);
+    cvr::yuv::uyvy_to_rgb(&uyvy, &mut y, encoding, Siting::Center);
+    assert_eq!((x.width(), x.height()), (width, height));
+    assert!(x == y);
+  }
+
+  cvr::yuv::uyvy_to_rgb(&cvr::yuv::Uyvy::new(), &mut x, encoding, Siting::Center);
+  assert_eq!(x.total(), 0);
+
+  assert!(cvr::yuv::Yuyv::from_vec(vec![0; 48], 7, 3).is_ok());
+  assert!(cvr::yuv::Uyvy::from_vec(vec![0; 42], 7, 3).is_err());
+}